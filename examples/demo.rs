@@ -1,7 +1,14 @@
-#![feature(plugin, custom_attribute)]
+#![feature(plugin, custom_attribute, const_fn, i128_type, stmt_expr_attributes,
+           use_extern_macros)]
 #![plugin(stanley)]
 #![allow(dead_code)]
 
+extern crate stanley;
+
+use std::cmp::Ordering;
+use std::sync::Mutex;
+use std::thread;
+
 #[condition(pre="true", post="ret == 10:i32")]
 fn loopy12() -> i32 {
     let mut a = 0;
@@ -14,7 +21,8 @@ fn loopy12() -> i32 {
     b
 }
 
-#[condition(pre="true", post="ret == (x * 4:i32)")]
+#[requires = "x > -1000:i32 && x < 1000:i32"]
+#[ensures = "ret == (x * 4:i32)"]
 fn loopy1(x: i32) -> i32 {
     let mut a = 0;
     let mut b = 0;
@@ -26,12 +34,25 @@ fn loopy1(x: i32) -> i32 {
     b
 }
 
-#[condition(pre="x < 100:i32 && x > 10:i32", post="ret == (x / 2:i32)")]
+// Proved up to the unrolling bound, with `i <= n` offered as a loop invariant
+#[condition(pre="true", post="ret == n")]
+fn count_up(n: u32) -> u32 {
+    let mut i = 0;
+    while i < n {
+        i += 1;
+    }
+    i
+}
+
+#[requires = "x < 100:i32 && x > 10:i32"]
+#[ensures = "ret == (x / 2:i32)"]
 fn divide_two(x: i32) -> i32 {
     x >> 1
 }
 
-#[condition(pre="true", post="ret == (x * 4:i32) / y")]
+#[requires = "x > -1000:i32 && x < 1000:i32"]
+#[requires = "y != 0:i32"]
+#[ensures = "ret == (x * 4:i32) / y"]
 fn multiply_by_4_div_y(x: i32, y: i32) -> i32 {
     (x << 2) / y
 }
@@ -41,7 +62,7 @@ fn check_range(x: i32) -> bool {
     x < 100 && x > 10
 }
 
-#[condition(pre="true", post="ret == (x < 100:i32 && x > 10:i32)")]
+#[ensures = "ret == (x < 100:i32 && x > 10:i32)"]
 fn check_range2(x: i32) -> bool {
     x < 100 && x > 10
 }
@@ -56,12 +77,13 @@ fn param_plus_one(x: i32) -> i32 {
     x + 1
 }
 
-#[condition(pre="true", post="ret == ((x * 2:i32) < 20:i32)")]
+#[requires = "x > -1000:i32 && x < 1000:i32"]
+#[ensures = "ret == ((x * 2:i32) < 20:i32)"]
 fn double_less_than_twenty(x: i32) -> bool {
     (x * 2) < 20
 }
 
-#[condition(pre="true", post="ret == (x / 2:i32)")]
+#[ensures = "ret == (x / 2:i32)"]
 fn div_two(x: i32) -> i32 {
     x / 2
 }
@@ -71,7 +93,7 @@ fn boolean_not(x: bool) -> bool {
     if x { false } else { true }
 }
 
-#[condition(pre="true", post="ret == (x > (y / 2:i32))")]
+#[ensures = "ret == (x > (y / 2:i32))"]
 fn bigger_than_input_div_two(x: i32, y: i32) -> bool {
     x > (y / 2)
 }
@@ -81,12 +103,12 @@ fn bigger_than_input(x: i32, y: i32) -> bool {
     x > y
 }
 
-#[condition(pre="true", post="ret == (x < 5:i32)")]
+#[ensures = "ret == (x < 5:i32)"]
 fn check_less_than_five(x: i32) -> bool {
     x < 5
 }
 
-#[condition(pre="true", post="ret == (x < 8:i32)")]
+#[ensures = "ret == (x < 8:i32)"]
 fn check_less_than_eight(x: i32) -> bool {
     if x < 8 { true } else { false }
 }
@@ -96,34 +118,735 @@ fn positive(x: i32) -> bool {
     x > 0
 }
 
-#[condition(pre="true", post="ret == (x < 0:i32)")]
+#[ensures = "ret == (x < 0:i32)"]
 fn negative(x: i32) -> bool {
     x < 0
 }
 
-#[condition(pre="true", post="ret == !x")]
+#[ensures = "ret == !x"]
 fn boolean_not3(x: bool) -> bool {
     !x
 }
 
-#[condition(pre="true", post="(x == true => ret == false) && (x == false => ret == true)")]
+#[ensures = "(x == true => ret == false) && (x == false => ret == true)"]
 fn boolean_not2(x: bool) -> bool {
     if x { false } else { true }
 }
 
-#[condition(pre="true", post="ret == (x + 1:i32)")]
+#[requires = "x < 1000:i32"]
+#[ensures = "ret == (x + 1:i32)"]
 fn param_plus_one2(x: i32) -> i32 {
     x + 1
 }
 
-#[condition(pre="true", post="ret == (x + 5:i32)")]
+#[requires = "x < 1000:i32"]
+#[ensures = "ret == (x + 5:i32)"]
 fn add_five(x: i32) -> i32 {
     x + 5
 }
 
-#[condition(pre="true", post="ret == (x - 5:i32)")]
+#[requires = "x > -1000:i32"]
+#[ensures = "ret == (x - 5:i32)"]
 fn param_minus_five(x: i32) -> i32 {
     x - 5
 }
 
+#[constant_time(secret="key")]
+fn add_key(x: i32, key: i32) -> i32 {
+    x + key
+}
+
+#[constant_time(secret="key")]
+fn branch_on_key(x: i32, key: i32) -> i32 {
+    if key > 0 { x } else { 0 }
+}
+
+// A public slice agrees between the runs in its length and elements
+#[constant_time(secret="key")]
+fn tag_length(key: &[u8], msg: &[u8]) -> usize {
+    if msg.len() > 16 { 16 } else { msg.len() }
+}
+
+#[flows(from="secret", to="ret", forbidden)]
+fn same_either_way(x: i32, secret: i32) -> i32 {
+    if secret > 0 { x } else { x }
+}
+
+#[flows(from="secret", to="ret", forbidden)]
+fn leak_secret(x: i32, secret: i32) -> i32 {
+    x + secret
+}
+
+static mut OFFSET: i32 = 3;
+
+#[deterministic]
+fn double(x: i32) -> i32 {
+    x * 2
+}
+
+#[deterministic]
+fn add_offset(x: i32) -> i32 {
+    unsafe { x + OFFSET }
+}
+
+fn times_eight(x: i32) -> i32 {
+    x * 8
+}
+
+#[equiv_to="times_eight"]
+fn times_eight_fast(x: i32) -> i32 {
+    x << 3
+}
+
+// `x % 0` panics and `i32::MIN % -1` overflows
+#[requires = "y != 0:i32"]
+#[requires = "y != -1:i32"]
+#[ensures = "ret == (x / y) * y"]
+fn round_down(x: i32, y: i32) -> i32 {
+    x - x % y
+}
+
+const fn square(x: i32) -> i32 {
+    x * x
+}
+
+#[ensures = "ret == 9:i32"]
+fn nine() -> i32 {
+    square(3)
+}
+
+#[requires = "x > 0:i32 && x < 100:i32"]
+#[ensures = "ret > x"]
+fn square_plus_one(x: i32) -> i32 {
+    square(x) + 1
+}
+
+#[ensures = "ret == (a + b)"]
+fn generic_add<T: std::ops::Add<Output = T>>(a: T, b: T) -> T {
+    a + b
+}
+
+fn use_generic_add() -> i32 {
+    generic_add(1i32, 2i32)
+}
+
+#[requires = "a.len() == 2 && (forall i in 0..a.len(): a[i] < 1000:i32)"]
+#[ensures = "forall i in 0..a.len(): a[i] == old(a[i]) + 1"]
+fn increment_pair(a: &mut [i32]) {
+    a[0] += 1;
+    a[1] += 1;
+}
+
+#[requires = "a.len() > 0 && (forall i in 0..a.len(): a[i] > 0)"]
+#[ensures = "ret > 0"]
+fn first_of_positive(a: &[i32]) -> i32 {
+    a[0]
+}
+
+#[requires = "a.len() == 2"]
+#[ensures = "sorted(a) && permutation(a, old(a))"]
+fn sort_pair(a: &mut [i32]) {
+    if a[0] > a[1] {
+        let t = a[0];
+        a[0] = a[1];
+        a[1] = t;
+    }
+}
+
+#[requires = "*p > -1000:i32 && *p < 1000:i32"]
+#[ensures = "*p == old(*p) * 2:i32"]
+fn double_in_place(p: &mut i32) {
+    *p *= 2;
+}
+
+#[history_invariant="self.count >= old(self.count)"]
+struct Counter {
+    count: u32,
+}
+
+impl Counter {
+    #[requires = "self.count < 100:u32"]
+    #[ensures = "self.count == old(self.count) + 1"]
+    fn increment(&mut self) {
+        self.count += 1;
+    }
+}
+
+struct Buffer {
+    items: [i32; 4],
+}
+
+impl Buffer {
+    #[requires = "i < 4"]
+    #[ensures = "true"]
+    #[pledge="final(self.items)[i] == final(ret) && \
+              (forall j in 0..4: j != i => final(self.items)[j] == self.items[j])"]
+    fn pick(&mut self, i: usize) -> &mut i32 {
+        &mut self.items[i]
+    }
+}
+
+struct Account {
+    #[lock_invariant="data >= 0"]
+    balance: Mutex<i64>,
+}
+
+impl Account {
+    #[requires = "amount >= 0:i64 && amount < 1000:i64"]
+    #[ensures = "true"]
+    fn deposit(&self, amount: i64) {
+        let mut balance = self.balance.lock().unwrap();
+
+        if *balance < 1000000 {
+            *balance += amount;
+        }
+    }
+}
+
+#[ensures = "ret > 0:i32"]
+fn spawn_positive() -> i32 {
+    let handle = thread::spawn(#[ensures = "ret == 42:i32"]
+                               || 42);
+
+    handle.join().unwrap()
+}
+
+#[ensures = "(x > 0:i32 && y > 0:i32 => ret == 3:i32) && \
+             (x > 0:i32 && y <= 0:i32 => ret == 2:i32) && \
+             (x <= 0:i32 => ret == 1:i32)"]
+fn early_return(x: i32, y: i32) -> i32 {
+    if x > 0 {
+        if y > 0 {
+            return 3;
+        }
+        return 2;
+    }
+    1
+}
+
+#[ensures = "ret >= 0:i32"]
+fn exit_on_negative(x: i32) -> i32 {
+    if x < 0 {
+        std::process::exit(1);
+    }
+    x
+}
+
+#[ensures = "false"]
+fn spin() -> ! {
+    loop {}
+}
+
+#[requires = "x >= 0:i32 && x < 256:i32"]
+#[ensures = "true"]
+fn low_byte(x: i32) -> u8 {
+    x as u8
+}
+
+#[requires = "x < i32::MAX"]
+#[ensures = "ret > x"]
+fn successor(x: i32) -> i32 {
+    x + 1
+}
+
+#[ensures = "ret == a[3] && a.len() == 4"]
+fn last_of_four(a: &[i32; 4]) -> i32 {
+    a[3]
+}
+
+#[ensures = "ret == 7:i32"]
+fn initialized_before_read() -> i32 {
+    let mut x: i32 = unsafe { std::mem::uninitialized() };
+    x = 7;
+    x
+}
+
+#[ensures = "(x > 0:i32 => ret == Some(x)) && (x <= 0:i32 => ret == None)"]
+fn positive_or_none(x: i32) -> Option<i32> {
+    if x > 0 { Some(x) } else { None }
+}
+
+#[requires = "o.is_some()"]
+#[ensures = "ret == o.unwrap()"]
+fn unwrap_present(o: Option<i32>) -> i32 {
+    o.unwrap()
+}
+
+#[requires = "c >= 'A' && c <= 'Z'"]
+#[ensures = "ret >= 'a' && ret <= 'z'"]
+fn ascii_lower(c: char) -> u32 {
+    c as u32 + 32
+}
+
+#[requires = "all_ascii(buf) && prefix(buf, tag) && tag.len() > 0:usize"]
+#[ensures = "ret == tag[0] && ret & 128:u8 == 0:u8"]
+fn tag_byte(buf: &[u8], tag: &[u8]) -> u8 {
+    buf[0]
+}
+
+#[requires = "0:i32 <= lo && lo <= hi"]
+#[ensures = "lo <= ret && ret <= hi"]
+fn midpoint(lo: i32, hi: i32) -> i32 {
+    lo + (hi - lo) / 2
+}
+
+#[requires = "a <= b"]
+#[ensures = "a <= ret && ret <= b"]
+fn floor_average(a: i32, b: i32) -> i32 {
+    (a & b) + ((a ^ b) >> 1)
+}
+
+#[requires = "a.len() > 1"]
+#[ensures = "exists i in 0..a.len(): a[i] == 0"]
+fn clear_second(a: &mut [i32]) {
+    a[1] = 0;
+}
+
+#[requires = "a.len() == 2 && (exists i in 0..a.len(): a[i] < 0)"]
+#[ensures = "ret < 0"]
+fn min_pair(a: &[i32]) -> i32 {
+    if a[0] < a[1] { a[0] } else { a[1] }
+}
+
+#[requires = "int(count) < int(limit)"]
+#[ensures = "int(ret) <= int(limit)"]
+fn next_count(count: u32, limit: i32) -> u32 {
+    count + 1
+}
+
+#[invariant = "i <= n && s == i"]
+#[ensures = "ret == n"]
+fn count_to(n: u32) -> u32 {
+    let mut i = 0;
+    let mut s = 0;
+    while i < n {
+        i += 1;
+        s += 1;
+    }
+    s
+}
+
+#[invariant = "i <= n"]
+#[variant = "n - i"]
+fn skip_to(n: u32) -> u32 {
+    let mut i = 0;
+    while i < n {
+        i += 1;
+    }
+    i
+}
+
+#[requires = "n >= 0:i32"]
+#[requires = "n <= 1000:i32"]
+#[ensures = "ret >= 0:i32 && ret <= n * 1000:i32"]
+#[variant = "n"]
+fn triangle(n: i32) -> i32 {
+    if n == 0 { 0 } else { n + triangle(n - 1) }
+}
+
+#[requires = "lo <= hi"]
+#[requires = "hi < 1000:i32"]
+#[ensures = "lo <= ret"]
+#[ensures = "ret <= hi"]
+fn bound(x: i32, lo: i32, hi: i32) -> i32 {
+    if x < lo { lo } else if x > hi { hi } else { x }
+}
+
+#[requires = "x < 1000:i32"]
+#[ensures = "ret == x + 1:i32"]
+fn increment(x: i32) -> i32 {
+    x + 1
+}
+
+#[requires = "x < 500:i32"]
+#[ensures = "ret == x + 2:i32"]
+fn increment_twice(x: i32) -> i32 {
+    increment(increment(x))
+}
+
+#[requires = "x < 500:i32"]
+#[ensures = "ret == x + 1:i32"]
+fn increment_through_pointer(x: i32) -> i32 {
+    let step: fn(i32) -> i32 = increment;
+    step(x)
+}
+
+// Overflows for large `x`, which the missing precondition does not rule out
+#[expect_fail]
+#[ensures = "ret > x"]
+fn unchecked_successor(x: i32) -> i32 {
+    x + 1
+}
+
+#[ensures = "ret <= 100:i32"]
+fn cap(x: i32) -> i32 {
+    x.min(100)
+}
+
+#[requires = "x > -1000:i32"]
+#[ensures = "ret >= 0:i32"]
+fn magnitude(x: i32) -> i32 {
+    x.abs()
+}
+
+#[requires = "d != 0:u32"]
+#[ensures = "ret.is_some()"]
+fn share(n: u32, d: u32) -> Option<u32> {
+    n.checked_div(d)
+}
+
+#[requires = "x < 1000:u32"]
+#[ensures = "ret == x * x"]
+fn squared_u32(x: u32) -> u32 {
+    x.pow(2)
+}
+
+#[requires = "n > 0:u32"]
+#[ensures = "ret == ilog2(n)"]
+#[ensures = "ret < 32:u32"]
+fn bucket(n: u32) -> u32 {
+    n.ilog2()
+}
+
+#[requires = "n <= 1024:usize"]
+#[ensures = "ret >= n"]
+#[ensures = "ret <= 2048:usize"]
+fn capacity(n: usize) -> usize {
+    n.next_power_of_two()
+}
+
+#[requires = "x < 1000000:u128"]
+#[ensures = "ret == x * 1000:u128"]
+fn to_millis(x: u128) -> u128 {
+    x * 1000
+}
+
+#[ensures = "ret == (a > b)"]
+fn greater(a: i32, b: i32) -> bool {
+    a > b
+}
+
+#[ensures = "ret == (a != b)"]
+fn differ(a: bool, b: bool) -> bool {
+    a ^ b
+}
+
+#[ensures = "ret == 255:u8 - x"]
+fn complement(x: u8) -> u8 {
+    !x
+}
+
+#[ensures = "ret.unwrap() == c && c < 10:u32"]
+#[ensures_err = "ret.unwrap_err() == c && c >= 10:u32"]
+fn digit(c: u32) -> Result<u32, u32> {
+    if c < 10 { Ok(c) } else { Err(c) }
+}
+
+#[ensures = "ret.unwrap() < 100:u32"]
+#[ensures_err = "ret.unwrap_err() >= 10:u32"]
+fn two_digits(high: u32, low: u32) -> Result<u32, u32> {
+    let high = digit(high)?;
+    let low = digit(low)?;
+    Ok(high * 10 + low)
+}
+
+#[ensures = "x.is_nan() || ret == x * 2.0"]
+fn double_f64(x: f64) -> f64 {
+    x + x
+}
+
+#[ensures = "ret == (x != x)"]
+fn nan(x: f32) -> bool {
+    x.is_nan()
+}
+
+enum Reading {
+    Missing,
+    Value(i32),
+}
+
+#[ensures = "ret >= 0:i32 && ret <= 100:i32"]
+fn sanitize(x: i32) -> i32 {
+    let reading = if x < 0 { Reading::Missing } else { Reading::Value(x) };
+
+    match reading {
+        Reading::Value(v) if v > 100 => 100,
+        Reading::Value(v) => v,
+        Reading::Missing => 0,
+    }
+}
+
+#[ensures = "(ret is Missing) == (x < 0:i32)"]
+fn read(x: i32) -> Reading {
+    if x < 0 { Reading::Missing } else { Reading::Value(x) }
+}
+
+enum Light {
+    Red,
+    Amber,
+    Green,
+}
+
+#[ensures = "(ret is Red) == stop && !(ret is Amber)"]
+fn signal(stop: bool) -> Light {
+    if stop { Light::Red } else { Light::Green }
+}
+
+#[ensures = "ret is Some"]
+fn wrap(x: u8) -> Option<u8> {
+    Some(x)
+}
+
+#[ensures = "ret == (n == 2:u8 || n == 3:u8 || n == 5:u8 || n == 7:u8)"]
+fn small_prime(n: u8) -> bool {
+    match n {
+        2 | 3 | 5 | 7 => true,
+        _ => false,
+    }
+}
+
+struct Span {
+    start: u32,
+    end: u32,
+}
+
+struct Token {
+    span: Span,
+    kind: u8,
+}
+
+#[requires = "token.span.start <= token.span.end"]
+#[ensures = "ret == token.span.end - token.span.start"]
+fn token_len(token: &Box<Token>) -> u32 {
+    token.span.end - token.span.start
+}
+
+#[ensures = "len(a) == 4:usize && ret == a[3:usize]"]
+fn last_of_four_bytes(a: [u8; 4]) -> u8 {
+    a[3]
+}
+
+#[requires = "span.start <= span.end"]
+#[ensures = "ret == span.end - span.start"]
+fn span_len(span: &Span) -> u32 {
+    span.end - span.start
+}
+
+#[requires = "token.span.start <= token.span.end"]
+#[ensures = "ret == token.span.end - token.span.start"]
+fn token_width(token: &Token) -> u32 {
+    let span = &token.span;
+    span_len(span)
+}
+
+fn span_width(span: &Span) -> u32 {
+    span.end - span.start
+}
+
+// Structs are the same input when all their fields are
+#[equiv_to="span_width"]
+fn span_width_by_field(span: &Span) -> u32 {
+    let start = span.start;
+    span.end - start
+}
+
+#[requires = "x < 100:u32"]
+#[ensures = "ret == x * 3:u32 + 1:u32"]
+fn triple_plus_one(x: u32) -> u32 {
+    x * 3 + 1
+}
+
+#[exact_bitwidth]
+struct Crc {
+    value: u8,
+}
+
+#[ensures = "ret == crc.value / 2:u8"]
+fn halve_crc(crc: &Crc) -> u8 {
+    crc.value / 2
+}
+
+#[requires = "d > 0:u32"]
+#[ensures = "ret.0 == n / d && ret.1 == n % d"]
+fn div_rem(n: u32, d: u32) -> (u32, u32) {
+    (n / d, n % d)
+}
+
+#[requires = "d > 0:u32"]
+#[ensures = "ret == n"]
+fn recombine(n: u32, d: u32) -> u32 {
+    let (q, r) = div_rem(n, d);
+    q * d + r
+}
+
+#[ensures = "ret.0 == p.1 && ret.1 == p.0"]
+fn swap(p: (u8, u8)) -> (u8, u8) {
+    (p.1, p.0)
+}
+
+#[requires = "*x < 100:u32"]
+#[ensures = "ret == *x + 1:u32 && *x == old(*x)"]
+fn successor_of_ref(x: &u32) -> u32 {
+    let y = &x;
+    **y + 1
+}
+
+#[requires = "*x < 1000:u32"]
+#[ensures = "*x == old(*x) + 1:u32"]
+fn bump(x: &mut u32) {
+    let r = &mut *x;
+    *r += 1;
+}
+
+#[ensures = "ret == 5:u32"]
+fn through_borrow() -> u32 {
+    let mut a = 1;
+    {
+        let r = &mut a;
+        *r = 5;
+    }
+    a
+}
+
+#[requires = "d > 0:u32"]
+#[ensures = "ret <= n"]
+fn whole_parts(n: u32, d: u32) -> u32 {
+    n / d * d
+}
+
+#[no_panic]
+fn saturating_double(x: u8) -> u8 {
+    if x > 127 { 255 } else { x * 2 }
+}
+
+#[ensures = "(a < b => ret == -1:i32) && (a == b => ret == 0:i32) && (a > b => ret == 1:i32)"]
+fn sign_of_difference(a: u32, b: u32) -> i32 {
+    match a.cmp(&b) {
+        Ordering::Less => -1,
+        Ordering::Equal => 0,
+        Ordering::Greater => 1,
+    }
+}
+
+#[ensures = "(a.0 != b.0) => (ret is Less <=> a.0 < b.0)"]
+fn lexicographic(a: (u8, u8), b: (u8, u8)) -> Ordering {
+    a.0.cmp(&b.0).then(a.1.cmp(&b.1))
+}
+
+#[requires = "a <= 1000:u32 && b <= 1000:u32"]
+#[ensures = "ret == a * b + 1:u32"]
+fn area_plus_one(a: u32, b: u32) -> u32 {
+    a * b + 1
+}
+
+#[expect_fail]
+#[requires = "n > 0:i32"]
+fn doubled(n: i32) -> i32 {
+    n * 2
+}
+
+#[requires = "count > 0:u32"]
+#[ensures = "ret <= total"]
+fn mean(total: u32, count: u32) -> u32 {
+    total / count
+}
+
+#[expect_fail]
+#[requires = "count < 10:u32"]
+fn remainder_of(total: u32, count: u32) -> u32 {
+    total % count
+}
+
+#[ensures = "forall i in 0..2: forall j in 0..2: ret[i][j] == m[j][i]"]
+fn transpose(m: [[i32; 2]; 2]) -> [[i32; 2]; 2] {
+    [[m[0][0], m[1][0]], [m[0][1], m[1][1]]]
+}
+
+#[ensures = "ret[0] == v[2] && ret[1] == v[1] && ret[2] == v[0]"]
+fn reversed(v: [i64; 3]) -> [i64; 3] {
+    let mut out = v;
+    out[0] = v[2];
+    out[2] = v[0];
+    out
+}
+
+#[requires = "i < a.len()"]
+#[ensures = "ret == a[i]"]
+fn pick(a: &[u8], i: usize) -> u8 {
+    a[i]
+}
+
+#[expect_fail]
+#[ensures = "ret == a[0]"]
+fn first(a: &[u8]) -> u8 {
+    a[0]
+}
+
+#[expect_fail]
+#[requires = "n < 100:u32"]
+fn checked_triple(n: u32) -> u32 {
+    let tripled = n * 3;
+    assert_eq!(tripled / 3, n);
+    assert!(tripled < 297, "tripled too large");
+    tripled
+}
+
+#[debug_assertions = "assume"]
+#[ensures = "ret < 200:u32"]
+fn halved(n: u32) -> u32 {
+    debug_assert!(n < 400);
+    n / 2
+}
+
+#[requires = "x < 1000:u32"]
+#[ensures = "ret < 1001000:u32"]
+fn square_plus(x: u32) -> u32 {
+    let squared = x * x;
+    stanley::assert!(squared < 1000000);
+    squared + x
+}
+
+#[ensures = "ret <= 100:u32"]
+fn percent(part: u32, whole: u32) -> u32 {
+    stanley::assume!(part <= whole && whole > 0 && whole <= 1000000);
+    part * 100 / whole
+}
+
+// Each iteration gets its own result, so the third increment is counted
+#[expect_fail]
+#[ensures = "ret == 2:i32"]
+fn increment_thrice() -> i32 {
+    let mut x = 0;
+    let mut i = 0;
+
+    while i < 3 {
+        x = increment(x);
+        i += 1;
+    }
+    x
+}
+
+fn clamp(x: i32) -> i32 {
+    if x > 100 { 100 } else { x }
+}
+
+#[harness(assume="x > -1000:i32")]
+fn clamp_harness(x: i32) {
+    let y = clamp(x);
+    assert!(y <= 100 && y > -1000);
+}
+
+#[requires = "x > 0:i32"]
+#[ensures = "true"]
+fn checked_positive(x: i32) -> i32 {
+    x
+}
+
+// The caller knows `x` is positive for reasons the prover cannot see
+#[harness]
+fn trusted_positive(x: i32) {
+    let y = #[assume_pre] checked_positive(x);
+    assert!(y > 0);
+}
+
 fn main() {}
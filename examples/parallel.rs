@@ -0,0 +1,28 @@
+// The obligations of each function are split into their conjuncts and
+// solved on several solver processes at once. A counterexample comes from
+// the first falsified conjunct, showing booleans and floats by their values.
+
+#![feature(plugin, custom_attribute)]
+#![plugin(stanley(jobs = "4"))]
+#![allow(dead_code)]
+
+#[requires = "x > -1000:i32 && x < 1000:i32"]
+#[ensures = "ret > x && ret <= x + 3:i32 && ret > -997:i32"]
+fn step_up(x: i32) -> i32 {
+    if x < 0 { x + 3 } else { x + 1 }
+}
+
+#[requires = "x > -1000:i32"]
+#[ensures = "(flag == false => ret == x) && (flag == true => ret == 0:i32 - x)"]
+fn negate_if(flag: bool, x: i32) -> i32 {
+    if flag { -x } else { x }
+}
+
+// Wrong for a negative or NaN `x`, and for either `flag`
+#[expect_fail]
+#[ensures = "ret >= x"]
+fn scale(flag: bool, x: f32) -> f32 {
+    if flag { x * 0.5 } else { x * 2.0 }
+}
+
+fn main() {}
@@ -0,0 +1,154 @@
+// Generates random arithmetic functions with contracts, each once with a
+// correct postcondition and once with a broken one, compiles them with
+// Stanley and checks it proves every correct contract and refutes every
+// broken one.
+//
+//     cargo build && cargo run --example stress -- [count] [seed]
+//
+// The crate is compiled with `$RUSTC` (default `rustc`), finding the plugin
+// in `target/debug/deps`.
+
+use std::env;
+use std::fs::{self, File};
+use std::io::Write;
+use std::process::{self, Command};
+
+struct Random {
+    state: u64,
+}
+
+impl Random {
+    fn next(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+
+    fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+}
+
+const VARIABLES: &'static [&'static str] = &["a", "b", "c"];
+
+// An expression written the same way in Rust and in specifications. Inputs
+// lie in -10..10 and trees are at most three deep, so no value overflows
+fn expression(random: &mut Random, depth: u32) -> String {
+    if depth == 0 || random.below(3) == 0 {
+        return match random.below(2) {
+            0 => VARIABLES[random.below(VARIABLES.len() as u64) as usize].to_string(),
+            _ => random.below(10).to_string(),
+        };
+    }
+
+    let op = ["+", "-", "*"][random.below(3) as usize];
+    format!("({} {} {})",
+            expression(random, depth - 1),
+            op,
+            expression(random, depth - 1))
+}
+
+fn condition(random: &mut Random) -> String {
+    let op = ["<", "<=", ">", ">=", "==", "!="][random.below(6) as usize];
+    format!("{} {} {}", expression(random, 1), op, expression(random, 1))
+}
+
+// A body and the postcondition it satisfies
+fn function(random: &mut Random) -> (String, String) {
+    if random.below(2) == 0 {
+        let result = expression(random, 3);
+        return (result.clone(), format!("ret == {}", result));
+    }
+
+    let test = condition(random);
+    let then = expression(random, 3);
+    let otherwise = expression(random, 3);
+
+    (format!("if {} {{ {} }} else {{ {} }}", test, then, otherwise),
+     format!("(({}) => ret == {}) && (!({}) => ret == {})",
+             test,
+             then,
+             test,
+             otherwise))
+}
+
+// Whether Stanley printed `status` for the function `name`, however its
+// path is qualified
+fn reported(output: &str, status: &str, name: &str) -> bool {
+    output.lines().any(|line| {
+        line.starts_with(status) &&
+        (line[status.len()..] == *name || line.ends_with(&format!("::{}", name)))
+    })
+}
+
+fn main() {
+    let args = env::args().collect::<Vec<_>>();
+    let count = args.get(1).map_or(50, |n| n.parse().expect("count must be a number"));
+    let seed = args.get(2).map_or(0x5eed, |n| n.parse().expect("seed must be a number"));
+    let mut random = Random { state: seed | 1 };
+
+    let pre = VARIABLES.iter()
+        .map(|v| format!("{} >= -10:i32 && {} <= 10:i32", v, v))
+        .collect::<Vec<_>>()
+        .join(" && ");
+    let params = VARIABLES.iter().map(|v| format!("{}: i32", v)).collect::<Vec<_>>().join(", ");
+
+    let mut source = String::from("#![feature(plugin, custom_attribute)]\n\
+                                   #![plugin(stanley)]\n\
+                                   #![allow(dead_code, unused_parens, unused_variables)]\n");
+
+    for i in 0..count {
+        let (body, post) = function(&mut random);
+
+        source.push_str(&format!("\n#[requires = \"{}\"]\n#[ensures = \"{}\"]\n\
+                                  fn gen_{}({}) -> i32 {{\n    {}\n}}\n",
+                                 pre,
+                                 post,
+                                 i,
+                                 params,
+                                 body));
+        source.push_str(&format!("\n#[requires = \"{}\"]\n#[ensures = \"!({})\"]\n\
+                                  fn gen_{}_broken({}) -> i32 {{\n    {}\n}}\n",
+                                 pre,
+                                 post,
+                                 i,
+                                 params,
+                                 body));
+    }
+
+    fs::create_dir_all("target/stress").unwrap();
+    File::create("target/stress/generated.rs")
+        .and_then(|mut file| file.write_all(source.as_bytes()))
+        .unwrap();
+
+    let rustc = env::var("RUSTC").unwrap_or("rustc".to_string());
+    let output = Command::new(rustc)
+        .args(&["--crate-type", "lib", "-L", "target/debug/deps", "--out-dir", "target/stress"])
+        .arg("target/stress/generated.rs")
+        .output()
+        .expect("cannot run rustc");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    let mut failures = 0;
+
+    for i in 0..count {
+        if !reported(&stdout, "[VALID] -- ", &format!("gen_{}", i)) {
+            println!("gen_{}: correct contract not proved", i);
+            failures += 1;
+        }
+
+        if !reported(&stdout, "!! [INVALID] -- ", &format!("gen_{}_broken", i)) {
+            println!("gen_{}_broken: broken contract not refuted", i);
+            failures += 1;
+        }
+    }
+
+    println!("{} functions, {} failures; source in target/stress/generated.rs",
+             2 * count,
+             failures);
+
+    if failures > 0 || !output.status.success() {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,94 @@
+use ast::{self, BinaryOperator, Expression, Types, UnaryOperator};
+use std::rc::Rc;
+
+fn literals(expression: &Expression, found: &mut Vec<Expression>) {
+    match *expression {
+        Expression::BinaryExpression(ref left, _, ref right) => {
+            literals(left, found);
+            literals(right, found);
+        }
+        Expression::UnaryExpression(_, ref expr) => literals(expr, found),
+        Expression::BitVector(..) |
+        Expression::Float(..) => {
+            if !found.contains(expression) {
+                found.push(expression.clone());
+            }
+        }
+        _ => {}
+    }
+}
+
+fn compare(left: &Expression, op: BinaryOperator, right: &Expression) -> Expression {
+    Expression::BinaryExpression(Rc::new(left.clone()), op, Rc::new(right.clone()))
+}
+
+fn type_of(expression: &Expression) -> Types {
+    match *expression {
+        Expression::VariableMapping(_, ty) |
+        Expression::BitVector(_, ty) |
+        Expression::Float(_, ty) => ty,
+        _ => Types::Unknown,
+    }
+}
+
+// Template candidates for a missing precondition, simplest first: sign and
+// zero tests on each argument, comparisons against the constants already
+// used by the specification, then comparisons between arguments
+pub fn candidates(arguments: &[Expression], specification: &[&Expression]) -> Vec<Expression> {
+    let mut constants = Vec::new();
+
+    for expression in specification {
+        literals(expression, &mut constants);
+    }
+
+    let comparisons = [BinaryOperator::NotEqual,
+                       BinaryOperator::GreaterThan,
+                       BinaryOperator::GreaterThanOrEqual,
+                       BinaryOperator::LessThan,
+                       BinaryOperator::LessThanOrEqual];
+    let mut result = Vec::new();
+
+    for argument in arguments {
+        match type_of(argument) {
+            Types::Bool => {
+                result.push(argument.clone());
+                result.push(Expression::UnaryExpression(UnaryOperator::Not,
+                                                        Rc::new(argument.clone())));
+            }
+            Types::Void | Types::Unknown => {}
+            ty => {
+                let zero = if ast::is_float(ty) {
+                    ast::float(0.0, ty)
+                } else {
+                    Expression::BitVector(0, ty)
+                };
+
+                for op in &comparisons {
+                    result.push(compare(argument, *op, &zero));
+                }
+            }
+        }
+    }
+
+    for argument in arguments {
+        for constant in &constants {
+            if type_of(constant) == type_of(argument) {
+                for op in &comparisons {
+                    result.push(compare(argument, *op, constant));
+                }
+            }
+        }
+    }
+
+    for (i, left) in arguments.iter().enumerate() {
+        for right in &arguments[i + 1..] {
+            if type_of(left) == type_of(right) && type_of(left) != Types::Bool {
+                for op in &comparisons {
+                    result.push(compare(left, *op, right));
+                }
+            }
+        }
+    }
+
+    result
+}
@@ -0,0 +1,83 @@
+use ast::{self, BinaryOperator, Expression, Types};
+use rustc::mir::BinOp;
+use std::rc::Rc;
+
+// rustc follows every arithmetic operation that can overflow by an
+// `Assert` on whether it did. Checked operations give a pair of the wrapped
+// result and that flag, which is defined here from the operands, so the
+// assertion is an obligation like any other check
+
+fn binary(left: Expression, op: BinaryOperator, right: Expression) -> Expression {
+    Expression::BinaryExpression(Rc::new(left), op, Rc::new(right))
+}
+
+fn literal(value: i64, ty: Types) -> Expression {
+    Expression::BitVector(value, ty)
+}
+
+// Whether `a op b` overflows, for the operators of checked operations
+pub fn flag(op: BinOp, a: &Expression, b: &Expression) -> Expression {
+    let ty = ast::determine_evaluation_type(a);
+    let zero = literal(0, ty);
+    let result = binary(a.clone(),
+                        match op {
+                            BinOp::Add => BinaryOperator::Addition,
+                            BinOp::Sub => BinaryOperator::Subtraction,
+                            _ => BinaryOperator::Multiplication,
+                        },
+                        b.clone());
+
+    match op {
+        // Adding a nonnegative number wraps below where it started, and
+        // adding a negative one above
+        BinOp::Add | BinOp::Sub if ast::is_signed(ty) => {
+            let (up, down) = if op == BinOp::Add {
+                (BinaryOperator::LessThan, BinaryOperator::GreaterThan)
+            } else {
+                (BinaryOperator::GreaterThan, BinaryOperator::LessThan)
+            };
+            let nonnegative = binary(b.clone(), BinaryOperator::GreaterThanOrEqual, zero.clone());
+            let negative = binary(b.clone(), BinaryOperator::LessThan, zero);
+
+            binary(binary(nonnegative, BinaryOperator::And, binary(result.clone(), up, a.clone())),
+                   BinaryOperator::Or,
+                   binary(negative, BinaryOperator::And, binary(result, down, a.clone())))
+        }
+        BinOp::Add => binary(result, BinaryOperator::LessThan, a.clone()),
+        BinOp::Sub => binary(a.clone(), BinaryOperator::LessThan, b.clone()),
+        // A product that wraps does not divide back into its factor. The
+        // division itself wraps for `-1 * MIN`
+        BinOp::Mul => {
+            let wrapped = binary(binary(a.clone(), BinaryOperator::NotEqual, zero),
+                                 BinaryOperator::And,
+                                 binary(binary(result, BinaryOperator::Division, a.clone()),
+                                        BinaryOperator::NotEqual,
+                                        b.clone()));
+
+            if ast::is_signed(ty) {
+                let minimum = binary(binary(a.clone(), BinaryOperator::Equal, literal(-1, ty)),
+                                     BinaryOperator::And,
+                                     binary(b.clone(),
+                                            BinaryOperator::Equal,
+                                            ast::type_constant(ty, "MIN")));
+                binary(wrapped, BinaryOperator::Or, minimum)
+            } else {
+                wrapped
+            }
+        }
+        // Shifting by the width of the value or more
+        BinOp::Shl | BinOp::Shr => {
+            let by = ast::determine_evaluation_type(b);
+            let bits = ::bitvector_size(ty) as i64;
+            let negative = binary(b.clone(), BinaryOperator::LessThan, literal(0, by));
+            let wide = binary(b.clone(), BinaryOperator::GreaterThanOrEqual, literal(bits, by));
+
+            match (ast::is_signed(by), bits < 1 << (::bitvector_size(by) - 1)) {
+                (false, _) => wide,
+                (true, true) => binary(negative, BinaryOperator::Or, wide),
+                (true, false) => negative,
+            }
+        }
+        _ => Expression::BooleanLiteral(false),
+    }
+}
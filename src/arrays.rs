@@ -0,0 +1,161 @@
+use {MirData, gen_expression, gen_lvalue, referenced};
+use ast::{BinaryOperator, Expression, Types};
+use option;
+use rustc::mir::*;
+use rustc::ty::{Ty, TypeVariants};
+use std::rc::Rc;
+
+// An array of arrays, as in `[[f64; 3]; 3]`, is a single solver array of
+// its elements flattened row by row, so `m[i][j]` is element `i * 3 + j` of
+// `m`, in the body and in specifications alike. Arrays are values: they are
+// copied, passed and returned whole, and built from a list of elements or
+// by repeating one, which stores every element into the array built
+
+// Elements an array built in the body may have, counting those of its rows
+const MAX_ELEMENTS: usize = 1024;
+
+fn usize_literal(value: usize) -> Expression {
+    Expression::BitVector(value as i64, Types::Usize)
+}
+
+fn binary(left: Expression, op: BinaryOperator, right: Expression) -> Expression {
+    Expression::BinaryExpression(Rc::new(left), op, Rc::new(right))
+}
+
+fn element_of(ty: Ty) -> Option<Ty> {
+    match referenced(ty).sty {
+        TypeVariants::TyArray(element, _) |
+        TypeVariants::TySlice(element) => Some(element),
+        _ => None,
+    }
+}
+
+fn is_array(ty: Ty) -> bool {
+    match ty.sty {
+        TypeVariants::TyArray(..) => true,
+        _ => false,
+    }
+}
+
+// The elements of the flattened array a value of type `ty` takes: those of
+// its rows for an array, and one for anything else
+fn scalars(ty: Ty) -> usize {
+    match ty.sty {
+        TypeVariants::TyArray(element, length) => length * scalars(element),
+        _ => 1,
+    }
+}
+
+// `index` counted in rows of `stride` elements
+fn scaled(index: Expression, stride: usize) -> Expression {
+    if stride == 1 {
+        index
+    } else {
+        binary(index, BinaryOperator::Multiplication, usize_literal(stride))
+    }
+}
+
+// `base[index]` in the body, where `base` may be a row of an array of arrays
+pub fn element<'a, 'tcx>(base: &Lvalue<'tcx>,
+                         index: Expression,
+                         data: &MirData<'a, 'tcx>)
+                         -> Expression {
+    if let Lvalue::Projection(ref row) = *base {
+        if let ProjectionElem::Index(ref outer) = row.elem {
+            let stride = scalars(base.ty(data.mir, data.tcx).to_ty(data.tcx));
+            let index = binary(scaled(gen_expression(outer, data), stride),
+                               BinaryOperator::Addition,
+                               index);
+
+            return element(&row.base, index, data);
+        }
+    }
+
+    Expression::Select(Rc::new(gen_lvalue(base.clone(), data)), Rc::new(index))
+}
+
+// `a[i][j]` of a specification as an element of the flattened `a`, once
+// every index of it is given; rows and arrays of other elements are kept
+pub fn flattened(select: &Expression, data: &MirData) -> Option<Expression> {
+    let mut indices = Vec::new();
+    let mut base = select;
+
+    while let Expression::Select(ref array, ref index) = *base {
+        indices.push((**index).clone());
+        base = &**array;
+    }
+
+    let mut ty = match *base {
+        Expression::Array(ref name, _) if indices.len() > 1 => {
+            let array = Expression::VariableMapping(name.clone(), Types::Unknown);
+
+            match option::specified_type(&array, data) {
+                Some(ty) => ty,
+                None => return None,
+            }
+        }
+        _ => return None,
+    };
+    let mut offset: Option<Expression> = None;
+
+    for index in indices.into_iter().rev() {
+        ty = match element_of(ty) {
+            Some(element) => element,
+            None => return None,
+        };
+
+        let term = scaled(index, scalars(ty));
+        offset = Some(match offset {
+                          Some(offset) => binary(offset, BinaryOperator::Addition, term),
+                          None => term,
+                      });
+    }
+
+    if is_array(ty) {
+        return None;
+    }
+
+    offset.map(|offset| Expression::Select(Rc::new(base.clone()), Rc::new(offset)))
+}
+
+// The array `dest` becomes when `rvalue`, a list of elements or a repeated
+// one, is assigned to it
+pub fn build<'a, 'tcx>(dest: &Expression,
+                       rvalue: &Rvalue<'tcx>,
+                       data: &MirData<'a, 'tcx>)
+                       -> Expression {
+    let ty = rvalue.ty(data.mir, data.tcx)
+        .unwrap_or_else(|| error!("`{:?}` has no known type", rvalue));
+    let (element, length) = match ty.sty {
+        TypeVariants::TyArray(element, length) => (element, length),
+        _ => error!("`{:?}` does not build an array", rvalue),
+    };
+    let stride = scalars(element);
+
+    if length * stride > MAX_ELEMENTS {
+        error!("Building an array of more than {} elements is not supported: `{:?}`",
+               MAX_ELEMENTS,
+               rvalue);
+    }
+
+    (0..length).fold(dest.clone(), |array, i| {
+        let value = match *rvalue {
+            Rvalue::Aggregate(_, ref operands) => gen_expression(&operands[i], data),
+            Rvalue::Repeat(ref operand, _) => gen_expression(operand, data),
+            _ => unreachable!(),
+        };
+
+        (0..stride).fold(array, |array, k| {
+            // The elements of a row are copied one by one
+            let value = if is_array(element) {
+                Expression::Select(Rc::new(value.clone()), Rc::new(usize_literal(k)))
+            } else {
+                value.clone()
+            };
+
+            Expression::Store(Rc::new(array),
+                              Rc::new(usize_literal(i * stride + k)),
+                              Rc::new(value))
+        })
+    })
+}
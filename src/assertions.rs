@@ -0,0 +1,117 @@
+use {MirData, is_panic, panic_message};
+use ast::{BinaryOperator, Expression};
+use rustc::mir::*;
+use self_composition::Composition;
+use std::rc::Rc;
+
+// `stanley::assert!(condition)` states a fact in the middle of a body. At
+// run time it panics like `assert!` when the condition is false, with a
+// message naming it. Each is proved on its own, as an obligation that no
+// run satisfying the precondition fails it, and the rest of the function is
+// then proved with the condition assumed after it, which splits a hard
+// proof into steps the solver finds separately. `stanley::assume!` states
+// a fact the prover cannot derive, say about a library call: it is assumed
+// from there on without a proof, and listed in the ledger of the proofs
+// relying on it. Crates using them need `#![feature(use_extern_macros)]`
+// and `extern crate stanley;`
+
+// What a failed assertion panics with, ahead of its condition
+const FAILED: &'static str = "stanley assertion failed: ";
+const ASSUMED: &'static str = "stanley assumption failed: ";
+
+#[macro_export]
+macro_rules! assert {
+    ($condition:expr $(,)*) => (
+        if !$condition {
+            panic!(concat!("stanley assertion failed: ", stringify!($condition)))
+        }
+    );
+}
+
+#[macro_export]
+macro_rules! assume {
+    ($condition:expr $(,)*) => (
+        if !$condition {
+            panic!(concat!("stanley assumption failed: ", stringify!($condition)))
+        }
+    );
+}
+
+// Whether a panic with `message` is that of a failed `stanley::assert!`
+pub fn is_assertion(message: &str) -> bool {
+    message.starts_with(FAILED)
+}
+
+// Whether a panic with `message` is that of a failed `stanley::assume!`
+pub fn is_assumption(message: &str) -> bool {
+    message.starts_with(ASSUMED)
+}
+
+fn fails_assertion(terminator: &Terminator) -> bool {
+    match terminator.kind {
+        TerminatorKind::Call { ref func, ref args, .. } if is_panic(func) => {
+            panic_message(args).map_or(false, |message| is_assertion(&message))
+        }
+        _ => false,
+    }
+}
+
+// Whether reaching `block` fails an assertion taken as proved, as those of
+// the verified function are; those of inlined callees are not proved with it
+pub fn is_assumed(block: usize, data: &MirData) -> bool {
+    if let Composition::Inlined(_) = data.composition {
+        return false;
+    }
+
+    fails_assertion(data.block_data[block].terminator())
+}
+
+// The ledger entry of the assumption `block` fails, if any; assumptions
+// hold wherever they are, also in inlined callees
+pub fn assumption(block: usize, data: &MirData) -> Option<String> {
+    let terminator = data.block_data[block].terminator();
+
+    match terminator.kind {
+        TerminatorKind::Call { ref func, ref args, .. } if is_panic(func) => {
+            match panic_message(args) {
+                Some(ref message) if is_assumption(message) => {
+                    let codemap = data.tcx.sess.codemap();
+                    let span = terminator.source_info.span.source_callsite();
+
+                    Some(format!("`stanley::assume!({})` at {}",
+                                 &message[ASSUMED.len()..],
+                                 codemap.span_to_string(span)))
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+// Whether a body has assertions to prove
+pub fn any(mir: &Mir) -> bool {
+    mir.basic_blocks().iter().any(|block| fails_assertion(block.terminator()))
+}
+
+// The obligation of every assertion of the verified function under `pre`,
+// labelled with where it is
+pub fn obligations(pre: &Expression, data: &MirData) -> Vec<(String, Expression)> {
+    let codemap = data.tcx.sess.codemap();
+
+    (0..data.block_data.len())
+        .filter(|&index| is_assumed(index, data))
+        .map(|index| {
+            let span = data.block_data[index].terminator().source_info.span.source_callsite();
+            let mut probe = data.with_composition(Composition::Single);
+            probe.probe = Some(index);
+            probe.probe_check = false;
+            let holds = probe.weakest_precondition(&Expression::BooleanLiteral(true));
+
+            (format!("assertion at {}", codemap.span_to_string(span)),
+             Expression::BinaryExpression(Rc::new(pre.clone()),
+                                          BinaryOperator::Implication,
+                                          Rc::new(holds)))
+        })
+        .collect()
+}
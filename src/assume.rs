@@ -0,0 +1,55 @@
+use config::Config;
+use rustc::hir::{self, intravisit};
+use rustc::hir::intravisit::{NestedVisitorMap, Visitor};
+use rustc::ty::TyCtxt;
+use syntax::ast::{Attribute, NodeId};
+use syntax::codemap::Span;
+
+// Calls marked `#[assume_pre]`, on the call itself or on the statement
+// containing it, assume the callee's precondition instead of proving it;
+// each such assumption is reported with the proofs relying on it
+
+struct Marked<'a> {
+    config: &'a Config,
+    spans: Vec<Span>,
+}
+
+impl<'a> Marked<'a> {
+    fn mark(&mut self, attrs: &[Attribute], span: Span) {
+        if attrs.iter().any(|attr| self.config.recognizes(attr, "assume_pre")) {
+            self.spans.push(span);
+        }
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for Marked<'a> {
+    // Closures are verified on their own
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::None
+    }
+
+    fn visit_expr(&mut self, expr: &'tcx hir::Expr) {
+        self.mark(&expr.attrs, expr.span);
+        intravisit::walk_expr(self, expr);
+    }
+
+    fn visit_local(&mut self, local: &'tcx hir::Local) {
+        self.mark(&local.attrs, local.span);
+        intravisit::walk_local(self, local);
+    }
+}
+
+pub fn marked_calls(tcx: TyCtxt, id: NodeId, config: &Config) -> Vec<Span> {
+    let body = tcx.hir.body(tcx.hir.body_owned_by(id));
+    let mut marked = Marked {
+        config: config,
+        spans: Vec::new(),
+    };
+
+    marked.visit_body(body);
+    marked.spans
+}
+
+pub fn is_marked(call: Span, marked: &[Span]) -> bool {
+    marked.iter().any(|span| span.lo <= call.lo && call.hi <= span.hi)
+}
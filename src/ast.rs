@@ -1,19 +1,35 @@
-use rustc::ty::Ty;
+use rustc::ty::{AdtDef, Ty};
 use rustc::ty::TypeVariants::*;
-use std::fmt::{Debug, Error, Formatter};
+use std::collections::HashMap;
+use std::fmt::{Debug, Display, Error, Formatter};
+use std::rc::Rc;
+use std::str::FromStr;
+use syntax::ast::FloatTy;
 use syntax::ast::IntTy::*;
 use syntax::ast::UintTy::*;
 
-#[derive(Clone, PartialEq)]
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub enum Expression {
-    BinaryExpression(Box<Expression>, BinaryOperator, Box<Expression>),
-    UnaryExpression(UnaryOperator, Box<Expression>),
+    BinaryExpression(Rc<Expression>, BinaryOperator, Rc<Expression>),
+    UnaryExpression(UnaryOperator, Rc<Expression>),
     VariableMapping(String, Types),
     BitVector(i64, Types),
+    // A floating-point literal by the bits of its IEEE 754 value; literals
+    // without a type keep those of an `f64`
+    Float(u64, Types),
     BooleanLiteral(bool),
+    // Application of a function registered by a theory, with its result type
+    FunctionCall(String, Vec<Expression>, Types),
+    // The contents of a slice or array, with the type of its elements
+    Array(String, Types),
+    Length(Rc<Expression>),
+    Select(Rc<Expression>, Rc<Expression>),
+    Store(Rc<Expression>, Rc<Expression>, Rc<Expression>),
+    // A bound variable with its type, and the body it is bound in
+    Forall(String, Types, Rc<Expression>),
 }
 
-#[derive(Copy, Clone, PartialEq)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
 pub enum BinaryOperator {
     // Normal operators
     Addition,
@@ -42,22 +58,31 @@ pub enum BinaryOperator {
     BiImplication,
 }
 
-#[derive(Clone, PartialEq)]
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub enum UnaryOperator {
     Negation,
     Not,
+    // Conversion to an integer type at least as wide, extending the operand
+    // by its own signedness like `as` does
+    Convert(Types),
 }
 
-#[derive(Clone, PartialEq, Copy)]
+#[derive(Clone, PartialEq, Eq, Hash, Copy)]
 pub enum Types {
     I8,
     I16,
     I32,
     I64,
+    I128,
     U8,
     U16,
     U32,
     U64,
+    U128,
+    Usize,
+    Isize,
+    F32,
+    F64,
     Bool,
     Void,
     Unknown,
@@ -66,13 +91,16 @@ pub enum Types {
 pub fn type_to_enum(x: Ty) -> Types {
     match x.sty {
         TyBool => Types::Bool,
+        // A `char` is its scalar value
+        TyChar => Types::U32,
         TyInt(a) => {
             match a {
                 I8 => Types::I8,
                 I16 => Types::I16,
                 I32 => Types::I32,
                 I64 => Types::I64,
-                _ => unreachable!(),
+                I128 => Types::I128,
+                Is => Types::Isize,
             }
         }
         TyUint(a) => {
@@ -81,13 +109,69 @@ pub fn type_to_enum(x: Ty) -> Types {
                 U16 => Types::U16,
                 U32 => Types::U32,
                 U64 => Types::U64,
-                _ => unreachable!(),
+                U128 => Types::U128,
+                Us => Types::Usize,
             }
         }
+        TyFloat(FloatTy::F32) => Types::F32,
+        TyFloat(FloatTy::F64) => Types::F64,
+        // References and boxes are modelled by the value they point to
+        TyRef(_, ref referenced) => type_to_enum(referenced.ty),
+        _ if x.is_box() => type_to_enum(x.boxed_ty()),
+        // Options and results are modelled by their payload, or their `Ok`
+        // payload, and separate parts
+        TyAdt(adt, substs) if is_option_adt(adt) || is_result_adt(adt) => {
+            type_to_enum(substs.type_at(0))
+        }
         _ => Types::Unknown,
     }
 }
 
+// `Option`, recognized by its variants as paths cannot be resolved here
+pub fn is_option_adt(adt: &AdtDef) -> bool {
+    adt.is_enum() && adt.variants.len() == 2 && &*adt.variants[0].name.as_str() == "None" &&
+    &*adt.variants[1].name.as_str() == "Some"
+}
+
+// Options, also behind references
+pub fn is_option(x: Ty) -> bool {
+    match x.sty {
+        TyRef(_, ref referenced) => is_option(referenced.ty),
+        TyAdt(adt, _) => is_option_adt(adt),
+        _ => false,
+    }
+}
+
+// `Result`, recognized by its variants like `Option`
+pub fn is_result_adt(adt: &AdtDef) -> bool {
+    adt.is_enum() && adt.variants.len() == 2 && &*adt.variants[0].name.as_str() == "Ok" &&
+    &*adt.variants[1].name.as_str() == "Err"
+}
+
+// Results, also behind references
+pub fn is_result(x: Ty) -> bool {
+    match x.sty {
+        TyRef(_, ref referenced) => is_result(referenced.ty),
+        TyAdt(adt, _) => is_result_adt(adt),
+        _ => false,
+    }
+}
+
+// The type of the elements of slices and arrays, also behind references
+pub fn element_type(x: Ty) -> Option<Types> {
+    match x.sty {
+        TyRef(_, ref referenced) => element_type(referenced.ty),
+        // Arrays of arrays are flattened into arrays of their elements
+        TySlice(element) | TyArray(element, _) => {
+            match element.sty {
+                TyArray(..) => element_type(element),
+                _ => Some(type_to_enum(element)),
+            }
+        }
+        _ => None,
+    }
+}
+
 pub fn string_to_type(s: String) -> Types {
     match s.as_str() {
         "bool" => Types::Bool,
@@ -95,10 +179,17 @@ pub fn string_to_type(s: String) -> Types {
         "i16" => Types::I16,
         "i32" => Types::I32,
         "i64" => Types::I64,
+        "i128" => Types::I128,
         "u8" => Types::U8,
         "u16" => Types::U16,
         "u32" => Types::U32,
         "u64" => Types::U64,
+        "u128" => Types::U128,
+        "usize" => Types::Usize,
+        "isize" => Types::Isize,
+        "f32" => Types::F32,
+        "f64" => Types::F64,
+        "char" => Types::U32,
         "()" => Types::Void,
         _ => unimplemented!(),
     }
@@ -113,7 +204,16 @@ impl Debug for Expression {
             Expression::UnaryExpression(ref op, ref r) => write!(fmt, "({:?} {:?})", op, r),
             Expression::VariableMapping(ref name, _) => write!(fmt, "{}", name),
             Expression::BitVector(ref val, _) => write!(fmt, "{:?}", val),
+            Expression::Float(bits, ty) => write!(fmt, "{}", float_text(bits, ty)),
             Expression::BooleanLiteral(ref b) => write!(fmt, "{:?}", b),
+            Expression::FunctionCall(ref name, ref args, _) => write!(fmt, "({} {:?})", name, args),
+            Expression::Array(ref name, _) => write!(fmt, "{}", name),
+            Expression::Length(ref array) => write!(fmt, "(len {:?})", array),
+            Expression::Select(ref array, ref index) => write!(fmt, "(select {:?} {:?})", array, index),
+            Expression::Store(ref array, ref index, ref value) => {
+                write!(fmt, "(store {:?} {:?} {:?})", array, index, value)
+            }
+            Expression::Forall(ref var, _, ref body) => write!(fmt, "(∀ {} {:?})", var, body),
         }
     }
 }
@@ -151,6 +251,7 @@ impl Debug for UnaryOperator {
         match *self {
             UnaryOperator::Negation => write!(fmt, "-"),
             UnaryOperator::Not => write!(fmt, "¬"),
+            UnaryOperator::Convert(ty) => write!(fmt, "as {:?}", ty),
         }
     }
 }
@@ -163,16 +264,121 @@ impl Debug for Types {
             Types::I16 => write!(fmt, "i16"),
             Types::I32 => write!(fmt, "i32"),
             Types::I64 => write!(fmt, "i64"),
+            Types::I128 => write!(fmt, "i128"),
             Types::U8 => write!(fmt, "u8"),
             Types::U16 => write!(fmt, "u16"),
             Types::U32 => write!(fmt, "u32"),
             Types::U64 => write!(fmt, "u64"),
+            Types::U128 => write!(fmt, "u128"),
+            Types::Usize => write!(fmt, "usize"),
+            Types::Isize => write!(fmt, "isize"),
+            Types::F32 => write!(fmt, "f32"),
+            Types::F64 => write!(fmt, "f64"),
             Types::Void => write!(fmt, "()"),
             Types::Unknown => write!(fmt, "?"),
         }
     }
 }
 
+// Display renders expressions back into the condition language
+impl Display for Expression {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        match *self {
+            Expression::BinaryExpression(ref l, op, ref r) => {
+                write_operand(fmt, l)?;
+                write!(fmt, " {} ", op)?;
+                write_operand(fmt, r)
+            }
+            Expression::UnaryExpression(ref op @ UnaryOperator::Convert(_), ref r) => {
+                write!(fmt, "{}({})", op, r)
+            }
+            Expression::UnaryExpression(ref op, ref r) => {
+                write!(fmt, "{}", op)?;
+                write_operand(fmt, r)
+            }
+            Expression::VariableMapping(ref name, _) => write!(fmt, "{}", name),
+            Expression::BitVector(ref val, Types::Unknown) => write!(fmt, "{}", val),
+            Expression::BitVector(ref val, ref ty) => write!(fmt, "{}:{:?}", val, ty),
+            Expression::Float(bits, Types::Unknown) => {
+                write!(fmt, "{}", float_text(bits, Types::Unknown))
+            }
+            Expression::Float(bits, ty) => {
+                let value = float_value(bits, ty);
+
+                if value.is_nan() {
+                    write!(fmt, "{:?}::NAN", ty)
+                } else if value.is_infinite() {
+                    write!(fmt, "{}{:?}::INFINITY", if value < 0.0 { "-" } else { "" }, ty)
+                } else {
+                    write!(fmt, "{}:{:?}", float_text(bits, ty), ty)
+                }
+            }
+            Expression::BooleanLiteral(ref b) => write!(fmt, "{}", b),
+            Expression::FunctionCall(ref name, ref args, _) => {
+                write!(fmt, "{}(", name)?;
+
+                for (i, arg) in args.iter().enumerate() {
+                    if i > 0 {
+                        write!(fmt, ", ")?;
+                    }
+
+                    write!(fmt, "{}", arg)?;
+                }
+
+                write!(fmt, ")")
+            }
+            Expression::Array(ref name, _) => write!(fmt, "{}", name),
+            Expression::Length(ref array) => {
+                write_operand(fmt, array)?;
+                write!(fmt, ".len()")
+            }
+            Expression::Select(ref array, ref index) => {
+                write_operand(fmt, array)?;
+                write!(fmt, "[{}]", index)
+            }
+            Expression::Store(ref array, ref index, ref value) => {
+                write_operand(fmt, array)?;
+                write!(fmt, "[{} := {}]", index, value)
+            }
+            Expression::Forall(ref var, ref ty, ref body) => {
+                write!(fmt, "forall {}:{:?}: {}", var, ty, body)
+            }
+        }
+    }
+}
+
+fn write_operand(fmt: &mut Formatter, expression: &Expression) -> Result<(), Error> {
+    match *expression {
+        Expression::BinaryExpression(..) |
+        Expression::Forall(..) => write!(fmt, "({})", expression),
+        _ => write!(fmt, "{}", expression),
+    }
+}
+
+impl Display for BinaryOperator {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        match *self {
+            BinaryOperator::And => write!(fmt, "&&"),
+            BinaryOperator::Or => write!(fmt, "||"),
+            BinaryOperator::Implication => write!(fmt, "=>"),
+            BinaryOperator::BiImplication => write!(fmt, "<=>"),
+            _ => write!(fmt, "{:?}", self),
+        }
+    }
+}
+
+impl Display for UnaryOperator {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        match *self {
+            UnaryOperator::Negation => write!(fmt, "-"),
+            UnaryOperator::Not => write!(fmt, "!"),
+            UnaryOperator::Convert(Types::I64) => write!(fmt, "int"),
+            UnaryOperator::Convert(Types::U64) => write!(fmt, "uint"),
+            UnaryOperator::Convert(ty) => write!(fmt, "as {:?}", ty),
+        }
+    }
+}
+
 pub fn determine_evaluation_type(expression: &Expression) -> Types {
     match ty_check(expression) {
         Ok(_) => {
@@ -202,9 +408,18 @@ pub fn determine_evaluation_type(expression: &Expression) -> Types {
                         BinaryOperator::BiImplication => Types::Bool,
                     }
                 }
+                Expression::UnaryExpression(UnaryOperator::Convert(ty), _) => ty,
                 Expression::UnaryExpression(_, ref expr) => determine_evaluation_type(expr),
-                Expression::VariableMapping(_, ref ty) => *ty,
-                Expression::BooleanLiteral(_) => Types::Bool,
+                Expression::VariableMapping(_, ref ty) |
+                Expression::FunctionCall(_, _, ref ty) |
+                Expression::Array(_, ref ty) |
+                Expression::Float(_, ref ty) => *ty,
+                // Arrays evaluate to the type of their elements
+                Expression::Select(ref array, _) |
+                Expression::Store(ref array, _, _) => determine_evaluation_type(array),
+                Expression::Length(_) => Types::Usize,
+                Expression::BooleanLiteral(_) |
+                Expression::Forall(..) => Types::Bool,
                 Expression::BitVector(_, ref ty) => {
                     match *ty {
                         Types::Bool | Types::Void | Types::Unknown => {
@@ -228,18 +443,20 @@ pub fn simplify_expression(expression: &Expression) -> Expression {
             if *op == BinaryOperator::Equal {
                 match aa {
                     Expression::BooleanLiteral(val) if val => return ca.clone(),
-                    Expression::BooleanLiteral(_) => return simplify_expression(&Expression::UnaryExpression(UnaryOperator::Not, Box::new(ca.clone()))),
+                    Expression::BooleanLiteral(_) => return simplify_expression(&Expression::UnaryExpression(UnaryOperator::Not, Rc::new(ca.clone()))),
                     _ => {
                         match ca {
                             Expression::BooleanLiteral(val2) if val2 => return aa.clone(),
-                            Expression::BooleanLiteral(_) => return simplify_expression(&Expression::UnaryExpression(UnaryOperator::Not, Box::new(aa.clone()))),
+                            Expression::BooleanLiteral(_) => return simplify_expression(&Expression::UnaryExpression(UnaryOperator::Not, Rc::new(aa.clone()))),
                             _ => {}
                         }
                     }
                 };
             }
 
-            if (*op == BinaryOperator::Implication || *op == BinaryOperator::Equal) && aa == ca {
+            // `NaN` is not equal to itself
+            if (*op == BinaryOperator::Implication ||
+                (*op == BinaryOperator::Equal && !any_type(&aa, &is_float))) && aa == ca {
                 return Expression::BooleanLiteral(true);
             }
 
@@ -286,7 +503,7 @@ pub fn simplify_expression(expression: &Expression) -> Expression {
                 }
             }
 
-            Expression::BinaryExpression(Box::new(aa), *op, Box::new(ca))
+            Expression::BinaryExpression(Rc::new(aa), *op, Rc::new(ca))
         }
         Expression::UnaryExpression(ref a, ref b) => {
             let ba = simplify_expression(b);
@@ -307,29 +524,277 @@ pub fn simplify_expression(expression: &Expression) -> Expression {
                                       }
                            }
                            Expression::BooleanLiteral(value) => Expression::BooleanLiteral(!value),
-                           _ => Expression::UnaryExpression(a.clone(), Box::new(ba)),
+                           _ => Expression::UnaryExpression(a.clone(), Rc::new(ba)),
                        };
             }
 
-            Expression::UnaryExpression(a.clone(), Box::new(ba))
+            Expression::UnaryExpression(a.clone(), Rc::new(ba))
+        }
+        Expression::FunctionCall(ref name, ref args, ty) => {
+            Expression::FunctionCall(name.clone(), args.iter().map(simplify_expression).collect(), ty)
+        }
+        Expression::Select(ref array, ref index) => {
+            let array = simplify_expression(array);
+            let index = simplify_expression(index);
+
+            // Reading back a value just stored needs no array reasoning
+            if let Expression::Store(ref inner, ref stored_index, ref value) = array {
+                if **stored_index == index {
+                    return (**value).clone();
+                }
+
+                if let (&Expression::BitVector(a, _), &Expression::BitVector(b, _)) =
+                    (&**stored_index, &index) {
+                    if a != b {
+                        return simplify_expression(&Expression::Select(inner.clone(),
+                                                                       Rc::new(index.clone())));
+                    }
+                }
+            }
+
+            Expression::Select(Rc::new(array), Rc::new(index))
+        }
+        // Stores keep the length
+        Expression::Length(ref array) => {
+            match simplify_expression(array) {
+                Expression::Store(array, _, _) => {
+                    simplify_expression(&Expression::Length(array))
+                }
+                array => Expression::Length(Rc::new(array)),
+            }
+        }
+        Expression::Store(ref array, ref index, ref value) => {
+            Expression::Store(Rc::new(simplify_expression(array)),
+                              Rc::new(simplify_expression(index)),
+                              Rc::new(simplify_expression(value)))
+        }
+        Expression::Forall(ref var, ty, ref body) => {
+            match simplify_expression(body) {
+                Expression::BooleanLiteral(value) => Expression::BooleanLiteral(value),
+                body => Expression::Forall(var.clone(), ty, Rc::new(body)),
+            }
+        }
+        _ => expression.clone(),
+    }
+}
+
+// Renames variables by order of first occurrence, so obligations that only
+// differ in the names of their variables become identical
+pub fn normalize_variables(expression: &Expression,
+                           names: &mut HashMap<String, String>)
+                           -> Expression {
+    match *expression {
+        Expression::BinaryExpression(ref left, ref op, ref right) => {
+            let new_left = normalize_variables(left, names);
+            let new_right = normalize_variables(right, names);
+            Expression::BinaryExpression(Rc::new(new_left), *op, Rc::new(new_right))
+        }
+        Expression::UnaryExpression(ref op, ref expr) => {
+            Expression::UnaryExpression(op.clone(), Rc::new(normalize_variables(expr, names)))
+        }
+        Expression::VariableMapping(ref name, ref ty) => {
+            let next = format!("v{}", names.len());
+            let normalized = names.entry(name.clone()).or_insert(next).clone();
+            Expression::VariableMapping(normalized, *ty)
+        }
+        Expression::FunctionCall(ref name, ref args, ty) => {
+            Expression::FunctionCall(name.clone(),
+                                     args.iter().map(|arg| normalize_variables(arg, names)).collect(),
+                                     ty)
+        }
+        Expression::Array(ref name, ref ty) => {
+            let next = format!("v{}", names.len());
+            let normalized = names.entry(name.clone()).or_insert(next).clone();
+            Expression::Array(normalized, *ty)
+        }
+        Expression::Length(ref array) => {
+            Expression::Length(Rc::new(normalize_variables(array, names)))
+        }
+        Expression::Select(ref array, ref index) => {
+            let array = normalize_variables(array, names);
+            Expression::Select(Rc::new(array), Rc::new(normalize_variables(index, names)))
+        }
+        Expression::Store(ref array, ref index, ref value) => {
+            let array = normalize_variables(array, names);
+            let index = normalize_variables(index, names);
+            Expression::Store(Rc::new(array),
+                              Rc::new(index),
+                              Rc::new(normalize_variables(value, names)))
+        }
+        Expression::Forall(ref var, ty, ref body) => {
+            let next = format!("v{}", names.len());
+            let normalized = names.entry(var.clone()).or_insert(next).clone();
+            Expression::Forall(normalized, ty, Rc::new(normalize_variables(body, names)))
         }
         _ => expression.clone(),
     }
 }
 
+// Booleans are ordered with `false` below `true`, as in Rust, which the
+// solver only knows for numbers; `None` for other comparisons
+pub fn boolean_ordering(left: &Expression,
+                        op: BinaryOperator,
+                        right: &Expression)
+                        -> Option<Expression> {
+    if determine_evaluation_type(left) != Types::Bool {
+        return None;
+    }
+
+    let not = |e: &Expression| Expression::UnaryExpression(UnaryOperator::Not, Rc::new(e.clone()));
+    let binary = |l: Expression, op: BinaryOperator, r: Expression| {
+        Expression::BinaryExpression(Rc::new(l), op, Rc::new(r))
+    };
+
+    match op {
+        BinaryOperator::LessThan => Some(binary(not(left), BinaryOperator::And, right.clone())),
+        BinaryOperator::LessThanOrEqual => {
+            Some(binary(left.clone(), BinaryOperator::Implication, right.clone()))
+        }
+        BinaryOperator::GreaterThan => Some(binary(left.clone(), BinaryOperator::And, not(right))),
+        BinaryOperator::GreaterThanOrEqual => {
+            Some(binary(right.clone(), BinaryOperator::Implication, left.clone()))
+        }
+        _ => None,
+    }
+}
+
+pub fn is_signed(ty: Types) -> bool {
+    match ty {
+        Types::I8 | Types::I16 | Types::I32 | Types::I64 | Types::I128 | Types::Isize => true,
+        _ => false,
+    }
+}
+
+// Whether a literal fits its type; literals are kept in 64 bits, which
+// 128-bit types extend by their signedness
+pub fn fits(value: i64, ty: Types) -> bool {
+    match (::bitvector_size(ty), is_signed(ty)) {
+        (bits, true) if bits >= 64 => true,
+        (bits, false) if bits >= 64 => value >= 0,
+        (bits, true) => -(1 << (bits - 1)) <= value && value < 1 << (bits - 1),
+        (bits, false) => 0 <= value && value < 1 << bits,
+    }
+}
+
+// A literal as written in a specification, of the given type or, when it
+// has none, of the type of what it is combined with
+pub fn literal(text: &str, ty: Types) -> Expression {
+    if ty == Types::Unknown {
+        return match i64::from_str(text) {
+            Ok(value) => Expression::BitVector(value, ty),
+            Err(_) => {
+                error!("`{}` is out of range for an untyped literal; annotate its type, as in \
+                        `{}:u64`",
+                       text,
+                       text)
+            }
+        };
+    }
+
+    let value = match (i64::from_str(text), u64::from_str(text)) {
+        (Ok(value), _) if fits(value, ty) => value,
+        // Unsigned literals beyond `i64::MAX` keep their bits
+        (Err(_), Ok(value)) if !is_signed(ty) => value as i64,
+        _ => error!("`{}` is out of range for `{:?}`", text, ty),
+    };
+
+    Expression::BitVector(value, ty)
+}
+
+pub fn is_float(ty: Types) -> bool {
+    ty == Types::F32 || ty == Types::F64
+}
+
+pub fn float_value(bits: u64, ty: Types) -> f64 {
+    match ty {
+        Types::F32 => f32::from_bits(bits as u32) as f64,
+        _ => f64::from_bits(bits),
+    }
+}
+
+// The shortest text reading back as the same value, always with a point
+// or an exponent, so it is not taken for an integer
+fn float_text(bits: u64, ty: Types) -> String {
+    let text = match ty {
+        Types::F32 => format!("{:?}", f32::from_bits(bits as u32)),
+        _ => format!("{:?}", f64::from_bits(bits)),
+    };
+
+    if text.contains(|c: char| !c.is_digit(10) && c != '-') {
+        text
+    } else {
+        format!("{}.0", text)
+    }
+}
+
+pub fn float(value: f64, ty: Types) -> Expression {
+    match ty {
+        Types::F32 => Expression::Float((value as f32).to_bits() as u64, ty),
+        _ => Expression::Float(value.to_bits(), ty),
+    }
+}
+
+// A floating-point literal as written in a specification, rounded to the
+// nearest value of its type
+pub fn float_literal(text: &str, ty: Types) -> Expression {
+    match ty {
+        Types::F32 => {
+            let value = f32::from_str(text).unwrap_or_else(|e| error!("`{}`: {}", text, e));
+            Expression::Float(value.to_bits() as u64, ty)
+        }
+        Types::F64 | Types::Unknown => {
+            let value = f64::from_str(text).unwrap_or_else(|e| error!("`{}`: {}", text, e));
+            Expression::Float(value.to_bits(), ty)
+        }
+        _ => error!("`{}` is a floating-point literal, not a `{:?}`", text, ty),
+    }
+}
+
+// Whether the type of any part of an expression satisfies `predicate`
+pub fn any_type<F: Fn(Types) -> bool>(expression: &Expression, predicate: &F) -> bool {
+    match *expression {
+        Expression::BinaryExpression(ref left, _, ref right) |
+        Expression::Select(ref left, ref right) => {
+            any_type(left, predicate) || any_type(right, predicate)
+        }
+        Expression::UnaryExpression(UnaryOperator::Convert(ty), ref expr) => {
+            predicate(ty) || any_type(expr, predicate)
+        }
+        Expression::UnaryExpression(_, ref expr) |
+        Expression::Length(ref expr) => any_type(expr, predicate),
+        Expression::Forall(_, ty, ref body) => predicate(ty) || any_type(body, predicate),
+        Expression::Store(ref array, ref index, ref value) => {
+            any_type(array, predicate) || any_type(index, predicate) ||
+            any_type(value, predicate)
+        }
+        Expression::FunctionCall(_, ref args, ty) => {
+            predicate(ty) || args.iter().any(|arg| any_type(arg, predicate))
+        }
+        Expression::VariableMapping(_, ty) |
+        Expression::BitVector(_, ty) |
+        Expression::Float(_, ty) |
+        Expression::Array(_, ty) => predicate(ty),
+        Expression::BooleanLiteral(_) => false,
+    }
+}
+
 pub fn same_signedness(type1: Types, type2: Types) -> bool {
     match type1 {
-        Types::U8 | Types::U16 | Types::U32 | Types::U64 => {
+        Types::U8 | Types::U16 | Types::U32 | Types::U64 | Types::U128 | Types::Usize => {
             match type2 {
-                Types::U8 | Types::U16 | Types::U32 | Types::U64 => true,
-                Types::I8 | Types::I16 | Types::I32 | Types::I64 => false,
+                Types::U8 | Types::U16 | Types::U32 | Types::U64 | Types::U128 |
+                Types::Usize => true,
+                Types::I8 | Types::I16 | Types::I32 | Types::I64 | Types::I128 |
+                Types::Isize => false,
                 _ => error!("Cannot find numeric signedness of `{:?}`", type2),
             }
         }
-        Types::I8 | Types::I16 | Types::I32 | Types::I64 => {
+        Types::I8 | Types::I16 | Types::I32 | Types::I64 | Types::I128 | Types::Isize => {
             match type2 {
-                Types::U8 | Types::U16 | Types::U32 | Types::U64 => false,
-                Types::I8 | Types::I16 | Types::I32 | Types::I64 => true,
+                Types::U8 | Types::U16 | Types::U32 | Types::U64 | Types::U128 |
+                Types::Usize => false,
+                Types::I8 | Types::I16 | Types::I32 | Types::I64 | Types::I128 |
+                Types::Isize => true,
                 _ => error!("Cannot find numeric signedness of `{:?}`", type2),
             }
         }
@@ -340,6 +805,10 @@ pub fn same_signedness(type1: Types, type2: Types) -> bool {
 pub fn ty_check(expression: &Expression) -> Result<bool, String> {
     match *expression {
         Expression::BooleanLiteral(_) => Ok(true),
+        Expression::Float(_, Types::Unknown) => {
+            Err(format!("The floating-point literal `{}` has no type", expression))
+        }
+        Expression::Float(..) => Ok(true),
         Expression::VariableMapping(ref name, ref ty) => {
             match *ty {
                 Types::Void => Err(format!("Variable `{}` has void type!", name)),
@@ -364,26 +833,97 @@ pub fn ty_check(expression: &Expression) -> Result<bool, String> {
                         Err(e) => Err(e),
                     }
                 }
+                // On integers, `!` is the bitwise complement as in Rust
                 UnaryOperator::Not => {
+                    ty_check(expr)?;
+
                     match determine_evaluation_type(expr) {
-                        Types::Bool => Ok(true),
-                        _ => {
-                            Err(format!("Invalid use of operator `{:?}` on non-boolean value \
-                                         `{:?}`",
+                        Types::Void | Types::Unknown | Types::F32 | Types::F64 => {
+                            Err(format!("Invalid use of operator `{:?}` on value `{:?}`, \
+                                         which is neither boolean nor an integer",
                                         *op,
                                         *expr))
                         }
+                        _ => Ok(true),
+                    }
+                }
+                UnaryOperator::Convert(ty) => {
+                    ty_check(expr)?;
+
+                    match determine_evaluation_type(expr) {
+                        Types::Bool => Err(format!("Cannot convert boolean value `{}`", expr)),
+                        source if is_float(source) || is_float(ty) => {
+                            Err(format!("Cannot convert `{}` between integer and floating-point \
+                                         types",
+                                        expr))
+                        }
+                        source if ::bitvector_size(source) > ::bitvector_size(ty) => {
+                            Err(format!("Cannot convert `{}` to the narrower `{:?}`", expr, ty))
+                        }
+                        _ => Ok(true),
                     }
                 }
             }
         }
         Expression::BitVector(_, ref ty) => {
             match *ty {
-                Types::U8 | Types::U16 | Types::U32 | Types::U64 | Types::I8 | Types::I16 |
-                Types::I32 | Types::I64 => Ok(true),
+                Types::U8 | Types::U16 | Types::U32 | Types::U64 | Types::U128 | Types::Usize |
+                Types::I8 | Types::I16 | Types::I32 | Types::I64 | Types::I128 |
+                Types::Isize => Ok(true),
                 _ => Err(format!("Invalid or unsupported integer type: `{:?}`", ty)),
             }
         }
+        Expression::FunctionCall(ref name, ref args, ref ty) => {
+            for arg in args {
+                ty_check(arg)?;
+            }
+
+            match *ty {
+                Types::Void | Types::Unknown => Err(format!("Unknown function `{}`", name)),
+                _ => Ok(true),
+            }
+        }
+        Expression::Array(ref name, ref ty) => {
+            match *ty {
+                Types::Void | Types::Unknown => {
+                    Err(format!("Slice `{}` has unsupported elements", name))
+                }
+                _ => Ok(true),
+            }
+        }
+        Expression::Length(ref array) => {
+            match **array {
+                Expression::Array(..) | Expression::Store(..) => ty_check(array),
+                _ => Err(format!("`{}` is not a slice", array)),
+            }
+        }
+        Expression::Select(ref array, ref index) => {
+            ty_check(array)?;
+            ty_check(index)?;
+
+            match determine_evaluation_type(index) {
+                Types::Usize => Ok(true),
+                ty => Err(format!("Index `{}` must be of type `usize`, not `{:?}`", index, ty)),
+            }
+        }
+        Expression::Store(ref array, ref index, ref value) => {
+            ty_check(&Expression::Select(array.clone(), index.clone()))?;
+            ty_check(value)?;
+
+            if determine_evaluation_type(array) != determine_evaluation_type(value) {
+                Err(format!("Cannot store `{}` in `{}`", value, array))
+            } else {
+                Ok(true)
+            }
+        }
+        Expression::Forall(ref var, _, ref body) => {
+            ty_check(body)?;
+
+            match determine_evaluation_type(body) {
+                Types::Bool => Ok(true),
+                _ => Err(format!("The body of `forall {}` must be boolean", var)),
+            }
+        }
         Expression::BinaryExpression(ref l, ref op, ref r) => {
             match ty_check(l) {
                 Ok(_) => {
@@ -415,6 +955,18 @@ pub fn ty_check(expression: &Expression) -> Result<bool, String> {
                                     }
                                 }
                                 BinaryOperator::BitwiseLeftShift |
+                                BinaryOperator::BitwiseRightShift |
+                                BinaryOperator::BitwiseOr |
+                                BinaryOperator::BitwiseAnd |
+                                BinaryOperator::BitwiseXor if is_float(l_type) ||
+                                                              is_float(r_type) => {
+                                    Err(format!("Invalid use of bitwise operator `{:?}` on \
+                                                 floating-point values: `{:?}` and `{:?}`",
+                                                op,
+                                                l_type,
+                                                r_type))
+                                }
+                                BinaryOperator::BitwiseLeftShift |
                                 BinaryOperator::BitwiseRightShift => {
                                     if (l_type == Types::Bool) || (r_type == Types::Bool) {
                                         Err(format!("Invalid use of binary operator `{:?}` on \
@@ -449,7 +1001,9 @@ pub fn ty_check(expression: &Expression) -> Result<bool, String> {
                                 BinaryOperator::LessThanOrEqual |
                                 BinaryOperator::GreaterThan |
                                 BinaryOperator::GreaterThanOrEqual => {
-                                    if (l_type == Types::Bool) || (r_type == Types::Bool) {
+                                    if l_type == Types::Bool && r_type == Types::Bool {
+                                        Ok(true)
+                                    } else if (l_type == Types::Bool) || (r_type == Types::Bool) {
                                         Err(format!("Invalid use of binary operator `{:?}` on \
                                                      boolean value: `{:?}` and `{:?}`",
                                                     op,
@@ -507,3 +1061,179 @@ pub fn ty_check(expression: &Expression) -> Result<bool, String> {
         }
     }
 }
+
+// Fields of variables are variables of their own, named by their path; on
+// other values `x.f` means `f(x)`
+pub fn field(receiver: Expression, name: String) -> Expression {
+    match receiver {
+        Expression::VariableMapping(path, _) => {
+            Expression::VariableMapping(format!("{}.{}", path, name), Types::Unknown)
+        }
+        Expression::FunctionCall(ref function, ref args, _) if function == "old" &&
+                                                                args.len() == 1 => {
+            Expression::FunctionCall(function.clone(),
+                                     vec![field(args[0].clone(), name)],
+                                     Types::Unknown)
+        }
+        receiver => Expression::FunctionCall(name, vec![receiver], Types::Unknown),
+    }
+}
+
+// A quoted character literal, like `'a'` or `'\n'`, as its scalar value
+// `forall v: ty in lo..hi: body`, the type defaulting to `usize`
+pub fn bounded_forall(var: String,
+                      ty: Option<Types>,
+                      lo: Expression,
+                      hi: Expression,
+                      body: Expression)
+                      -> Expression {
+    let bound = Expression::VariableMapping(var.clone(), Types::Unknown);
+    let from = Expression::BinaryExpression(Rc::new(lo),
+                                            BinaryOperator::LessThanOrEqual,
+                                            Rc::new(bound.clone()));
+    let to = Expression::BinaryExpression(Rc::new(bound), BinaryOperator::LessThan, Rc::new(hi));
+    let range = Expression::BinaryExpression(Rc::new(from), BinaryOperator::And, Rc::new(to));
+    let ty = match ty {
+        None | Some(Types::Unknown) => Types::Usize,
+        Some(ty) => ty,
+    };
+
+    Expression::Forall(var,
+                       ty,
+                       Rc::new(Expression::BinaryExpression(Rc::new(range),
+                                                            BinaryOperator::Implication,
+                                                            Rc::new(body))))
+}
+
+pub fn char_literal(text: &str) -> Expression {
+    let inner = &text[1..text.len() - 1];
+    let c = match inner {
+        "\\n" => '\n',
+        "\\r" => '\r',
+        "\\t" => '\t',
+        "\\0" => '\0',
+        "\\\\" => '\\',
+        "\\'" => '\'',
+        _ => inner.chars().next().unwrap(),
+    };
+
+    Expression::BitVector(c as i64, Types::U32)
+}
+
+// `NAN`, the infinities and the limits of a floating-point type, as in
+// `f64::INFINITY`
+fn float_constant(ty: Types, name: &str) -> Expression {
+    let value = match (name, ty) {
+        ("NAN", _) => ::std::f64::NAN,
+        ("INFINITY", _) => ::std::f64::INFINITY,
+        ("NEG_INFINITY", _) => ::std::f64::NEG_INFINITY,
+        ("MAX", Types::F32) => ::std::f32::MAX as f64,
+        ("MIN", Types::F32) => ::std::f32::MIN as f64,
+        ("MIN_POSITIVE", Types::F32) => ::std::f32::MIN_POSITIVE as f64,
+        ("EPSILON", Types::F32) => ::std::f32::EPSILON as f64,
+        ("MAX", _) => ::std::f64::MAX,
+        ("MIN", _) => ::std::f64::MIN,
+        ("MIN_POSITIVE", _) => ::std::f64::MIN_POSITIVE,
+        ("EPSILON", _) => ::std::f64::EPSILON,
+        _ => {
+            error!("Unknown constant `{:?}::{}`, expected `NAN`, `INFINITY`, `NEG_INFINITY`, \
+                    `MAX`, `MIN`, `MIN_POSITIVE` or `EPSILON`",
+                   ty,
+                   name)
+        }
+    };
+
+    float(value, ty)
+}
+
+// `MIN`, `MAX` and `BITS` of a primitive integer type, as in `i32::MAX`,
+// and the constants of floating-point types
+pub fn type_constant(ty: Types, name: &str) -> Expression {
+    if is_float(ty) {
+        return float_constant(ty, name);
+    }
+
+    let bits = match ty {
+        Types::I8 | Types::U8 => 8,
+        Types::I16 | Types::U16 => 16,
+        Types::I32 | Types::U32 => 32,
+        Types::I64 | Types::U64 | Types::Isize | Types::Usize => 64,
+        Types::I128 | Types::U128 => 128,
+        _ => error!("`{:?}::{}` is not a constant of an integer type", ty, name),
+    };
+    let signed = !same_signedness(ty, Types::Usize);
+    let one = Expression::BitVector(1, ty);
+
+    match (name, signed) {
+        // Literals only keep 64 bits, so the bounds of 128-bit types are
+        // computed from one
+        ("MIN", true) if bits == 128 => {
+            Expression::BinaryExpression(Rc::new(one),
+                                         BinaryOperator::BitwiseLeftShift,
+                                         Rc::new(Expression::BitVector(127, ty)))
+        }
+        ("MAX", true) if bits == 128 => {
+            Expression::BinaryExpression(Rc::new(type_constant(ty, "MIN")),
+                                         BinaryOperator::Subtraction,
+                                         Rc::new(one))
+        }
+        ("MAX", false) if bits == 128 => {
+            Expression::UnaryExpression(UnaryOperator::Negation, Rc::new(one))
+        }
+        ("MIN", true) => Expression::BitVector(i64::min_value() >> (64 - bits), ty),
+        ("MIN", false) => Expression::BitVector(0, ty),
+        ("MAX", true) => Expression::BitVector(i64::max_value() >> (64 - bits), ty),
+        // Literals are masked to their type when encoded, so `-1` is all ones
+        ("MAX", false) if bits == 64 => Expression::BitVector(-1, ty),
+        ("MAX", false) => Expression::BitVector((1i64 << bits) - 1, ty),
+        ("BITS", _) => Expression::BitVector(bits, Types::U32),
+        _ => error!("Unknown constant `{:?}::{}`, expected `MIN`, `MAX` or `BITS`", ty, name),
+    }
+}
+
+// Marks the variables a specification reads from the state at entry
+pub const OLD_SUFFIX: &'static str = "$old";
+
+// Once the weakest precondition reaches the entry of a function, the old
+// values are simply the current ones
+pub fn resolve_old(expression: &Expression) -> Expression {
+    match *expression {
+        Expression::BinaryExpression(ref left, ref op, ref right) => {
+            Expression::BinaryExpression(Rc::new(resolve_old(left)),
+                                         *op,
+                                         Rc::new(resolve_old(right)))
+        }
+        Expression::UnaryExpression(ref op, ref expr) => {
+            Expression::UnaryExpression(op.clone(), Rc::new(resolve_old(expr)))
+        }
+        Expression::VariableMapping(ref name, ty) => {
+            Expression::VariableMapping(name.replace(OLD_SUFFIX, ""), ty)
+        }
+        Expression::FunctionCall(ref name, ref args, ty) => {
+            Expression::FunctionCall(name.clone(), args.iter().map(resolve_old).collect(), ty)
+        }
+        Expression::Array(ref name, ty) => Expression::Array(name.replace(OLD_SUFFIX, ""), ty),
+        Expression::Length(ref array) => Expression::Length(Rc::new(resolve_old(array))),
+        Expression::Select(ref array, ref index) => {
+            Expression::Select(Rc::new(resolve_old(array)), Rc::new(resolve_old(index)))
+        }
+        Expression::Store(ref array, ref index, ref value) => {
+            Expression::Store(Rc::new(resolve_old(array)),
+                              Rc::new(resolve_old(index)),
+                              Rc::new(resolve_old(value)))
+        }
+        Expression::Forall(ref var, ty, ref body) => {
+            Expression::Forall(var.replace(OLD_SUFFIX, ""), ty, Rc::new(resolve_old(body)))
+        }
+        _ => expression.clone(),
+    }
+}
+
+// The variable an array expression updates
+pub fn array_name(array: &Expression) -> &str {
+    match *array {
+        Expression::Array(ref name, _) => name,
+        Expression::Store(ref array, _, _) => array_name(array),
+        _ => error!("`{}` is not a slice", array),
+    }
+}
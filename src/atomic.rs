@@ -0,0 +1,93 @@
+use {MirData, field_path, gen_expression, gen_lvalue, referenced,
+     substitute_variable_with_expression};
+use ast::{BinaryOperator, Expression, Types};
+use lock;
+use rustc::hir::def_id::DefId;
+use rustc::mir::*;
+use rustc::ty::{Ty, TyCtxt, TypeVariants};
+use self_composition;
+use std::rc::Rc;
+
+fn value_type(tcx: TyCtxt, ty: Ty) -> Option<Types> {
+    match ty.sty {
+        TypeVariants::TyAdt(adt, _) => {
+            match tcx.item_path_str(adt.did).as_ref() {
+                "std::sync::atomic::AtomicUsize" => Some(Types::Usize),
+                "std::sync::atomic::AtomicIsize" => Some(Types::Isize),
+                "std::sync::atomic::AtomicBool" => Some(Types::Bool),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+pub fn is_atomic_call(data: &MirData, args: &[Operand]) -> bool {
+    match args.first() {
+        Some(receiver) => {
+            value_type(data.tcx, referenced(receiver.ty(data.mir, data.tcx))).is_some()
+        }
+        None => false,
+    }
+}
+
+// Under the sequentially consistent approximation an atomic is a shared
+// variable that other threads may change between any two accesses; each
+// access then happens as a single step
+pub fn access(wp: &Expression,
+              def_id: DefId,
+              args: &[Operand],
+              destination: Lvalue,
+              index: usize,
+              depth: usize,
+              data: &MirData)
+              -> Expression {
+    let method = data.tcx.item_name(def_id).as_str().to_string();
+
+    if !data.config.approximate_concurrency {
+        error!("`{}` on an atomic needs the `approximate_concurrency` plugin flag", method);
+    }
+
+    let shared = match lock::borrowed(&args[0], data) {
+        Some(lvalue) => {
+            match field_path(&lvalue, data) {
+                Some((path, ty)) => {
+                    Expression::VariableMapping(path, value_type(data.tcx, ty).unwrap())
+                }
+                None => error!("Atomics are only supported as arguments or their fields"),
+            }
+        }
+        None => error!("Atomics are only supported as arguments or their fields"),
+    };
+    let shared = data.composition.rename(&shared);
+    let dest = data.composition.rename(&gen_lvalue(destination, data));
+    let operand = |i: usize| data.composition.rename(&gen_expression(&args[i], data));
+
+    let wp = match method.as_ref() {
+        "load" => substitute_variable_with_expression(wp, &dest, &shared),
+        "store" => substitute_variable_with_expression(wp, &shared, &operand(1)),
+        "swap" => {
+            let wp = substitute_variable_with_expression(wp, &shared, &operand(1));
+            substitute_variable_with_expression(&wp, &dest, &shared)
+        }
+        "fetch_add" | "fetch_sub" => {
+            let op = if method == "fetch_add" {
+                BinaryOperator::Addition
+            } else {
+                BinaryOperator::Subtraction
+            };
+            let updated = Expression::BinaryExpression(Rc::new(shared.clone()),
+                                                       op,
+                                                       Rc::new(operand(1)));
+            let wp = substitute_variable_with_expression(wp, &shared, &updated);
+            substitute_variable_with_expression(&wp, &dest, &shared)
+        }
+        _ => error!("Unsupported atomic operation `{}`", method),
+    };
+
+    data.approximated_atomics.set(true);
+
+    let interfered = self_composition::rename_with(&shared,
+                                                   &format!("$shared{}_{}", index, depth));
+    substitute_variable_with_expression(&wp, &shared, &interfered)
+}
@@ -0,0 +1,96 @@
+use std::collections::BTreeSet;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Write};
+use std::path::Path;
+use summary::{self, Status, Summary};
+
+// Every obligation has an identifier that stays the same from build to
+// build: the def path of its function, then the kind of check and the
+// clause it is about, with punctuation and spacing flattened. A baseline
+// file lists the identifiers of obligations known not to be proved, one
+// per line, so a crate adopting Stanley fails only on new ones
+
+pub fn id(label: &str) -> String {
+    let function = summary::function(label);
+    let detail = &label[function.len()..];
+    let mut kind = String::new();
+
+    for c in detail.chars() {
+        if c.is_alphanumeric() {
+            kind.extend(c.to_lowercase());
+        } else if !kind.is_empty() && !kind.ends_with('_') {
+            kind.push('_');
+        }
+    }
+
+    let kind = kind.trim_right_matches('_');
+
+    format!("{}#{}", function, if kind.is_empty() { "contract" } else { kind })
+}
+
+fn is_failure(status: Status) -> bool {
+    status == Status::Invalid || status == Status::Error
+}
+
+fn load(path: &str) -> BTreeSet<String> {
+    if !Path::new(path).exists() {
+        return BTreeSet::new();
+    }
+
+    let file = File::open(path).unwrap_or_else(|e| error!("Cannot open `{}`: {}", path, e));
+
+    BufReader::new(file)
+        .lines()
+        .map(|line| line.unwrap_or_else(|e| error!("Cannot read `{}`: {}", path, e)))
+        .map(|line| line.trim().to_string())
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .collect()
+}
+
+// Records the failures of this run as the baseline
+pub fn save(path: &str, summaries: &[Summary]) {
+    let failures = summaries.iter()
+        .filter(|summary| is_failure(summary.status))
+        .map(|summary| id(&summary.label))
+        .collect::<BTreeSet<_>>();
+
+    File::create(path)
+        .and_then(|mut file| {
+            writeln!(file, "# Obligations known not to be proved")?;
+
+            for failure in &failures {
+                writeln!(file, "{}", failure)?;
+            }
+
+            Ok(())
+        })
+        .unwrap_or_else(|e| error!("Cannot write `{}`: {}", path, e));
+
+    println!("   note: recorded {} known failures in `{}`", failures.len(), path);
+}
+
+// Reports failures against the baseline, and ends the build with an error
+// when there are any new ones
+pub fn check(path: &str, summaries: &[Summary]) {
+    let known = load(path);
+    let mut new = 0;
+
+    for summary in summaries {
+        let id = id(&summary.label);
+
+        if !is_failure(summary.status) {
+            if summary.status == Status::Valid && known.contains(&id) {
+                println!("   note: {} is proved now; remove it from `{}`", id, path);
+            }
+        } else if known.contains(&id) {
+            println!("   [BASELINE] -- {} (known failure)", id);
+        } else {
+            println!("!! [NEW] -- {}", id);
+            new += 1;
+        }
+    }
+
+    if new > 0 {
+        error!("{} obligations failed that are not in the baseline `{}`", new, path);
+    }
+}
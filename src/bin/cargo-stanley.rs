@@ -0,0 +1,445 @@
+// Verifies every example and integration test of a crate with Stanley and
+// prints one line per file, so documented examples stay verifiable as the
+// specification language changes.
+//
+//     cargo stanley [--watch] [--explain <id>]
+//     cargo stanley fmt [--check]
+//     cargo stanley clean
+//
+// Run from the root of a crate depending on Stanley. The crate is built
+// first, then each `examples/*.rs` and `tests/*.rs` is compiled with
+// `$RUSTC` (default `rustc`) against `target/debug`, loading the plugin into
+// files that do not load it themselves. A run that ends without the
+// plugin's result line never reached the plugin, and is an error. Options are passed through the
+// `STANLEY_` environment variables, and the output of each file is kept in
+// `target/stanley`. `--explain` sets `STANLEY_EXPLAIN` for the file of the
+// crate the obligation is in, and prints its explanation after the results.
+// `fmt` rewrites the conditions of contracts in the crate and every file
+// with `STANLEY_FMT=write`, and `fmt --check` lists the unformatted ones
+// with `STANLEY_FMT=check` and fails if there are any. `clean` removes
+// `target/stanley` and the summaries `STANLEY_SUMMARY` names, with their
+// journal, so the next run starts over. With `--watch`, it keeps running
+// and verifies again whenever a source of the crate changes.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fs::{self, File};
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::{self, Command};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+const DIRECTORIES: &'static [&'static str] = &["examples", "tests"];
+
+const OUT_DIR: &'static str = "target/stanley";
+
+// What `--watch` looks for changes in, and how often
+const WATCHED: &'static [&'static str] = &["Cargo.toml", "src", "examples", "tests"];
+const POLL_MILLISECONDS: u64 = 500;
+
+const USAGE: &'static [&'static str] = &["cargo stanley [--watch] [--explain <id>]",
+                                         "cargo stanley fmt [--check]",
+                                         "cargo stanley clean"];
+
+// The results of verifying one file, from the last line of its run
+struct Outcome {
+    proved: usize,
+    failed: usize,
+    skipped: usize,
+    result: &'static str,
+    // What the run printed to explain an obligation or about formatting
+    report: Vec<String>,
+}
+
+struct Arguments {
+    // The identifier of the obligation to explain
+    explain: Option<String>,
+    // The value of `STANLEY_FMT`, for `fmt`
+    fmt: Option<&'static str>,
+    clean: bool,
+    watch: bool,
+}
+
+fn usage() -> ! {
+    let _ = writeln!(io::stderr(), "usage: {}", USAGE.join("\n       "));
+    process::exit(2)
+}
+
+fn parse(arguments: Vec<String>) -> Arguments {
+    let mut parsed = Arguments {
+        explain: None,
+        fmt: None,
+        clean: false,
+        watch: false,
+    };
+    let mut arguments = arguments.into_iter().peekable();
+
+    if arguments.peek().map_or(false, |argument| argument == "fmt") {
+        arguments.next();
+        parsed.fmt = match arguments.next() {
+            Some(ref argument) if argument == "--check" => Some("check"),
+            Some(_) => usage(),
+            None => Some("write"),
+        };
+    } else if arguments.peek().map_or(false, |argument| argument == "clean") {
+        arguments.next();
+        parsed.clean = true;
+    }
+
+    while let Some(argument) = arguments.next() {
+        match argument.as_ref() {
+            "--watch" => parsed.watch = true,
+            "--explain" => {
+                match arguments.next() {
+                    Some(id) => parsed.explain = Some(id),
+                    None => usage(),
+                }
+            }
+            _ => usage(),
+        }
+    }
+
+    parsed
+}
+
+// Whether the obligation `id`, as in `demo::parse#contract`, is in the crate
+// rustc makes of `source`
+fn is_crate_of(id: &str, source: &Path) -> bool {
+    let name = source.file_stem().map_or(String::new(), |stem| {
+        stem.to_string_lossy().replace('-', "_")
+    });
+
+    id.split("::").next() == Some(&name[..])
+}
+
+// The explanation, the report of an unknown identifier and the formatting
+// of clauses in the output of a run, without the rest of the run
+fn report(stdout: &str) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut explaining = false;
+
+    for line in stdout.lines() {
+        explaining = line.starts_with("Explanation of `") ||
+                     explaining && line.starts_with("   ") && !line.starts_with("   note: ");
+
+        if explaining || line.starts_with("!! no obligation of this run has the identifier") ||
+           line.starts_with("!! [UNFORMATTED] -- ") ||
+           line.starts_with("   note: formatted ") {
+            lines.push(line.to_string());
+        }
+    }
+
+    lines
+}
+
+fn sources(directory: &str) -> Vec<PathBuf> {
+    let mut sources = match fs::read_dir(directory) {
+        Ok(entries) => {
+            entries.filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                .filter(|path| path.extension().map_or(false, |extension| extension == "rs"))
+                .collect::<Vec<_>>()
+        }
+        Err(_) => Vec::new(),
+    };
+
+    sources.sort();
+    sources
+}
+
+// The number before `word` in `verification result: ok. 4 proved; ...`
+fn count(summary: &str, word: &str) -> usize {
+    summary.split(|c| c == '.' || c == ';')
+        .filter_map(|part| {
+            let mut words = part.split_whitespace();
+            match (words.next(), words.next()) {
+                (Some(n), Some(w)) if w == word => n.parse().ok(),
+                _ => None,
+            }
+        })
+        .next()
+        .unwrap_or(0)
+}
+
+fn run(source: &Path, test: bool, arguments: &Arguments) -> io::Result<Outcome> {
+    let mut text = String::new();
+    File::open(source)?.read_to_string(&mut text)?;
+    let log = Path::new(OUT_DIR).join(source.with_extension("log"));
+    let out_dir = log.parent().unwrap_or(Path::new(OUT_DIR)).to_path_buf();
+    fs::create_dir_all(&out_dir)?;
+
+    let rustc = env::var("RUSTC").unwrap_or("rustc".to_string());
+    let mut command = Command::new(rustc);
+    command.args(&["-L", "target/debug", "-L", "target/debug/deps", "--out-dir"])
+        .arg(&out_dir);
+
+    if test {
+        command.arg("--test");
+    } else if text.contains("fn main(") {
+        command.args(&["--crate-type", "bin"]);
+    } else {
+        command.args(&["--crate-type", "lib"]);
+    }
+
+    // Loading the plugin twice would verify everything twice
+    if !text.contains("plugin(stanley") {
+        command.arg("-Zextra-plugins=stanley");
+    }
+
+    if let Some(ref id) = arguments.explain {
+        command.env("STANLEY_EXPLAIN", id);
+    }
+
+    if let Some(mode) = arguments.fmt {
+        command.env("STANLEY_FMT", mode);
+    }
+
+    let output = command.arg(source).output()?;
+    let mut file = File::create(&log)?;
+    file.write_all(&output.stdout)?;
+    file.write_all(&output.stderr)?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let summary = stdout.lines().rev().find(|line| line.starts_with("verification result: "));
+
+    let outcome = match summary {
+        Some(summary) => {
+            let failed = count(summary, "failed");
+            let proved = count(summary, "proved");
+            let skipped = count(summary, "skipped");
+            Outcome {
+                proved: proved,
+                failed: failed,
+                skipped: skipped,
+                result: if failed > 0 || !output.status.success() {
+                    "FAILED"
+                } else if proved + skipped == 0 {
+                    "no checks"
+                } else {
+                    "ok"
+                },
+                report: report(&stdout),
+            }
+        }
+        None => {
+            Outcome {
+                proved: 0,
+                failed: 0,
+                skipped: 0,
+                result: "error",
+                report: report(&stdout),
+            }
+        }
+    };
+
+    Ok(outcome)
+}
+
+// A file that cannot be read, compiled or logged is an error of its own, and
+// the other files are still verified
+fn verify(source: &Path, test: bool, arguments: &Arguments) -> Outcome {
+    run(source, test, arguments).unwrap_or_else(|error| {
+        let _ = writeln!(io::stderr(), "cannot verify {}: {}", source.display(), error);
+        Outcome {
+            proved: 0,
+            failed: 0,
+            skipped: 0,
+            result: "error",
+            report: Vec::new(),
+        }
+    })
+}
+
+fn clean() {
+    let mut paths = vec![PathBuf::from(OUT_DIR)];
+
+    if let Ok(summary) = env::var("STANLEY_SUMMARY") {
+        paths.push(PathBuf::from(format!("{}.partial", summary)));
+        paths.push(PathBuf::from(summary));
+    }
+
+    for path in paths {
+        let removed = if path.is_dir() {
+            fs::remove_dir_all(&path)
+        } else {
+            fs::remove_file(&path)
+        };
+
+        match removed {
+            Ok(()) => println!("removed {}", path.display()),
+            Err(ref error) if error.kind() == io::ErrorKind::NotFound => {}
+            Err(error) => {
+                let _ = writeln!(io::stderr(), "cannot remove {}: {}", path.display(), error);
+                process::exit(1);
+            }
+        }
+    }
+}
+
+fn build(arguments: &Arguments) -> bool {
+    let mut build = Command::new("cargo");
+    build.arg("build");
+
+    // The crate's own contracts are formatted as it is built
+    if let Some(mode) = arguments.fmt {
+        build.env("STANLEY_FMT", mode);
+    }
+
+    match build.status() {
+        Ok(status) => status.success(),
+        Err(error) => {
+            let _ = writeln!(io::stderr(), "cannot run cargo: {}", error);
+            process::exit(1);
+        }
+    }
+}
+
+fn files(arguments: &Arguments) -> Vec<(&'static str, PathBuf)> {
+    DIRECTORIES.iter()
+        .flat_map(|directory| sources(directory).into_iter().map(move |path| (*directory, path)))
+        .filter(|&(_, ref path)| {
+            arguments.explain.as_ref().map_or(true, |id| is_crate_of(id, path))
+        })
+        .collect()
+}
+
+// Verifies `files` and prints their results, returning how many failed
+fn verify_all(files: &[(&'static str, PathBuf)], arguments: &Arguments) -> usize {
+    let width = files.iter().map(|&(_, ref path)| path.to_string_lossy().len()).max().unwrap_or(0);
+    let mut failures = 0;
+    let mut reports = Vec::new();
+
+    println!("{:width$}  {:>6}  {:>6}  {:>7}  result",
+             "file",
+             "proved",
+             "failed",
+             "skipped",
+             width = width);
+
+    for &(directory, ref path) in files {
+        let outcome = verify(path, directory == "tests", arguments);
+
+        if outcome.result == "FAILED" || outcome.result == "error" {
+            failures += 1;
+        }
+
+        println!("{:width$}  {:>6}  {:>6}  {:>7}  {}",
+                 path.to_string_lossy(),
+                 outcome.proved,
+                 outcome.failed,
+                 outcome.skipped,
+                 outcome.result,
+                 width = width);
+        reports.extend(outcome.report);
+    }
+
+    println!("{} files, {} failed; output in {}", files.len(), failures, OUT_DIR);
+
+    for line in reports {
+        println!("{}", line);
+    }
+
+    failures
+}
+
+// The modification times of the sources under `path`
+fn modified(path: &Path, times: &mut BTreeMap<PathBuf, SystemTime>) {
+    if path.is_dir() {
+        if let Ok(entries) = fs::read_dir(path) {
+            for entry in entries.filter_map(|entry| entry.ok()) {
+                modified(&entry.path(), times);
+            }
+        }
+    } else if path.extension().map_or(false, |extension| extension == "rs" || extension == "toml") {
+        if let Ok(time) = fs::metadata(path).and_then(|metadata| metadata.modified()) {
+            times.insert(path.to_path_buf(), time);
+        }
+    }
+}
+
+fn snapshot() -> BTreeMap<PathBuf, SystemTime> {
+    let mut times = BTreeMap::new();
+
+    for root in WATCHED {
+        modified(Path::new(root), &mut times);
+    }
+
+    times
+}
+
+// Verifies again whenever a source changes: an example or test alone when
+// only it changed, and everything after building the crate again otherwise
+fn watch(arguments: &Arguments) -> ! {
+    let mut times = snapshot();
+
+    loop {
+        thread::sleep(Duration::from_millis(POLL_MILLISECONDS));
+        let now = snapshot();
+
+        if now == times {
+            continue;
+        }
+
+        let verified = files(arguments);
+        let changed = now.iter()
+            .filter(|&(path, time)| times.get(path) != Some(time))
+            .map(|(path, _)| path.clone())
+            .chain(times.keys().filter(|path| !now.contains_key(*path)).cloned())
+            .collect::<Vec<_>>();
+        times = now;
+
+        let only_files = changed.iter()
+            .all(|path| verified.iter().any(|&(_, ref file)| file == path));
+        let selected = if only_files {
+            verified.into_iter().filter(|&(_, ref file)| changed.contains(file)).collect::<Vec<_>>()
+        } else if build(arguments) {
+            verified
+        } else {
+            continue;
+        };
+
+        println!("");
+        verify_all(&selected, arguments);
+    }
+}
+
+fn main() {
+    let mut arguments = env::args().skip(1).collect::<Vec<_>>();
+
+    // Cargo runs `cargo-stanley stanley ...`
+    if arguments.first().map_or(false, |argument| argument == "stanley") {
+        arguments.remove(0);
+    }
+
+    let arguments = parse(arguments);
+
+    if arguments.clean {
+        return clean();
+    }
+
+    if !build(&arguments) {
+        if arguments.watch {
+            watch(&arguments);
+        }
+        process::exit(1);
+    }
+
+    let files = files(&arguments);
+    let failures = if files.is_empty() {
+        match arguments.explain {
+            Some(ref id) => println!("no example or test is the crate of `{}`", id),
+            None => println!("no examples or tests to verify"),
+        }
+        0
+    } else {
+        verify_all(&files, &arguments)
+    };
+
+    if arguments.watch {
+        watch(&arguments);
+    }
+
+    if failures > 0 {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,80 @@
+use MirData;
+use rustc::hir;
+use rustc::mir::*;
+use rustc::ty::TypeVariants;
+
+// The state behind `&mut` arguments is passed along by the arguments
+// themselves: `*x = e` assigns `x`, and the postcondition reads the final
+// value of `*x` like any other variable. A mutable borrow held in a local,
+// as in `let r = &mut *x` or `let r = &mut a`, is the place it borrows, so
+// writing through it assigns that place and a later read of the place
+// sees the write. Such a local must borrow the same place on every path
+
+fn is_mutable_reference(local: Local, data: &MirData) -> bool {
+    match data.mir.local_decls[local].ty.sty {
+        TypeVariants::TyRef(_, ref pointee) => pointee.mutbl == hir::MutMutable,
+        _ => false,
+    }
+}
+
+// The place a definition of a borrowing local makes it borrow, if it is
+// known
+fn borrowed<'tcx>(rvalue: &Rvalue<'tcx>, data: &MirData) -> Option<Lvalue<'tcx>> {
+    match *rvalue {
+        Rvalue::Ref(_, BorrowKind::Mut, ref place) => Some(place.clone()),
+        // Moving a mutable reference on keeps what it borrows
+        Rvalue::Use(Operand::Consume(Lvalue::Local(other))) => {
+            if is_mutable_reference(other, data) {
+                Some(Lvalue::Local(other).deref())
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
+// The place a local holding a mutable borrow points to, when every
+// assignment of the local borrows the same one. Arguments are their own
+// places, and references returned by calls are not followed
+pub fn target<'a, 'tcx>(local: Local, data: &MirData<'a, 'tcx>) -> Option<Lvalue<'tcx>> {
+    match data.mir.local_kind(local) {
+        LocalKind::Temp | LocalKind::Var if is_mutable_reference(local, data) => {}
+        _ => return None,
+    }
+
+    let mut found: Option<Lvalue<'tcx>> = None;
+
+    for block in &data.block_data {
+        for stmt in &block.statements {
+            let rvalue = match stmt.kind {
+                StatementKind::Assign(Lvalue::Local(assigned), ref rvalue) => {
+                    if assigned != local {
+                        continue;
+                    }
+
+                    rvalue
+                }
+                _ => continue,
+            };
+            let place = match borrowed(rvalue, data) {
+                Some(place) => place,
+                None => return None,
+            };
+
+            match found {
+                Some(ref known) if *known != place => {
+                    error!("A mutable borrow borrows `{:?}` and `{:?}` on different paths, \
+                            which is not supported",
+                           known,
+                           place)
+                }
+                _ => {}
+            }
+
+            found = Some(place);
+        }
+    }
+
+    found
+}
@@ -0,0 +1,309 @@
+use ast::{self, BinaryOperator, Expression, Types};
+use float;
+use numeric;
+use pledge;
+use self_composition;
+use with_type;
+use std::rc::Rc;
+
+// The sub-slice `buf[lo..hi]`, as parsed
+pub const SUBSLICE: &'static str = "subslice";
+
+fn binary(left: Expression, op: BinaryOperator, right: Expression) -> Expression {
+    Expression::BinaryExpression(Rc::new(left), op, Rc::new(right))
+}
+
+fn select(array: &Expression, index: &Expression) -> Expression {
+    Expression::Select(Rc::new(array.clone()), Rc::new(index.clone()))
+}
+
+fn arity(name: &str, args: &[Expression], expected: usize) {
+    if args.len() != expected {
+        error!("`{}` expects {} arguments, found {}", name, expected, args.len());
+    }
+}
+
+fn slice(name: &str, array: &Expression) {
+    match *array {
+        Expression::Array(..) | Expression::Store(..) => {}
+        _ => error!("`{}` expects a slice, found `{}`", name, array),
+    }
+}
+
+// Every pair of elements is ordered, rather than every adjacent pair, so
+// instantiating the quantifiers never needs induction
+fn sorted_range(array: &Expression, lo: Expression, hi: Expression) -> Expression {
+    let i = Expression::VariableMapping("i$sorted".to_string(), Types::Usize);
+    let j = Expression::VariableMapping("j$sorted".to_string(), Types::Usize);
+
+    let inner_range = binary(binary(i.clone(), BinaryOperator::LessThanOrEqual, j.clone()),
+                             BinaryOperator::And,
+                             binary(j.clone(), BinaryOperator::LessThan, hi.clone()));
+    let ordered = binary(select(array, &i), BinaryOperator::LessThanOrEqual, select(array, &j));
+    let inner = Expression::Forall("j$sorted".to_string(),
+                                   Types::Usize,
+                                   Rc::new(binary(inner_range,
+                                                  BinaryOperator::Implication,
+                                                  ordered)));
+
+    let outer_range = binary(binary(lo, BinaryOperator::LessThanOrEqual, i.clone()),
+                             BinaryOperator::And,
+                             binary(i, BinaryOperator::LessThan, hi));
+    Expression::Forall("i$sorted".to_string(),
+                       Types::Usize,
+                       Rc::new(binary(outer_range, BinaryOperator::Implication, inner)))
+}
+
+// A slice, or a sub-slice `buf[lo..hi]` of one, as its array and bounds
+struct View {
+    array: Expression,
+    lo: Expression,
+    hi: Expression,
+}
+
+impl View {
+    fn starts_at_zero(&self) -> bool {
+        self.lo == Expression::BitVector(0, Types::Usize)
+    }
+
+    fn length(&self) -> Expression {
+        if self.starts_at_zero() {
+            return self.hi.clone();
+        }
+
+        binary(self.hi.clone(), BinaryOperator::Subtraction, self.lo.clone())
+    }
+
+    fn element(&self, k: &Expression) -> Expression {
+        if self.starts_at_zero() {
+            return select(&self.array, k);
+        }
+
+        select(&self.array, &binary(self.lo.clone(), BinaryOperator::Addition, k.clone()))
+    }
+}
+
+fn view(name: &str, expression: &Expression) -> View {
+    match *expression {
+        Expression::FunctionCall(ref function, ref args, _) if function == SUBSLICE => {
+            View {
+                array: args[0].clone(),
+                lo: args[1].clone(),
+                hi: args[2].clone(),
+            }
+        }
+        _ => {
+            slice(name, expression);
+            View {
+                array: expression.clone(),
+                lo: Expression::BitVector(0, Types::Usize),
+                hi: Expression::Length(Rc::new(expression.clone())),
+            }
+        }
+    }
+}
+
+// `body(k)` for every `k` in `0..length`
+fn for_each(bound: &str, length: Expression, body: &Fn(&Expression) -> Expression) -> Expression {
+    let k = Expression::VariableMapping(bound.to_string(), Types::Usize);
+    let range = binary(k.clone(), BinaryOperator::LessThan, length);
+
+    Expression::Forall(bound.to_string(),
+                       Types::Usize,
+                       Rc::new(binary(range, BinaryOperator::Implication, body(&k))))
+}
+
+// Every byte has its high bit clear, which is the same comparison whether
+// bytes are encoded signed or unsigned
+fn all_ascii(buf: &View) -> Expression {
+    if ast::determine_evaluation_type(&buf.array) != Types::U8 {
+        error!("`all_ascii` expects a byte slice, found `{}`", buf.array);
+    }
+
+    for_each("k$ascii", buf.length(), &|k| {
+        binary(binary(buf.element(k),
+                      BinaryOperator::BitwiseAnd,
+                      Expression::BitVector(0x80, Types::U8)),
+               BinaryOperator::Equal,
+               Expression::BitVector(0, Types::U8))
+    })
+}
+
+// The first `other.len()` elements of `buf` are those of `other`
+fn prefix(buf: &View, other: &View) -> Expression {
+    binary(binary(other.length(), BinaryOperator::LessThanOrEqual, buf.length()),
+           BinaryOperator::And,
+           for_each("k$prefix", other.length(), &|k| {
+               binary(buf.element(k), BinaryOperator::Equal, other.element(k))
+           }))
+}
+
+fn same_elements(left: &View, right: &View) -> Expression {
+    binary(binary(left.length(), BinaryOperator::Equal, right.length()),
+           BinaryOperator::And,
+           for_each("k$slice", left.length(), &|k| {
+               binary(left.element(k), BinaryOperator::Equal, right.element(k))
+           }))
+}
+
+// `buf[i..j] == other` and `!=` between slices when either side is a
+// sub-slice, with both sides resolved; `None` for other comparisons
+pub fn compare_slices(left: &Expression,
+                      op: BinaryOperator,
+                      right: &Expression)
+                      -> Option<Expression> {
+    let is_subslice = |expression: &Expression| match *expression {
+        Expression::FunctionCall(ref function, _, _) => function == SUBSLICE,
+        _ => false,
+    };
+
+    if !is_subslice(left) && !is_subslice(right) {
+        return None;
+    }
+
+    let same = same_elements(&view("==", left), &view("==", right));
+
+    match op {
+        BinaryOperator::Equal => Some(same),
+        BinaryOperator::NotEqual => {
+            Some(Expression::UnaryExpression(ast::UnaryOperator::Not, Rc::new(same)))
+        }
+        _ => error!("Sub-slices can only be compared with `==` and `!=`"),
+    }
+}
+
+// Functions every specification can use; `None` for the others
+pub fn expand(name: &str, args: &[Expression]) -> Option<Expression> {
+    match name {
+        "len" => {
+            arity(name, args, 1);
+            Some(view(name, &args[0]).length())
+        }
+        // Kept as is until the sub-slice is used by a predicate or compared
+        SUBSLICE => {
+            arity(name, args, 3);
+            slice("a sub-slice", &args[0]);
+            Some(Expression::FunctionCall(name.to_string(),
+                                          vec![args[0].clone(),
+                                               with_type(args[1].clone(), Types::Usize),
+                                               with_type(args[2].clone(), Types::Usize)],
+                                          Types::Unknown))
+        }
+        "all_ascii" => {
+            arity(name, args, 1);
+            Some(all_ascii(&view(name, &args[0])))
+        }
+        "prefix" => {
+            arity(name, args, 2);
+            Some(prefix(&view(name, &args[0]), &view(name, &args[1])))
+        }
+        "old" => {
+            arity(name, args, 1);
+            Some(self_composition::rename_with(&args[0], ast::OLD_SUFFIX))
+        }
+        "final" => {
+            arity(name, args, 1);
+            Some(self_composition::rename_with(&args[0], pledge::FINAL_SUFFIX))
+        }
+        "sorted" => {
+            arity(name, args, 1);
+            slice(name, &args[0]);
+            Some(sorted_range(&args[0],
+                              Expression::BitVector(0, Types::Usize),
+                              Expression::Length(Rc::new(args[0].clone()))))
+        }
+        "sorted_range" => {
+            arity(name, args, 3);
+            slice(name, &args[0]);
+            Some(sorted_range(&args[0],
+                              with_type(args[1].clone(), Types::Usize),
+                              with_type(args[2].clone(), Types::Usize)))
+        }
+        // Explicit conversions for mixing signed and unsigned values
+        "int" | "uint" => {
+            arity(name, args, 1);
+            let ty = if name == "int" { Types::I64 } else { Types::U64 };
+            Some(Expression::UnaryExpression(ast::UnaryOperator::Convert(ty),
+                                             Rc::new(with_type(args[0].clone(), ty))))
+        }
+        "permutation" => {
+            arity(name, args, 2);
+            slice(name, &args[0]);
+            slice(name, &args[1]);
+            Some(Expression::FunctionCall(name.to_string(), args.to_vec(), Types::Bool))
+        }
+        _ => float::expand(name, args).or_else(|| numeric::expand(name, args)),
+    }
+}
+
+// The array a swap through a temporary was applied to, recognized in the
+// form its weakest precondition takes
+fn swapped(array: &Expression) -> Option<&Expression> {
+    if let Expression::Store(ref inner, ref j, ref second) = *array {
+        if let Expression::Store(ref first, ref i, ref value) = **inner {
+            if **value == select(first, j) && **second == select(first, i) {
+                return Some(first);
+            }
+        }
+    }
+
+    None
+}
+
+fn permutation(first: &Expression, second: &Expression) -> bool {
+    if first == second {
+        return true;
+    }
+
+    match (swapped(first), swapped(second)) {
+        (Some(first), _) => permutation(first, second),
+        (_, Some(second)) => permutation(first, second),
+        _ => false,
+    }
+}
+
+// Permutations are proved by reducing chains of swaps; the others become
+// opaque facts, only known to preserve the length
+pub fn eliminate_permutations(verification_condition: &Expression) -> Expression {
+    let mut hypotheses = Vec::new();
+    let reduced = reduce(&ast::simplify_expression(verification_condition), &mut hypotheses);
+
+    if hypotheses.is_empty() {
+        return reduced;
+    }
+
+    let hypothesis = hypotheses.into_iter()
+        .fold(Expression::BooleanLiteral(true),
+              |acc, h| binary(acc, BinaryOperator::And, h));
+    binary(hypothesis, BinaryOperator::Implication, reduced)
+}
+
+fn reduce(expression: &Expression, hypotheses: &mut Vec<Expression>) -> Expression {
+    match *expression {
+        Expression::BinaryExpression(ref left, op, ref right) => {
+            let left = reduce(left, hypotheses);
+            binary(left, op, reduce(right, hypotheses))
+        }
+        Expression::UnaryExpression(ref op, ref expr) => {
+            Expression::UnaryExpression(op.clone(), Rc::new(reduce(expr, hypotheses)))
+        }
+        Expression::Forall(ref var, ty, ref body) => {
+            Expression::Forall(var.clone(), ty, Rc::new(reduce(body, hypotheses)))
+        }
+        Expression::FunctionCall(ref name, ref args, _) if name == "permutation" => {
+            if permutation(&args[0], &args[1]) {
+                return Expression::BooleanLiteral(true);
+            }
+
+            let opaque = Expression::VariableMapping(format!("permutation${}", hypotheses.len()),
+                                                     Types::Bool);
+            let same_length = binary(Expression::Length(Rc::new(args[0].clone())),
+                                     BinaryOperator::Equal,
+                                     Expression::Length(Rc::new(args[1].clone())));
+
+            hypotheses.push(binary(opaque.clone(), BinaryOperator::Implication, same_length));
+            opaque
+        }
+        _ => expression.clone(),
+    }
+}
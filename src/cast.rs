@@ -0,0 +1,106 @@
+use MirData;
+use ast::{self, BinaryOperator, Expression, Types};
+use rustc::mir::*;
+use rustc::ty::TyCtxt;
+use std::rc::Rc;
+
+// Integer casts whose target cannot represent every value of the source,
+// so that `as` silently wraps. Whether they may is its own class of
+// obligation, apart from arithmetic overflow
+
+fn is_integer(ty: Types) -> bool {
+    match ty {
+        Types::Bool | Types::Void | Types::Unknown | Types::F32 | Types::F64 => false,
+        _ => true,
+    }
+}
+
+// The number of bits of the largest value of the type
+fn magnitude(ty: Types) -> usize {
+    ::bitvector_size(ty) - if ast::is_signed(ty) { 1 } else { 0 }
+}
+
+// Only needed for signed types narrower than 128 bits
+fn max(ty: Types) -> u64 {
+    (1u64 << (::bitvector_size(ty) - 1)) - 1
+}
+
+fn and(left: Option<Expression>, right: Option<Expression>) -> Option<Expression> {
+    match (left, right) {
+        (Some(left), Some(right)) => {
+            Some(Expression::BinaryExpression(Rc::new(left), BinaryOperator::And, Rc::new(right)))
+        }
+        (left, right) => left.or(right),
+    }
+}
+
+// That `value` of type `source` fits into `target`, stated in `source`;
+// `None` when it always does
+fn fits(value: &Expression, source: Types, target: Types) -> Option<Expression> {
+    let compare = |op, bound: u64| {
+        Expression::BinaryExpression(Rc::new(value.clone()),
+                                     op,
+                                     Rc::new(Expression::BitVector(bound as i64, source)))
+    };
+
+    let signed = (ast::is_signed(source), ast::is_signed(target));
+    let lower = match signed {
+        (true, false) => Some(compare(BinaryOperator::GreaterThanOrEqual, 0)),
+        (true, true) if ::bitvector_size(target) < ::bitvector_size(source) => {
+            Some(compare(BinaryOperator::GreaterThanOrEqual, !max(target)))
+        }
+        _ => None,
+    };
+    // A value that cannot be negative fits when no bits are set above the
+    // largest value of the target, which also works where that value does
+    // not fit in a 64-bit literal
+    let upper = match signed {
+        _ if magnitude(target) >= magnitude(source) => None,
+        (true, true) => Some(compare(BinaryOperator::LessThanOrEqual, max(target))),
+        _ => {
+            let by = Expression::BitVector(magnitude(target) as i64, source);
+            let shifted = Expression::BinaryExpression(Rc::new(value.clone()),
+                                                       BinaryOperator::BitwiseRightShift,
+                                                       Rc::new(by));
+            Some(Expression::BinaryExpression(Rc::new(shifted),
+                                              BinaryOperator::Equal,
+                                              Rc::new(Expression::BitVector(0, source))))
+        }
+    };
+
+    and(lower, upper)
+}
+
+fn lossy<'tcx>(rvalue: &Rvalue<'tcx>,
+               mir: &Mir<'tcx>,
+               tcx: TyCtxt)
+               -> Option<(Operand<'tcx>, Types, Types)> {
+    if let Rvalue::Cast(CastKind::Misc, ref operand, target) = *rvalue {
+        let source = ast::type_to_enum(operand.ty(mir, tcx));
+        let target = ast::type_to_enum(target);
+
+        if is_integer(source) && is_integer(target) &&
+           (magnitude(target) < magnitude(source) ||
+            ast::is_signed(source) != ast::is_signed(target)) {
+            return Some((operand.clone(), source, target));
+        }
+    }
+
+    None
+}
+
+pub fn has_lossy_casts(tcx: TyCtxt, mir: &Mir) -> bool {
+    mir.basic_blocks().iter().any(|block| {
+        block.statements.iter().any(|stmt| match stmt.kind {
+            StatementKind::Assign(_, ref rvalue) => lossy(rvalue, mir, tcx).is_some(),
+            _ => false,
+        })
+    })
+}
+
+// What must hold before `rvalue` for it not to truncate
+pub fn obligation(rvalue: &Rvalue, data: &MirData) -> Option<Expression> {
+    lossy(rvalue, data.mir, data.tcx).and_then(|(operand, source, target)| {
+        fits(&::gen_expression(&operand, data), source, target)
+    })
+}
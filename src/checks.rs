@@ -0,0 +1,87 @@
+use {MirData, UNIMPLEMENTED, is_panic, model_value, model_values, panic_message, solve,
+     source_name};
+use assertions;
+use ast::{BinaryOperator, Expression};
+use debug;
+use eval;
+use rustc::mir::*;
+use rustproof_libsmt::backends::smtlib2::SMTRes;
+use self_composition::Composition;
+use std::collections::BTreeMap;
+use std::rc::Rc;
+
+// rustc guards indexing, arithmetic that can overflow, and division and
+// remainder by an `Assert` that panics when the index is out of bounds, the
+// operation overflowed or the divisor is zero. Each is an obligation under
+// the precondition, on the paths reaching it, and so is every `panic!`,
+// failed `assert!` and `assert_eq!` being unreachable. When a proof fails,
+// each of these is probed alone, so the report names the operations and
+// assertions that may panic, where they are, and inputs making them panic
+
+// What a check guards against, as rustc describes it
+fn check_description(msg: &AssertMessage) -> &'static str {
+    match *msg {
+        AssertMessage::BoundsCheck { .. } => "index out of bounds",
+        AssertMessage::Math(ref err) => err.description(),
+    }
+}
+
+// The operations and panics of a function that may panic under `pre`
+pub fn report(name: &str, pre: &Expression, data: &MirData) {
+    let codemap = data.tcx.sess.codemap();
+
+    for (index, block) in data.block_data.iter().enumerate() {
+        let terminator = block.terminator();
+
+        // A check fails where it is, and a panic when it is reached
+        let (check, description) = match terminator.kind {
+            TerminatorKind::Assert { ref msg, .. } => {
+                (true, Some(check_description(msg).to_string()))
+            }
+            TerminatorKind::Call { ref func, ref args, .. } if is_panic(func) => {
+                match panic_message(args) {
+                    _ if debug::is_excused(index, data) => continue,
+                    _ if assertions::is_assumed(index, data) => continue,
+                    Some(ref message) if message == UNIMPLEMENTED => continue,
+                    Some(ref message) if assertions::is_assumption(message) => continue,
+                    message => (false, message),
+                }
+            }
+            _ => continue,
+        };
+
+        let mut probe = data.with_composition(Composition::Single);
+        probe.probe = Some(index);
+        probe.probe_check = check;
+        let holds = probe.weakest_precondition(&Expression::BooleanLiteral(true));
+        let verification_condition = Expression::BinaryExpression(Rc::new(pre.clone()),
+                                                                  BinaryOperator::Implication,
+                                                                  Rc::new(holds));
+
+        if let SMTRes::Sat(_, ref model) = solve(&verification_condition, &probe) {
+            // Panics are written as macros, which are shown as written
+            let span = terminator.source_info.span.source_callsite();
+            let operation = codemap.span_to_snippet(span).unwrap_or_else(|_| "?".to_string());
+            let description = description.map_or(String::new(), |text| format!(" ({})", text));
+            let fails = operation.starts_with("assert") || operation.starts_with("debug_assert");
+
+            println!("   note: {}: `{}` at {} may {}{}",
+                     name,
+                     operation,
+                     codemap.span_to_string(span),
+                     if fails { "fail" } else { "panic" },
+                     description);
+
+            let mut types = BTreeMap::new();
+            eval::variables(&verification_condition, &mut types);
+
+            for (var, hex) in model_values(model.as_ref().map_or("", |model| &model[..])) {
+                if let Some(source) = source_name(&var, data) {
+                    let value = model_value(&hex, types.get(&var).cloned())
+                        .unwrap_or_else(|| "?".to_string());
+                    println!("      {:7} = {:>10} (0x{})", source, value, hex);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,6150 @@
+use std::rc::Rc;
+use ast::*;
+use spans;
+extern crate lalrpop_util as __lalrpop_util;
+
+mod __parse__Condition {
+    #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports)]
+
+    use std::rc::Rc;
+    use ast::*;
+    use spans;
+    extern crate lalrpop_util as __lalrpop_util;
+    #[allow(dead_code)]
+    pub enum __Symbol<'input> {
+        Term_22_22(&'input str),
+        Term_22_21_22(&'input str),
+        Term_22_21_3d_22(&'input str),
+        Term_22_25_22(&'input str),
+        Term_22_26_22(&'input str),
+        Term_22_26_26_22(&'input str),
+        Term_22_28_22(&'input str),
+        Term_22_29_22(&'input str),
+        Term_22_2a_22(&'input str),
+        Term_22_2b_22(&'input str),
+        Term_22_2c_22(&'input str),
+        Term_22_2d_22(&'input str),
+        Term_22_2e_22(&'input str),
+        Term_22_2e_2e_22(&'input str),
+        Term_22_2f_22(&'input str),
+        Term_22_3a_22(&'input str),
+        Term_22_3a_3a_22(&'input str),
+        Term_22_3c_22(&'input str),
+        Term_22_3c_3c_22(&'input str),
+        Term_22_3c_3d_22(&'input str),
+        Term_22_3c_3d_3e_22(&'input str),
+        Term_22_3d_3d_22(&'input str),
+        Term_22_3d_3e_22(&'input str),
+        Term_22_3e_22(&'input str),
+        Term_22_3e_3d_22(&'input str),
+        Term_22_3e_3e_22(&'input str),
+        Term_22XOR_22(&'input str),
+        Term_22_5b_22(&'input str),
+        Term_22_5d_22(&'input str),
+        Term_22_5e_22(&'input str),
+        Term_22bool_22(&'input str),
+        Term_22char_22(&'input str),
+        Term_22exists_22(&'input str),
+        Term_22f32_22(&'input str),
+        Term_22f64_22(&'input str),
+        Term_22false_22(&'input str),
+        Term_22forall_22(&'input str),
+        Term_22i128_22(&'input str),
+        Term_22i16_22(&'input str),
+        Term_22i32_22(&'input str),
+        Term_22i64_22(&'input str),
+        Term_22i8_22(&'input str),
+        Term_22in_22(&'input str),
+        Term_22is_22(&'input str),
+        Term_22isize_22(&'input str),
+        Term_22true_22(&'input str),
+        Term_22u128_22(&'input str),
+        Term_22u16_22(&'input str),
+        Term_22u32_22(&'input str),
+        Term_22u64_22(&'input str),
+        Term_22u8_22(&'input str),
+        Term_22usize_22(&'input str),
+        Term_22_7c_22(&'input str),
+        Term_22_7c_7c_22(&'input str),
+        Termr_23_22_27_28_5b_5e_27_5c_5c_5c_5c_5d_7c_5c_5c_5c_5c_5bnrt0_27_5c_5c_5c_5c_5d_29_27_22_23(&'input str),
+        Termr_23_22_2d_3f_5b0_2d9_5d_2b_22_23(&'input str),
+        Termr_23_22_2d_3f_5b0_2d9_5d_2b_28_5c_5c_2e_5b0_2d9_5d_2b_28_5beE_5d_5b_2d_2b_5d_3f_5b0_2d9_5d_2b_29_3f_7c_5beE_5d_5b_2d_2b_5d_3f_5b0_2d9_5d_2b_29_22_23(&'input str),
+        Termr_23_22_5ba_2dzA_2dZ_5d_5ba_2dzA_2dZ0_2d9___5d_2a_22_23(&'input str),
+        Nt_28_22_3a_22_20_3cTYPE_3e_29(Types),
+        Nt_28_22_3a_22_20_3cTYPE_3e_29_3f(::std::option::Option<Types>),
+        Nt_28_3cCondition_3e_20_22_2c_22_29(Expression),
+        Nt_28_3cCondition_3e_20_22_2c_22_29_2a(::std::vec::Vec<Expression>),
+        Nt_28_3cCondition_3e_20_22_2c_22_29_2b(::std::vec::Vec<Expression>),
+        Nt_40L(usize),
+        Nt_40R(usize),
+        NtAtom(Expression),
+        NtBOP1(BinaryOperator),
+        NtBOP2(BinaryOperator),
+        NtBOP3(BinaryOperator),
+        NtBOP4(BinaryOperator),
+        NtBOP5(BinaryOperator),
+        NtBOP6(BinaryOperator),
+        NtBOP7(BinaryOperator),
+        NtBOP8(BinaryOperator),
+        NtBound(Expression),
+        NtComma_3cCondition_3e(Vec<Expression>),
+        NtCondition(Expression),
+        NtCondition_3f(::std::option::Option<Expression>),
+        NtE1(Expression),
+        NtE10(Expression),
+        NtE2(Expression),
+        NtE3(Expression),
+        NtE4(Expression),
+        NtE5(Expression),
+        NtE6(Expression),
+        NtE7(Expression),
+        NtE8(Expression),
+        NtE9(Expression),
+        NtExprHelper_3cE1_2c_20BOP1_2c_20E2_3e(Expression),
+        NtExprHelper_3cE2_2c_20BOP2_2c_20E3_3e(Expression),
+        NtExprHelper_3cE3_2c_20BOP3_2c_20E4_3e(Expression),
+        NtExprHelper_3cE4_2c_20BOP4_2c_20E5_3e(Expression),
+        NtExprHelper_3cE5_2c_20BOP5_2c_20E6_3e(Expression),
+        NtExprHelper_3cE6_2c_20BOP6_2c_20E7_3e(Expression),
+        NtExprHelper_3cE7_2c_20BOP7_2c_20E8_3e(Expression),
+        NtExprHelper_3cE8_2c_20BOP8_2c_20E9_3e(Expression),
+        NtIDENTIFIER(String),
+        NtTYPE(Types),
+        NtUOP(UnaryOperator),
+        Nt____Condition(Expression),
+    }
+    const __ACTION: &'static [i32] = &[
+        // State 0
+        25, 26, 0, 0, 0, 0, 27, 0, 28, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 0, 0, 42, 43, 44, 45, 46, 47, 48, 49, 0, 0, 50, 51, 52, 53,
+        // State 1
+        -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68,
+        // State 2
+        -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116,
+        // State 3
+        0, 0, 0, 0, 0, 55, 0, -58, 0, 0, -58, 0, 0, -58, 0, 0, 0, 0, 0, 0, 56, 0, 57, 0, 0, 0, 58, 0, -58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 59, 0, 0, 0, 0,
+        // State 4
+        0, 0, -79, -79, -79, -79, 0, -79, -79, -79, -79, -79, 60, -79, -79, 0, 0, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, 61, -79, -79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 62, 0, 0, 0, 0, 0, 0, 0, 0, -79, -79, 0, 0, 0, 0,
+        // State 5
+        0, 0, 64, 0, 0, -81, 0, -81, 0, 0, -81, 0, 0, -81, 0, 0, 0, 65, 0, 66, -81, 67, -81, 68, 69, 0, -81, 0, -81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -81, 0, 0, 0, 0,
+        // State 6
+        0, 0, -83, 0, 0, -83, 0, -83, 0, 0, -83, 0, 0, -83, 0, 0, 0, -83, 0, -83, -83, -83, -83, -83, -83, 0, -83, 0, -83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 71, -83, 0, 0, 0, 0,
+        // State 7
+        0, 0, -85, 0, 0, -85, 0, -85, 0, 0, -85, 0, 0, -85, 0, 0, 0, -85, 0, -85, -85, -85, -85, -85, -85, 0, -85, 0, -85, 73, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -85, -85, 0, 0, 0, 0,
+        // State 8
+        0, 0, -87, 0, 75, -87, 0, -87, 0, 0, -87, 0, 0, -87, 0, 0, 0, -87, 0, -87, -87, -87, -87, -87, -87, 0, -87, 0, -87, -87, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -87, -87, 0, 0, 0, 0,
+        // State 9
+        0, 0, -89, 0, -89, -89, 0, -89, 0, 0, -89, 0, 0, -89, 0, 0, 0, -89, 77, -89, -89, -89, -89, -89, -89, 78, -89, 0, -89, -89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -89, -89, 0, 0, 0, 0,
+        // State 10
+        0, 0, -91, 0, -91, -91, 0, -91, 0, 80, -91, 81, 0, -91, 0, 0, 0, -91, -91, -91, -91, -91, -91, -91, -91, -91, -91, 0, -91, -91, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -91, -91, 0, 0, 0, 0,
+        // State 11
+        0, 0, -93, 83, -93, -93, 0, -93, 84, -93, -93, -93, 0, -93, 85, 0, 0, -93, -93, -93, -93, -93, -93, -93, -93, -93, -93, 0, -93, -93, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -93, -93, 0, 0, 0, 0,
+        // State 12
+        -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95,
+        // State 13
+        -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61,
+        // State 14
+        -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70,
+        // State 15
+        -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71,
+        // State 16
+        -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72,
+        // State 17
+        -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73,
+        // State 18
+        -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74,
+        // State 19
+        -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75,
+        // State 20
+        -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76,
+        // State 21
+        0, 0, -21, -21, -21, -21, 86, -21, -21, -21, -21, -21, -21, -21, -21, 87, 0, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -21, 0, 0, 0, 0, 0, 0, 0, 0, -21, -21, 0, 0, 0, 0,
+        // State 22
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 88, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 23
+        25, 26, 0, 0, 0, 0, 27, 0, 28, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 31, 0, 33, 34, 35, 0, 37, 38, 39, 40, 41, 0, 0, 42, 43, 44, 45, 46, 47, 48, 49, 0, 0, 50, 51, 52, 53,
+        // State 24
+        -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113,
+        // State 25
+        -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115,
+        // State 26
+        25, 26, 0, 0, 0, 0, 27, 0, 28, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 0, 0, 42, 43, 44, 45, 46, 47, 48, 49, 0, 0, 50, 51, 52, 53,
+        // State 27
+        25, 26, 0, 0, 0, 0, 27, 0, 28, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 31, 0, 33, 34, 35, 0, 37, 38, 39, 40, 41, 0, 0, 42, 43, 44, 45, 46, 47, 48, 49, 0, 0, 50, 51, 52, 53,
+        // State 28
+        -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114,
+        // State 29
+        -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97,
+        // State 30
+        -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112,
+        // State 31
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 53,
+        // State 32
+        -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110,
+        // State 33
+        -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111,
+        // State 34
+        -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12,
+        // State 35
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 53,
+        // State 36
+        -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102,
+        // State 37
+        -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99,
+        // State 38
+        -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100,
+        // State 39
+        -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101,
+        // State 40
+        -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98,
+        // State 41
+        -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109,
+        // State 42
+        -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11,
+        // State 43
+        -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107,
+        // State 44
+        -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104,
+        // State 45
+        -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105,
+        // State 46
+        -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106,
+        // State 47
+        -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103,
+        // State 48
+        -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108,
+        // State 49
+        -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17,
+        // State 50
+        0, 0, -14, -14, -14, -14, 0, -14, -14, -14, -14, -14, -14, -14, -14, 94, 0, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -14, 0, 0, 0, 0, 0, 0, 0, 0, -14, -14, 0, 0, 0, 0,
+        // State 51
+        0, 0, -16, -16, -16, -16, 0, -16, -16, -16, -16, -16, -16, -16, -16, 95, 0, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -16, 0, 0, 0, 0, 0, 0, 0, 0, -16, -16, 0, 0, 0, 0,
+        // State 52
+        -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96,
+        // State 53
+        25, 26, 0, 0, 0, 0, 27, 0, 28, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 31, 0, 33, 34, 35, 0, 37, 38, 39, 40, 41, 0, 0, 42, 43, 44, 45, 46, 47, 48, 49, 0, 0, 50, 51, 52, 53,
+        // State 54
+        -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22,
+        // State 55
+        -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26,
+        // State 56
+        -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25,
+        // State 57
+        -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24,
+        // State 58
+        -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23,
+        // State 59
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 98, 0, 53,
+        // State 60
+        25, 26, 0, 0, 0, 0, 27, 0, 28, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 0, 0, 42, 43, 44, 45, 46, 47, 48, 49, 0, 0, 50, 51, 52, 53,
+        // State 61
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 53,
+        // State 62
+        25, 26, 0, 0, 0, 0, 27, 0, 28, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 31, 0, 33, 34, 35, 0, 37, 38, 39, 40, 41, 0, 0, 42, 43, 44, 45, 46, 47, 48, 49, 0, 0, 50, 51, 52, 53,
+        // State 63
+        -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32,
+        // State 64
+        -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27,
+        // State 65
+        -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28,
+        // State 66
+        -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31,
+        // State 67
+        -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29,
+        // State 68
+        -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30,
+        // State 69
+        25, 26, 0, 0, 0, 0, 27, 0, 28, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 31, 0, 33, 34, 35, 0, 37, 38, 39, 40, 41, 0, 0, 42, 43, 44, 45, 46, 47, 48, 49, 0, 0, 50, 51, 52, 53,
+        // State 70
+        -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33,
+        // State 71
+        25, 26, 0, 0, 0, 0, 27, 0, 28, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 31, 0, 33, 34, 35, 0, 37, 38, 39, 40, 41, 0, 0, 42, 43, 44, 45, 46, 47, 48, 49, 0, 0, 50, 51, 52, 53,
+        // State 72
+        -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34,
+        // State 73
+        25, 26, 0, 0, 0, 0, 27, 0, 28, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 31, 0, 33, 34, 35, 0, 37, 38, 39, 40, 41, 0, 0, 42, 43, 44, 45, 46, 47, 48, 49, 0, 0, 50, 51, 52, 53,
+        // State 74
+        -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35,
+        // State 75
+        25, 26, 0, 0, 0, 0, 27, 0, 28, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 31, 0, 33, 34, 35, 0, 37, 38, 39, 40, 41, 0, 0, 42, 43, 44, 45, 46, 47, 48, 49, 0, 0, 50, 51, 52, 53,
+        // State 76
+        -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36,
+        // State 77
+        -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37,
+        // State 78
+        25, 26, 0, 0, 0, 0, 27, 0, 28, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 31, 0, 33, 34, 35, 0, 37, 38, 39, 40, 41, 0, 0, 42, 43, 44, 45, 46, 47, 48, 49, 0, 0, 50, 51, 52, 53,
+        // State 79
+        -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38,
+        // State 80
+        -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39,
+        // State 81
+        25, 26, 0, 0, 0, 0, 27, 0, 28, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 31, 0, 33, 34, 35, 0, 37, 38, 39, 40, 41, 0, 0, 42, 43, 44, 45, 46, 47, 48, 49, 0, 0, 50, 51, 52, 53,
+        // State 82
+        -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42,
+        // State 83
+        -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40,
+        // State 84
+        -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41,
+        // State 85
+        25, 26, 0, 0, 0, 0, 27, -51, 28, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 0, 0, 42, 43, 44, 45, 46, 47, 48, 49, 0, 0, 50, 51, 52, 53,
+        // State 86
+        25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 31, 0, 33, 34, 0, 0, 37, 38, 39, 40, 41, 0, 0, 42, 0, 44, 45, 46, 47, 48, 49, 0, 0, 0, 0, 0, 0,
+        // State 87
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 53,
+        // State 88
+        -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77,
+        // State 89
+        0, 0, 0, 0, 0, 0, 0, 113, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 90
+        -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78,
+        // State 91
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 114, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 115, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 92
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 117, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 93
+        25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 31, 0, 33, 34, 0, 0, 37, 38, 39, 40, 41, 0, 0, 42, 0, 44, 45, 46, 47, 48, 49, 0, 0, 0, 0, 0, 0,
+        // State 94
+        25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 31, 0, 33, 34, 0, 0, 37, 38, 39, 40, 41, 0, 0, 42, 0, 44, 45, 46, 47, 48, 49, 0, 0, 0, 0, 0, 0,
+        // State 95
+        0, 0, 64, 0, 0, -80, 0, -80, 0, 0, -80, 0, 0, -80, 0, 0, 0, 65, 0, 66, -80, 67, -80, 68, 69, 0, -80, 0, -80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -80, 0, 0, 0, 0,
+        // State 96
+        0, 0, -65, -65, -65, -65, 120, -65, -65, -65, -65, -65, -65, -65, -65, 0, 0, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -65, 0, 0, 0, 0, 0, 0, 0, 0, -65, -65, 0, 0, 0, 0,
+        // State 97
+        -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66,
+        // State 98
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 122, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 99
+        -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67,
+        // State 100
+        0, 0, -82, 0, 0, -82, 0, -82, 0, 0, -82, 0, 0, -82, 0, 0, 0, -82, 0, -82, -82, -82, -82, -82, -82, 0, -82, 0, -82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 71, -82, 0, 0, 0, 0,
+        // State 101
+        0, 0, -84, 0, 0, -84, 0, -84, 0, 0, -84, 0, 0, -84, 0, 0, 0, -84, 0, -84, -84, -84, -84, -84, -84, 0, -84, 0, -84, 73, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -84, -84, 0, 0, 0, 0,
+        // State 102
+        0, 0, -86, 0, 75, -86, 0, -86, 0, 0, -86, 0, 0, -86, 0, 0, 0, -86, 0, -86, -86, -86, -86, -86, -86, 0, -86, 0, -86, -86, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -86, -86, 0, 0, 0, 0,
+        // State 103
+        0, 0, -88, 0, -88, -88, 0, -88, 0, 0, -88, 0, 0, -88, 0, 0, 0, -88, 77, -88, -88, -88, -88, -88, -88, 78, -88, 0, -88, -88, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -88, -88, 0, 0, 0, 0,
+        // State 104
+        0, 0, -90, 0, -90, -90, 0, -90, 0, 80, -90, 81, 0, -90, 0, 0, 0, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, 0, -90, -90, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -90, -90, 0, 0, 0, 0,
+        // State 105
+        0, 0, -92, 83, -92, -92, 0, -92, 84, -92, -92, -92, 0, -92, 85, 0, 0, -92, -92, -92, -92, -92, -92, -92, -92, -92, -92, 0, -92, -92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -92, -92, 0, 0, 0, 0,
+        // State 106
+        -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94,
+        // State 107
+        25, 26, 0, 0, 0, 0, 27, -53, 28, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 0, 0, 42, 43, 44, 45, 46, 47, 48, 49, 0, 0, 50, 51, 52, 53,
+        // State 108
+        0, 0, 0, 0, 0, 0, 0, 124, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 109
+        0, 0, 0, 0, 0, 0, 0, -50, 0, 0, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 110
+        -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20,
+        // State 111
+        -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18,
+        // State 112
+        -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69,
+        // State 113
+        25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 31, 0, 33, 34, 0, 0, 37, 38, 39, 40, 41, 0, 0, 42, 0, 44, 45, 46, 47, 48, 49, 0, 0, 0, 0, 0, 0,
+        // State 114
+        25, 0, 0, 0, 0, 0, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 31, 0, 33, 34, 0, 0, 37, 38, 39, 40, 41, 0, 0, 42, 0, 44, 45, 46, 47, 48, 49, 0, 0, 0, 131, 0, 53,
+        // State 115
+        25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 31, 0, 33, 34, 0, 0, 37, 38, 39, 40, 41, 0, 0, 42, 0, 44, 45, 46, 47, 48, 49, 0, 0, 0, 0, 0, 0,
+        // State 116
+        25, 0, 0, 0, 0, 0, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 31, 0, 33, 34, 0, 0, 37, 38, 39, 40, 41, 0, 0, 42, 0, 44, 45, 46, 47, 48, 49, 0, 0, 0, 131, 0, 53,
+        // State 117
+        -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13,
+        // State 118
+        -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15,
+        // State 119
+        25, 26, 0, 0, 0, 0, 27, -51, 28, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 0, 0, 42, 43, 44, 45, 46, 47, 48, 49, 0, 0, 50, 51, 52, 53,
+        // State 120
+        25, 26, 0, 0, 0, 0, 27, 0, 28, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 0, 0, 42, 43, 44, 45, 46, 47, 48, 49, 0, 0, 50, 51, 52, 53,
+        // State 121
+        -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62,
+        // State 122
+        0, 0, 0, 0, 0, 0, 0, -52, 0, 0, 136, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 123
+        -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19,
+        // State 124
+        -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7,
+        // State 125
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 137, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 126
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 138, 139, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 127
+        -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45,
+        // State 128
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 140, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 129
+        25, 26, 0, 0, 0, 0, 27, 0, 28, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 0, 0, 42, 43, 44, 45, 46, 47, 48, 49, 0, 0, 50, 51, 52, 53,
+        // State 130
+        -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43,
+        // State 131
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 142, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 132
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 138, 143, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 133
+        0, 0, 0, 0, 0, 0, 0, 144, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 134
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 145, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 135
+        -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8,
+        // State 136
+        25, 0, 0, 0, 0, 0, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 31, 0, 33, 34, 0, 0, 37, 38, 39, 40, 41, 0, 0, 42, 0, 44, 45, 46, 47, 48, 49, 0, 0, 0, 131, 0, 53,
+        // State 137
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 148, 0, 53,
+        // State 138
+        25, 0, 0, 0, 0, 0, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 31, 0, 33, 34, 0, 0, 37, 38, 39, 40, 41, 0, 0, 42, 0, 44, 45, 46, 47, 48, 49, 0, 0, 0, 131, 0, 53,
+        // State 139
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 53,
+        // State 140
+        0, 0, 0, 0, 0, 0, 0, 151, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 141
+        25, 0, 0, 0, 0, 0, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 31, 0, 33, 34, 0, 0, 37, 38, 39, 40, 41, 0, 0, 42, 0, 44, 45, 46, 47, 48, 49, 0, 0, 0, 131, 0, 53,
+        // State 142
+        25, 0, 0, 0, 0, 0, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 31, 0, 33, 34, 0, 0, 37, 38, 39, 40, 41, 0, 0, 42, 0, 44, 45, 46, 47, 48, 49, 0, 0, 0, 131, 0, 53,
+        // State 143
+        -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64,
+        // State 144
+        -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63,
+        // State 145
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 138, 154, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 146
+        0, 0, 0, 0, 0, 0, 155, 0, 0, 0, 0, 0, -47, -47, 0, -47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 147
+        -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48,
+        // State 148
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 138, 0, 0, 156, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 149
+        -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44,
+        // State 150
+        -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49,
+        // State 151
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 138, 157, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 152
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 138, 0, 0, 158, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 153
+        25, 0, 0, 0, 0, 0, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 31, 0, 33, 34, 0, 0, 37, 38, 39, 40, 41, 0, 0, 42, 0, 44, 45, 46, 47, 48, 49, 0, 0, 0, 131, 0, 53,
+        // State 154
+        25, 26, 0, 0, 0, 0, 27, -51, 28, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 0, 0, 42, 43, 44, 45, 46, 47, 48, 49, 0, 0, 50, 51, 52, 53,
+        // State 155
+        25, 26, 0, 0, 0, 0, 27, 0, 28, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 0, 0, 42, 43, 44, 45, 46, 47, 48, 49, 0, 0, 50, 51, 52, 53,
+        // State 156
+        25, 0, 0, 0, 0, 0, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 31, 0, 33, 34, 0, 0, 37, 38, 39, 40, 41, 0, 0, 42, 0, 44, 45, 46, 47, 48, 49, 0, 0, 0, 131, 0, 53,
+        // State 157
+        25, 26, 0, 0, 0, 0, 27, 0, 28, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 0, 0, 42, 43, 44, 45, 46, 47, 48, 49, 0, 0, 50, 51, 52, 53,
+        // State 158
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 138, 0, 0, 164, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 159
+        0, 0, 0, 0, 0, 0, 0, 165, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 160
+        -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57,
+        // State 161
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 138, 0, 0, 166, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 162
+        -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55,
+        // State 163
+        25, 26, 0, 0, 0, 0, 27, 0, 28, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 0, 0, 42, 43, 44, 45, 46, 47, 48, 49, 0, 0, 50, 51, 52, 53,
+        // State 164
+        -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46,
+        // State 165
+        25, 26, 0, 0, 0, 0, 27, 0, 28, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 0, 0, 42, 43, 44, 45, 46, 47, 48, 49, 0, 0, 50, 51, 52, 53,
+        // State 166
+        -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56,
+        // State 167
+        -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54,
+    ];
+    const __EOF_ACTION: &'static [i32] = &[
+        0,
+        -68,
+        -116,
+        -58,
+        -79,
+        -81,
+        -83,
+        -85,
+        -87,
+        -89,
+        -91,
+        -93,
+        -95,
+        -61,
+        -70,
+        -71,
+        -72,
+        -73,
+        -74,
+        -75,
+        -76,
+        -21,
+        0,
+        0,
+        -113,
+        -115,
+        0,
+        0,
+        -114,
+        -97,
+        -112,
+        0,
+        -110,
+        -111,
+        -12,
+        0,
+        -102,
+        -99,
+        -100,
+        -101,
+        -98,
+        -109,
+        -11,
+        -107,
+        -104,
+        -105,
+        -106,
+        -103,
+        -108,
+        -17,
+        -14,
+        -16,
+        -96,
+        0,
+        -22,
+        -26,
+        -25,
+        -24,
+        -23,
+        0,
+        0,
+        0,
+        0,
+        -32,
+        -27,
+        -28,
+        -31,
+        -29,
+        -30,
+        0,
+        -33,
+        0,
+        -34,
+        0,
+        -35,
+        0,
+        -36,
+        -37,
+        0,
+        -38,
+        -39,
+        0,
+        -42,
+        -40,
+        -41,
+        0,
+        0,
+        0,
+        -77,
+        0,
+        -78,
+        0,
+        0,
+        0,
+        0,
+        -80,
+        -65,
+        -66,
+        0,
+        -67,
+        -82,
+        -84,
+        -86,
+        -88,
+        -90,
+        -92,
+        -94,
+        0,
+        0,
+        0,
+        -20,
+        -18,
+        -69,
+        0,
+        0,
+        0,
+        0,
+        -13,
+        -15,
+        0,
+        0,
+        -62,
+        0,
+        -19,
+        -7,
+        0,
+        0,
+        -45,
+        0,
+        0,
+        -43,
+        0,
+        0,
+        0,
+        0,
+        -8,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        -64,
+        -63,
+        0,
+        0,
+        -48,
+        0,
+        -44,
+        -49,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        -57,
+        0,
+        -55,
+        0,
+        -46,
+        0,
+        -56,
+        -54,
+    ];
+    const __GOTO: &'static [i32] = &[
+        // State 0
+        0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 0,
+        // State 1
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 2
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 3
+        0, 0, 0, 0, 0, 0, 0, 0, 54, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 4
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 5
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 6
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 7
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 72, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 8
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 74, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 9
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 76, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 10
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 11
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 12
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 13
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 14
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 15
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 16
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 17
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 18
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 19
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 20
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 21
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 22
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 23
+        0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 89, 0, 0, 0, 0, 0, 0, 0, 0, 22, 23, 24, 0,
+        // State 24
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 25
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 26
+        0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 90, 0, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 0,
+        // State 27
+        0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 91, 0, 0, 0, 0, 0, 0, 0, 0, 22, 23, 24, 0,
+        // State 28
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 29
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 30
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 31
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 92, 0, 0, 0,
+        // State 32
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 33
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 34
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 35
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 93, 0, 0, 0,
+        // State 36
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 37
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 38
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 39
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 40
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 41
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 42
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 43
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 44
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 45
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 46
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 47
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 48
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 49
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 50
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 51
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 52
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 53
+        0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 96, 7, 8, 9, 10, 11, 12, 13, 0, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 0,
+        // State 54
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 55
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 56
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 57
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 58
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 59
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 97, 0, 0, 0,
+        // State 60
+        0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 99, 0, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 0,
+        // State 61
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 100, 0, 0, 0,
+        // State 62
+        0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 101, 8, 9, 10, 11, 12, 13, 0, 0, 16, 17, 18, 19, 20, 21, 22, 23, 24, 0,
+        // State 63
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 64
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 65
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 66
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 67
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 68
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 69
+        0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 102, 9, 10, 11, 12, 13, 0, 0, 0, 17, 18, 19, 20, 21, 22, 23, 24, 0,
+        // State 70
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 71
+        0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 103, 10, 11, 12, 13, 0, 0, 0, 0, 18, 19, 20, 21, 22, 23, 24, 0,
+        // State 72
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 73
+        0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 104, 11, 12, 13, 0, 0, 0, 0, 0, 19, 20, 21, 22, 23, 24, 0,
+        // State 74
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 75
+        0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 105, 12, 13, 0, 0, 0, 0, 0, 0, 20, 21, 22, 23, 24, 0,
+        // State 76
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 77
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 78
+        0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 106, 13, 0, 0, 0, 0, 0, 0, 0, 21, 22, 23, 24, 0,
+        // State 79
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 80
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 81
+        0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 107, 0, 0, 0, 0, 0, 0, 0, 0, 22, 23, 24, 0,
+        // State 82
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 83
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 84
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 85
+        0, 0, 0, 0, 108, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 109, 110, 0, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 0,
+        // State 86
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 111, 0, 0,
+        // State 87
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 112, 0, 0, 0,
+        // State 88
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 89
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 90
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 91
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 92
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 93
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 118, 0, 0,
+        // State 94
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 119, 0, 0,
+        // State 95
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 96
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 97
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 98
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 99
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 100
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 101
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 72, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 102
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 74, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 103
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 76, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 104
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 105
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 106
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 107
+        0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 123, 0, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 0,
+        // State 108
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 109
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 110
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 111
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 112
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 113
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 126, 0, 0,
+        // State 114
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 127, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 129, 0, 0,
+        // State 115
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 132, 0, 0,
+        // State 116
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 129, 0, 0,
+        // State 117
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 118
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 119
+        0, 0, 0, 0, 108, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 134, 110, 0, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 0,
+        // State 120
+        0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 135, 0, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 0,
+        // State 121
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 122
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 123
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 124
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 125
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 126
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 127
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 128
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 129
+        0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 141, 0, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 0,
+        // State 130
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 131
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 132
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 133
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 134
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 135
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 136
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 146, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 129, 0, 0,
+        // State 137
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 147, 0, 0, 0,
+        // State 138
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 149, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 129, 0, 0,
+        // State 139
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 150, 0, 0, 0,
+        // State 140
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 141
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 152, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 129, 0, 0,
+        // State 142
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 153, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 129, 0, 0,
+        // State 143
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 144
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 145
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 146
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 147
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 148
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 149
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 150
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 151
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 152
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 153
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 159, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 129, 0, 0,
+        // State 154
+        0, 0, 0, 0, 108, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 160, 110, 0, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 0,
+        // State 155
+        0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 161, 0, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 0,
+        // State 156
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 162, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 129, 0, 0,
+        // State 157
+        0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 163, 0, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 0,
+        // State 158
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 159
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 160
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 161
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 162
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 163
+        0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 167, 0, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 0,
+        // State 164
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 165
+        0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 168, 0, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 0,
+        // State 166
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 167
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    fn __expected_tokens(__state: usize) -> Vec<::std::string::String> {
+        const __TERMINAL: &'static [&'static str] = &[
+            r###""""###,
+            r###""!""###,
+            r###""!=""###,
+            r###""%""###,
+            r###""&""###,
+            r###""&&""###,
+            r###""(""###,
+            r###"")""###,
+            r###""*""###,
+            r###""+""###,
+            r###"",""###,
+            r###""-""###,
+            r###"".""###,
+            r###""..""###,
+            r###""/""###,
+            r###"":""###,
+            r###""::""###,
+            r###""<""###,
+            r###""<<""###,
+            r###""<=""###,
+            r###""<=>""###,
+            r###""==""###,
+            r###""=>""###,
+            r###"">""###,
+            r###"">=""###,
+            r###"">>""###,
+            r###""XOR""###,
+            r###""[""###,
+            r###""]""###,
+            r###""^""###,
+            r###""bool""###,
+            r###""char""###,
+            r###""exists""###,
+            r###""f32""###,
+            r###""f64""###,
+            r###""false""###,
+            r###""forall""###,
+            r###""i128""###,
+            r###""i16""###,
+            r###""i32""###,
+            r###""i64""###,
+            r###""i8""###,
+            r###""in""###,
+            r###""is""###,
+            r###""isize""###,
+            r###""true""###,
+            r###""u128""###,
+            r###""u16""###,
+            r###""u32""###,
+            r###""u64""###,
+            r###""u8""###,
+            r###""usize""###,
+            r###""|""###,
+            r###""||""###,
+            r###"r#"'([^'\\\\]|\\\\[nrt0'\\\\])'"#"###,
+            r###"r#"-?[0-9]+"#"###,
+            r###"r#"-?[0-9]+(\\.[0-9]+([eE][-+]?[0-9]+)?|[eE][-+]?[0-9]+)"#"###,
+            r###"r#"[a-zA-Z][a-zA-Z0-9_]*"#"###,
+        ];
+        __ACTION[(__state * 58)..].iter().zip(__TERMINAL).filter_map(|(&state, terminal)| {
+            if state == 0 {
+                None
+            } else {
+                Some(terminal.to_string())
+            }
+        }).collect()
+    }
+    pub fn parse_Condition<
+        'input,
+    >(
+        input: &'input str,
+    ) -> Result<Expression, __lalrpop_util::ParseError<usize, (usize, &'input str), ()>>
+    {
+        let mut __tokens = super::__intern_token::__Matcher::new(input);
+        let mut __states = vec![0_i32];
+        let mut __symbols = vec![];
+        let mut __integer;
+        let mut __lookahead;
+        let mut __last_location = Default::default();
+        '__shift: loop {
+            __lookahead = match __tokens.next() {
+                Some(Ok(v)) => v,
+                None => break '__shift,
+                Some(Err(e)) => return Err(e),
+            };
+            __last_location = __lookahead.2.clone();
+            __integer = match __lookahead.1 {
+                (4, _) if true => 0,
+                (5, _) if true => 1,
+                (6, _) if true => 2,
+                (7, _) if true => 3,
+                (8, _) if true => 4,
+                (9, _) if true => 5,
+                (10, _) if true => 6,
+                (11, _) if true => 7,
+                (12, _) if true => 8,
+                (13, _) if true => 9,
+                (14, _) if true => 10,
+                (15, _) if true => 11,
+                (16, _) if true => 12,
+                (17, _) if true => 13,
+                (18, _) if true => 14,
+                (19, _) if true => 15,
+                (20, _) if true => 16,
+                (21, _) if true => 17,
+                (22, _) if true => 18,
+                (23, _) if true => 19,
+                (24, _) if true => 20,
+                (25, _) if true => 21,
+                (26, _) if true => 22,
+                (27, _) if true => 23,
+                (28, _) if true => 24,
+                (29, _) if true => 25,
+                (30, _) if true => 26,
+                (31, _) if true => 27,
+                (32, _) if true => 28,
+                (33, _) if true => 29,
+                (34, _) if true => 30,
+                (35, _) if true => 31,
+                (36, _) if true => 32,
+                (37, _) if true => 33,
+                (38, _) if true => 34,
+                (39, _) if true => 35,
+                (40, _) if true => 36,
+                (41, _) if true => 37,
+                (42, _) if true => 38,
+                (43, _) if true => 39,
+                (44, _) if true => 40,
+                (45, _) if true => 41,
+                (46, _) if true => 42,
+                (47, _) if true => 43,
+                (48, _) if true => 44,
+                (49, _) if true => 45,
+                (50, _) if true => 46,
+                (51, _) if true => 47,
+                (52, _) if true => 48,
+                (53, _) if true => 49,
+                (54, _) if true => 50,
+                (55, _) if true => 51,
+                (56, _) if true => 52,
+                (57, _) if true => 53,
+                (0, _) if true => 54,
+                (1, _) if true => 55,
+                (2, _) if true => 56,
+                (3, _) if true => 57,
+                _ => {
+                    let __state = *__states.last().unwrap() as usize;
+                    let __error = __lalrpop_util::ParseError::UnrecognizedToken {
+                        token: Some(__lookahead),
+                        expected: __expected_tokens(__state),
+                    };
+                    return Err(__error);
+                }
+            };
+            '__inner: loop {
+                let __state = *__states.last().unwrap() as usize;
+                let __action = __ACTION[__state * 58 + __integer];
+                if __action > 0 {
+                    let __symbol = match __integer {
+                        0 => match __lookahead.1 {
+                            (4, __tok0) => __Symbol::Term_22_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        1 => match __lookahead.1 {
+                            (5, __tok0) => __Symbol::Term_22_21_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        2 => match __lookahead.1 {
+                            (6, __tok0) => __Symbol::Term_22_21_3d_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        3 => match __lookahead.1 {
+                            (7, __tok0) => __Symbol::Term_22_25_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        4 => match __lookahead.1 {
+                            (8, __tok0) => __Symbol::Term_22_26_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        5 => match __lookahead.1 {
+                            (9, __tok0) => __Symbol::Term_22_26_26_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        6 => match __lookahead.1 {
+                            (10, __tok0) => __Symbol::Term_22_28_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        7 => match __lookahead.1 {
+                            (11, __tok0) => __Symbol::Term_22_29_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        8 => match __lookahead.1 {
+                            (12, __tok0) => __Symbol::Term_22_2a_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        9 => match __lookahead.1 {
+                            (13, __tok0) => __Symbol::Term_22_2b_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        10 => match __lookahead.1 {
+                            (14, __tok0) => __Symbol::Term_22_2c_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        11 => match __lookahead.1 {
+                            (15, __tok0) => __Symbol::Term_22_2d_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        12 => match __lookahead.1 {
+                            (16, __tok0) => __Symbol::Term_22_2e_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        13 => match __lookahead.1 {
+                            (17, __tok0) => __Symbol::Term_22_2e_2e_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        14 => match __lookahead.1 {
+                            (18, __tok0) => __Symbol::Term_22_2f_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        15 => match __lookahead.1 {
+                            (19, __tok0) => __Symbol::Term_22_3a_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        16 => match __lookahead.1 {
+                            (20, __tok0) => __Symbol::Term_22_3a_3a_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        17 => match __lookahead.1 {
+                            (21, __tok0) => __Symbol::Term_22_3c_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        18 => match __lookahead.1 {
+                            (22, __tok0) => __Symbol::Term_22_3c_3c_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        19 => match __lookahead.1 {
+                            (23, __tok0) => __Symbol::Term_22_3c_3d_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        20 => match __lookahead.1 {
+                            (24, __tok0) => __Symbol::Term_22_3c_3d_3e_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        21 => match __lookahead.1 {
+                            (25, __tok0) => __Symbol::Term_22_3d_3d_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        22 => match __lookahead.1 {
+                            (26, __tok0) => __Symbol::Term_22_3d_3e_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        23 => match __lookahead.1 {
+                            (27, __tok0) => __Symbol::Term_22_3e_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        24 => match __lookahead.1 {
+                            (28, __tok0) => __Symbol::Term_22_3e_3d_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        25 => match __lookahead.1 {
+                            (29, __tok0) => __Symbol::Term_22_3e_3e_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        26 => match __lookahead.1 {
+                            (30, __tok0) => __Symbol::Term_22XOR_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        27 => match __lookahead.1 {
+                            (31, __tok0) => __Symbol::Term_22_5b_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        28 => match __lookahead.1 {
+                            (32, __tok0) => __Symbol::Term_22_5d_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        29 => match __lookahead.1 {
+                            (33, __tok0) => __Symbol::Term_22_5e_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        30 => match __lookahead.1 {
+                            (34, __tok0) => __Symbol::Term_22bool_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        31 => match __lookahead.1 {
+                            (35, __tok0) => __Symbol::Term_22char_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        32 => match __lookahead.1 {
+                            (36, __tok0) => __Symbol::Term_22exists_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        33 => match __lookahead.1 {
+                            (37, __tok0) => __Symbol::Term_22f32_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        34 => match __lookahead.1 {
+                            (38, __tok0) => __Symbol::Term_22f64_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        35 => match __lookahead.1 {
+                            (39, __tok0) => __Symbol::Term_22false_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        36 => match __lookahead.1 {
+                            (40, __tok0) => __Symbol::Term_22forall_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        37 => match __lookahead.1 {
+                            (41, __tok0) => __Symbol::Term_22i128_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        38 => match __lookahead.1 {
+                            (42, __tok0) => __Symbol::Term_22i16_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        39 => match __lookahead.1 {
+                            (43, __tok0) => __Symbol::Term_22i32_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        40 => match __lookahead.1 {
+                            (44, __tok0) => __Symbol::Term_22i64_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        41 => match __lookahead.1 {
+                            (45, __tok0) => __Symbol::Term_22i8_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        42 => match __lookahead.1 {
+                            (46, __tok0) => __Symbol::Term_22in_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        43 => match __lookahead.1 {
+                            (47, __tok0) => __Symbol::Term_22is_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        44 => match __lookahead.1 {
+                            (48, __tok0) => __Symbol::Term_22isize_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        45 => match __lookahead.1 {
+                            (49, __tok0) => __Symbol::Term_22true_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        46 => match __lookahead.1 {
+                            (50, __tok0) => __Symbol::Term_22u128_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        47 => match __lookahead.1 {
+                            (51, __tok0) => __Symbol::Term_22u16_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        48 => match __lookahead.1 {
+                            (52, __tok0) => __Symbol::Term_22u32_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        49 => match __lookahead.1 {
+                            (53, __tok0) => __Symbol::Term_22u64_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        50 => match __lookahead.1 {
+                            (54, __tok0) => __Symbol::Term_22u8_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        51 => match __lookahead.1 {
+                            (55, __tok0) => __Symbol::Term_22usize_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        52 => match __lookahead.1 {
+                            (56, __tok0) => __Symbol::Term_22_7c_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        53 => match __lookahead.1 {
+                            (57, __tok0) => __Symbol::Term_22_7c_7c_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        54 => match __lookahead.1 {
+                            (0, __tok0) => __Symbol::Termr_23_22_27_28_5b_5e_27_5c_5c_5c_5c_5d_7c_5c_5c_5c_5c_5bnrt0_27_5c_5c_5c_5c_5d_29_27_22_23((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        55 => match __lookahead.1 {
+                            (1, __tok0) => __Symbol::Termr_23_22_2d_3f_5b0_2d9_5d_2b_22_23((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        56 => match __lookahead.1 {
+                            (2, __tok0) => __Symbol::Termr_23_22_2d_3f_5b0_2d9_5d_2b_28_5c_5c_2e_5b0_2d9_5d_2b_28_5beE_5d_5b_2d_2b_5d_3f_5b0_2d9_5d_2b_29_3f_7c_5beE_5d_5b_2d_2b_5d_3f_5b0_2d9_5d_2b_29_22_23((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        57 => match __lookahead.1 {
+                            (3, __tok0) => __Symbol::Termr_23_22_5ba_2dzA_2dZ_5d_5ba_2dzA_2dZ0_2d9___5d_2a_22_23((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        _ => unreachable!(),
+                    };
+                    __states.push(__action - 1);
+                    __symbols.push((__lookahead.0, __symbol, __lookahead.2));
+                    continue '__shift;
+                } else if __action < 0 {
+                    if let Some(r) = __reduce(input, __action, Some(&__lookahead.0), &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                        return r;
+                    }
+                } else {
+                    let __state = *__states.last().unwrap() as usize;
+                    let __error = __lalrpop_util::ParseError::UnrecognizedToken {
+                        token: Some(__lookahead),
+                        expected: __expected_tokens(__state),
+                    };
+                    return Err(__error)
+                }
+            }
+        }
+        loop {
+            let __state = *__states.last().unwrap() as usize;
+            let __action = __EOF_ACTION[__state];
+            if __action < 0 {
+                if let Some(r) = __reduce(input, __action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                    return r;
+                }
+            } else {
+                let __state = *__states.last().unwrap() as usize;
+                let __error = __lalrpop_util::ParseError::UnrecognizedToken {
+                    token: None,
+                    expected: __expected_tokens(__state),
+                };
+                return Err(__error);
+            }
+        }
+    }
+    pub fn __reduce<
+        'input,
+    >(
+        input: &'input str,
+        __action: i32,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Option<Result<Expression,__lalrpop_util::ParseError<usize, (usize, &'input str), ()>>>
+    {
+        let __nonterminal = match -__action {
+            1 => {
+                // (":" <TYPE>) = ":", TYPE => ActionFn(102);
+                let __sym1 = __pop_NtTYPE(__symbols);
+                let __sym0 = __pop_Term_22_3a_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action102::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28_22_3a_22_20_3cTYPE_3e_29(__nt), __end));
+                0
+            }
+            2 => {
+                // (":" <TYPE>)? = ":", TYPE => ActionFn(111);
+                let __sym1 = __pop_NtTYPE(__symbols);
+                let __sym0 = __pop_Term_22_3a_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action111::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28_22_3a_22_20_3cTYPE_3e_29_3f(__nt), __end));
+                1
+            }
+            3 => {
+                // (":" <TYPE>)? =  => ActionFn(101);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::__action101::<>(input, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::Nt_28_22_3a_22_20_3cTYPE_3e_29_3f(__nt), __end));
+                1
+            }
+            4 => {
+                // (<Condition> ",") = Condition, "," => ActionFn(108);
+                let __sym1 = __pop_Term_22_2c_22(__symbols);
+                let __sym0 = __pop_NtCondition(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action108::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28_3cCondition_3e_20_22_2c_22_29(__nt), __end));
+                2
+            }
+            5 => {
+                // (<Condition> ",")* =  => ActionFn(106);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::__action106::<>(input, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::Nt_28_3cCondition_3e_20_22_2c_22_29_2a(__nt), __end));
+                3
+            }
+            6 => {
+                // (<Condition> ",")* = (<Condition> ",")+ => ActionFn(107);
+                let __sym0 = __pop_Nt_28_3cCondition_3e_20_22_2c_22_29_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action107::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::Nt_28_3cCondition_3e_20_22_2c_22_29_2a(__nt), __end));
+                3
+            }
+            7 => {
+                // (<Condition> ",")+ = Condition, "," => ActionFn(116);
+                let __sym1 = __pop_Term_22_2c_22(__symbols);
+                let __sym0 = __pop_NtCondition(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action116::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28_3cCondition_3e_20_22_2c_22_29_2b(__nt), __end));
+                4
+            }
+            8 => {
+                // (<Condition> ",")+ = (<Condition> ",")+, Condition, "," => ActionFn(117);
+                let __sym2 = __pop_Term_22_2c_22(__symbols);
+                let __sym1 = __pop_NtCondition(__symbols);
+                let __sym0 = __pop_Nt_28_3cCondition_3e_20_22_2c_22_29_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action117::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::Nt_28_3cCondition_3e_20_22_2c_22_29_2b(__nt), __end));
+                4
+            }
+            9 => {
+                // @L =  => ActionFn(103);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::__action103::<>(input, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::Nt_40L(__nt), __end));
+                5
+            }
+            10 => {
+                // @R =  => ActionFn(83);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::__action83::<>(input, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::Nt_40R(__nt), __end));
+                6
+            }
+            11 => {
+                // Atom = "true" => ActionFn(23);
+                let __sym0 = __pop_Term_22true_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action23::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtAtom(__nt), __end));
+                7
+            }
+            12 => {
+                // Atom = "false" => ActionFn(24);
+                let __sym0 = __pop_Term_22false_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action24::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtAtom(__nt), __end));
+                7
+            }
+            13 => {
+                // Atom = r#"-?[0-9]+"#, ":", TYPE => ActionFn(25);
+                let __sym2 = __pop_NtTYPE(__symbols);
+                let __sym1 = __pop_Term_22_3a_22(__symbols);
+                let __sym0 = __pop_Termr_23_22_2d_3f_5b0_2d9_5d_2b_22_23(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action25::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtAtom(__nt), __end));
+                7
+            }
+            14 => {
+                // Atom = r#"-?[0-9]+"# => ActionFn(26);
+                let __sym0 = __pop_Termr_23_22_2d_3f_5b0_2d9_5d_2b_22_23(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action26::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtAtom(__nt), __end));
+                7
+            }
+            15 => {
+                // Atom = r#"-?[0-9]+(\\.[0-9]+([eE][-+]?[0-9]+)?|[eE][-+]?[0-9]+)"#, ":", TYPE => ActionFn(27);
+                let __sym2 = __pop_NtTYPE(__symbols);
+                let __sym1 = __pop_Term_22_3a_22(__symbols);
+                let __sym0 = __pop_Termr_23_22_2d_3f_5b0_2d9_5d_2b_28_5c_5c_2e_5b0_2d9_5d_2b_28_5beE_5d_5b_2d_2b_5d_3f_5b0_2d9_5d_2b_29_3f_7c_5beE_5d_5b_2d_2b_5d_3f_5b0_2d9_5d_2b_29_22_23(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action27::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtAtom(__nt), __end));
+                7
+            }
+            16 => {
+                // Atom = r#"-?[0-9]+(\\.[0-9]+([eE][-+]?[0-9]+)?|[eE][-+]?[0-9]+)"# => ActionFn(28);
+                let __sym0 = __pop_Termr_23_22_2d_3f_5b0_2d9_5d_2b_28_5c_5c_2e_5b0_2d9_5d_2b_28_5beE_5d_5b_2d_2b_5d_3f_5b0_2d9_5d_2b_29_3f_7c_5beE_5d_5b_2d_2b_5d_3f_5b0_2d9_5d_2b_29_22_23(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action28::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtAtom(__nt), __end));
+                7
+            }
+            17 => {
+                // Atom = r#"'([^'\\\\]|\\\\[nrt0'\\\\])'"# => ActionFn(29);
+                let __sym0 = __pop_Termr_23_22_27_28_5b_5e_27_5c_5c_5c_5c_5d_7c_5c_5c_5c_5c_5bnrt0_27_5c_5c_5c_5c_5d_29_27_22_23(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action29::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtAtom(__nt), __end));
+                7
+            }
+            18 => {
+                // Atom = TYPE, "::", IDENTIFIER => ActionFn(30);
+                let __sym2 = __pop_NtIDENTIFIER(__symbols);
+                let __sym1 = __pop_Term_22_3a_3a_22(__symbols);
+                let __sym0 = __pop_NtTYPE(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action30::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtAtom(__nt), __end));
+                7
+            }
+            19 => {
+                // Atom = IDENTIFIER, "(", Comma<Condition>, ")" => ActionFn(31);
+                let __sym3 = __pop_Term_22_29_22(__symbols);
+                let __sym2 = __pop_NtComma_3cCondition_3e(__symbols);
+                let __sym1 = __pop_Term_22_28_22(__symbols);
+                let __sym0 = __pop_NtIDENTIFIER(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym3.2.clone();
+                let __nt = super::__action31::<>(input, __sym0, __sym1, __sym2, __sym3);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 4);
+                __symbols.push((__start, __Symbol::NtAtom(__nt), __end));
+                7
+            }
+            20 => {
+                // Atom = IDENTIFIER, ":", TYPE => ActionFn(32);
+                let __sym2 = __pop_NtTYPE(__symbols);
+                let __sym1 = __pop_Term_22_3a_22(__symbols);
+                let __sym0 = __pop_NtIDENTIFIER(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action32::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtAtom(__nt), __end));
+                7
+            }
+            21 => {
+                // Atom = IDENTIFIER => ActionFn(33);
+                let __sym0 = __pop_NtIDENTIFIER(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action33::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtAtom(__nt), __end));
+                7
+            }
+            22 => {
+                // BOP1 = "&&" => ActionFn(43);
+                let __sym0 = __pop_Term_22_26_26_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action43::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBOP1(__nt), __end));
+                8
+            }
+            23 => {
+                // BOP1 = "||" => ActionFn(44);
+                let __sym0 = __pop_Term_22_7c_7c_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action44::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBOP1(__nt), __end));
+                8
+            }
+            24 => {
+                // BOP1 = "XOR" => ActionFn(45);
+                let __sym0 = __pop_Term_22XOR_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action45::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBOP1(__nt), __end));
+                8
+            }
+            25 => {
+                // BOP1 = "=>" => ActionFn(46);
+                let __sym0 = __pop_Term_22_3d_3e_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action46::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBOP1(__nt), __end));
+                8
+            }
+            26 => {
+                // BOP1 = "<=>" => ActionFn(47);
+                let __sym0 = __pop_Term_22_3c_3d_3e_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action47::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBOP1(__nt), __end));
+                8
+            }
+            27 => {
+                // BOP2 = "<" => ActionFn(48);
+                let __sym0 = __pop_Term_22_3c_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action48::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBOP2(__nt), __end));
+                9
+            }
+            28 => {
+                // BOP2 = "<=" => ActionFn(49);
+                let __sym0 = __pop_Term_22_3c_3d_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action49::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBOP2(__nt), __end));
+                9
+            }
+            29 => {
+                // BOP2 = ">" => ActionFn(50);
+                let __sym0 = __pop_Term_22_3e_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action50::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBOP2(__nt), __end));
+                9
+            }
+            30 => {
+                // BOP2 = ">=" => ActionFn(51);
+                let __sym0 = __pop_Term_22_3e_3d_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action51::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBOP2(__nt), __end));
+                9
+            }
+            31 => {
+                // BOP2 = "==" => ActionFn(52);
+                let __sym0 = __pop_Term_22_3d_3d_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action52::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBOP2(__nt), __end));
+                9
+            }
+            32 => {
+                // BOP2 = "!=" => ActionFn(53);
+                let __sym0 = __pop_Term_22_21_3d_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action53::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBOP2(__nt), __end));
+                9
+            }
+            33 => {
+                // BOP3 = "|" => ActionFn(54);
+                let __sym0 = __pop_Term_22_7c_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action54::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBOP3(__nt), __end));
+                10
+            }
+            34 => {
+                // BOP4 = "^" => ActionFn(55);
+                let __sym0 = __pop_Term_22_5e_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action55::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBOP4(__nt), __end));
+                11
+            }
+            35 => {
+                // BOP5 = "&" => ActionFn(56);
+                let __sym0 = __pop_Term_22_26_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action56::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBOP5(__nt), __end));
+                12
+            }
+            36 => {
+                // BOP6 = "<<" => ActionFn(57);
+                let __sym0 = __pop_Term_22_3c_3c_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action57::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBOP6(__nt), __end));
+                13
+            }
+            37 => {
+                // BOP6 = ">>" => ActionFn(58);
+                let __sym0 = __pop_Term_22_3e_3e_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action58::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBOP6(__nt), __end));
+                13
+            }
+            38 => {
+                // BOP7 = "+" => ActionFn(59);
+                let __sym0 = __pop_Term_22_2b_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action59::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBOP7(__nt), __end));
+                14
+            }
+            39 => {
+                // BOP7 = "-" => ActionFn(60);
+                let __sym0 = __pop_Term_22_2d_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action60::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBOP7(__nt), __end));
+                14
+            }
+            40 => {
+                // BOP8 = "*" => ActionFn(61);
+                let __sym0 = __pop_Term_22_2a_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action61::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBOP8(__nt), __end));
+                15
+            }
+            41 => {
+                // BOP8 = "/" => ActionFn(62);
+                let __sym0 = __pop_Term_22_2f_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action62::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBOP8(__nt), __end));
+                15
+            }
+            42 => {
+                // BOP8 = "%" => ActionFn(63);
+                let __sym0 = __pop_Term_22_25_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action63::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBOP8(__nt), __end));
+                15
+            }
+            43 => {
+                // Bound = r#"-?[0-9]+"# => ActionFn(34);
+                let __sym0 = __pop_Termr_23_22_2d_3f_5b0_2d9_5d_2b_22_23(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action34::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBound(__nt), __end));
+                16
+            }
+            44 => {
+                // Bound = TYPE, "::", IDENTIFIER => ActionFn(35);
+                let __sym2 = __pop_NtIDENTIFIER(__symbols);
+                let __sym1 = __pop_Term_22_3a_3a_22(__symbols);
+                let __sym0 = __pop_NtTYPE(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action35::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtBound(__nt), __end));
+                16
+            }
+            45 => {
+                // Bound = IDENTIFIER => ActionFn(36);
+                let __sym0 = __pop_NtIDENTIFIER(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action36::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBound(__nt), __end));
+                16
+            }
+            46 => {
+                // Bound = Bound, ".", IDENTIFIER, "(", Comma<Condition>, ")" => ActionFn(37);
+                let __sym5 = __pop_Term_22_29_22(__symbols);
+                let __sym4 = __pop_NtComma_3cCondition_3e(__symbols);
+                let __sym3 = __pop_Term_22_28_22(__symbols);
+                let __sym2 = __pop_NtIDENTIFIER(__symbols);
+                let __sym1 = __pop_Term_22_2e_22(__symbols);
+                let __sym0 = __pop_NtBound(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym5.2.clone();
+                let __nt = super::__action37::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 6);
+                __symbols.push((__start, __Symbol::NtBound(__nt), __end));
+                16
+            }
+            47 => {
+                // Bound = Bound, ".", IDENTIFIER => ActionFn(38);
+                let __sym2 = __pop_NtIDENTIFIER(__symbols);
+                let __sym1 = __pop_Term_22_2e_22(__symbols);
+                let __sym0 = __pop_NtBound(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action38::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtBound(__nt), __end));
+                16
+            }
+            48 => {
+                // Bound = Bound, ".", r#"-?[0-9]+"# => ActionFn(39);
+                let __sym2 = __pop_Termr_23_22_2d_3f_5b0_2d9_5d_2b_22_23(__symbols);
+                let __sym1 = __pop_Term_22_2e_22(__symbols);
+                let __sym0 = __pop_NtBound(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action39::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtBound(__nt), __end));
+                16
+            }
+            49 => {
+                // Bound = "(", Condition, ")" => ActionFn(40);
+                let __sym2 = __pop_Term_22_29_22(__symbols);
+                let __sym1 = __pop_NtCondition(__symbols);
+                let __sym0 = __pop_Term_22_28_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action40::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtBound(__nt), __end));
+                16
+            }
+            50 => {
+                // Comma<Condition> = Condition => ActionFn(133);
+                let __sym0 = __pop_NtCondition(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action133::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtComma_3cCondition_3e(__nt), __end));
+                17
+            }
+            51 => {
+                // Comma<Condition> =  => ActionFn(134);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::__action134::<>(input, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::NtComma_3cCondition_3e(__nt), __end));
+                17
+            }
+            52 => {
+                // Comma<Condition> = (<Condition> ",")+, Condition => ActionFn(135);
+                let __sym1 = __pop_NtCondition(__symbols);
+                let __sym0 = __pop_Nt_28_3cCondition_3e_20_22_2c_22_29_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action135::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtComma_3cCondition_3e(__nt), __end));
+                17
+            }
+            53 => {
+                // Comma<Condition> = (<Condition> ",")+ => ActionFn(136);
+                let __sym0 = __pop_Nt_28_3cCondition_3e_20_22_2c_22_29_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action136::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtComma_3cCondition_3e(__nt), __end));
+                17
+            }
+            54 => {
+                // Condition = "forall", IDENTIFIER, ":", TYPE, "in", Bound, "..", Bound, ":", Condition => ActionFn(120);
+                let __sym9 = __pop_NtCondition(__symbols);
+                let __sym8 = __pop_Term_22_3a_22(__symbols);
+                let __sym7 = __pop_NtBound(__symbols);
+                let __sym6 = __pop_Term_22_2e_2e_22(__symbols);
+                let __sym5 = __pop_NtBound(__symbols);
+                let __sym4 = __pop_Term_22in_22(__symbols);
+                let __sym3 = __pop_NtTYPE(__symbols);
+                let __sym2 = __pop_Term_22_3a_22(__symbols);
+                let __sym1 = __pop_NtIDENTIFIER(__symbols);
+                let __sym0 = __pop_Term_22forall_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym9.2.clone();
+                let __nt = super::__action120::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7, __sym8, __sym9);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 10);
+                __symbols.push((__start, __Symbol::NtCondition(__nt), __end));
+                18
+            }
+            55 => {
+                // Condition = "forall", IDENTIFIER, "in", Bound, "..", Bound, ":", Condition => ActionFn(121);
+                let __sym7 = __pop_NtCondition(__symbols);
+                let __sym6 = __pop_Term_22_3a_22(__symbols);
+                let __sym5 = __pop_NtBound(__symbols);
+                let __sym4 = __pop_Term_22_2e_2e_22(__symbols);
+                let __sym3 = __pop_NtBound(__symbols);
+                let __sym2 = __pop_Term_22in_22(__symbols);
+                let __sym1 = __pop_NtIDENTIFIER(__symbols);
+                let __sym0 = __pop_Term_22forall_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym7.2.clone();
+                let __nt = super::__action121::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 8);
+                __symbols.push((__start, __Symbol::NtCondition(__nt), __end));
+                18
+            }
+            56 => {
+                // Condition = "exists", IDENTIFIER, ":", TYPE, "in", Bound, "..", Bound, ":", Condition => ActionFn(122);
+                let __sym9 = __pop_NtCondition(__symbols);
+                let __sym8 = __pop_Term_22_3a_22(__symbols);
+                let __sym7 = __pop_NtBound(__symbols);
+                let __sym6 = __pop_Term_22_2e_2e_22(__symbols);
+                let __sym5 = __pop_NtBound(__symbols);
+                let __sym4 = __pop_Term_22in_22(__symbols);
+                let __sym3 = __pop_NtTYPE(__symbols);
+                let __sym2 = __pop_Term_22_3a_22(__symbols);
+                let __sym1 = __pop_NtIDENTIFIER(__symbols);
+                let __sym0 = __pop_Term_22exists_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym9.2.clone();
+                let __nt = super::__action122::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7, __sym8, __sym9);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 10);
+                __symbols.push((__start, __Symbol::NtCondition(__nt), __end));
+                18
+            }
+            57 => {
+                // Condition = "exists", IDENTIFIER, "in", Bound, "..", Bound, ":", Condition => ActionFn(123);
+                let __sym7 = __pop_NtCondition(__symbols);
+                let __sym6 = __pop_Term_22_3a_22(__symbols);
+                let __sym5 = __pop_NtBound(__symbols);
+                let __sym4 = __pop_Term_22_2e_2e_22(__symbols);
+                let __sym3 = __pop_NtBound(__symbols);
+                let __sym2 = __pop_Term_22in_22(__symbols);
+                let __sym1 = __pop_NtIDENTIFIER(__symbols);
+                let __sym0 = __pop_Term_22exists_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym7.2.clone();
+                let __nt = super::__action123::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 8);
+                __symbols.push((__start, __Symbol::NtCondition(__nt), __end));
+                18
+            }
+            58 => {
+                // Condition = E1 => ActionFn(3);
+                let __sym0 = __pop_NtE1(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action3::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtCondition(__nt), __end));
+                18
+            }
+            59 => {
+                // Condition? = Condition => ActionFn(104);
+                let __sym0 = __pop_NtCondition(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action104::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtCondition_3f(__nt), __end));
+                19
+            }
+            60 => {
+                // Condition? =  => ActionFn(105);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::__action105::<>(input, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::NtCondition_3f(__nt), __end));
+                19
+            }
+            61 => {
+                // E1 = ExprHelper<E1, BOP1, E2> => ActionFn(4);
+                let __sym0 = __pop_NtExprHelper_3cE1_2c_20BOP1_2c_20E2_3e(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action4::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtE1(__nt), __end));
+                20
+            }
+            62 => {
+                // E10 = E10, "[", Condition, "]" => ActionFn(126);
+                let __sym3 = __pop_Term_22_5d_22(__symbols);
+                let __sym2 = __pop_NtCondition(__symbols);
+                let __sym1 = __pop_Term_22_5b_22(__symbols);
+                let __sym0 = __pop_NtE10(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym3.2.clone();
+                let __nt = super::__action126::<>(input, __sym0, __sym1, __sym2, __sym3);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 4);
+                __symbols.push((__start, __Symbol::NtE10(__nt), __end));
+                21
+            }
+            63 => {
+                // E10 = E10, "[", Condition, "..", Condition, "]" => ActionFn(127);
+                let __sym5 = __pop_Term_22_5d_22(__symbols);
+                let __sym4 = __pop_NtCondition(__symbols);
+                let __sym3 = __pop_Term_22_2e_2e_22(__symbols);
+                let __sym2 = __pop_NtCondition(__symbols);
+                let __sym1 = __pop_Term_22_5b_22(__symbols);
+                let __sym0 = __pop_NtE10(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym5.2.clone();
+                let __nt = super::__action127::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 6);
+                __symbols.push((__start, __Symbol::NtE10(__nt), __end));
+                21
+            }
+            64 => {
+                // E10 = E10, ".", IDENTIFIER, "(", Comma<Condition>, ")" => ActionFn(128);
+                let __sym5 = __pop_Term_22_29_22(__symbols);
+                let __sym4 = __pop_NtComma_3cCondition_3e(__symbols);
+                let __sym3 = __pop_Term_22_28_22(__symbols);
+                let __sym2 = __pop_NtIDENTIFIER(__symbols);
+                let __sym1 = __pop_Term_22_2e_22(__symbols);
+                let __sym0 = __pop_NtE10(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym5.2.clone();
+                let __nt = super::__action128::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 6);
+                __symbols.push((__start, __Symbol::NtE10(__nt), __end));
+                21
+            }
+            65 => {
+                // E10 = E10, ".", IDENTIFIER => ActionFn(129);
+                let __sym2 = __pop_NtIDENTIFIER(__symbols);
+                let __sym1 = __pop_Term_22_2e_22(__symbols);
+                let __sym0 = __pop_NtE10(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action129::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtE10(__nt), __end));
+                21
+            }
+            66 => {
+                // E10 = E10, ".", r#"-?[0-9]+"# => ActionFn(130);
+                let __sym2 = __pop_Termr_23_22_2d_3f_5b0_2d9_5d_2b_22_23(__symbols);
+                let __sym1 = __pop_Term_22_2e_22(__symbols);
+                let __sym0 = __pop_NtE10(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action130::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtE10(__nt), __end));
+                21
+            }
+            67 => {
+                // E10 = E10, "is", IDENTIFIER => ActionFn(131);
+                let __sym2 = __pop_NtIDENTIFIER(__symbols);
+                let __sym1 = __pop_Term_22is_22(__symbols);
+                let __sym0 = __pop_NtE10(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action131::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtE10(__nt), __end));
+                21
+            }
+            68 => {
+                // E10 = Atom => ActionFn(132);
+                let __sym0 = __pop_NtAtom(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action132::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtE10(__nt), __end));
+                21
+            }
+            69 => {
+                // E10 = "(", Condition, ")" => ActionFn(22);
+                let __sym2 = __pop_Term_22_29_22(__symbols);
+                let __sym1 = __pop_NtCondition(__symbols);
+                let __sym0 = __pop_Term_22_28_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action22::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtE10(__nt), __end));
+                21
+            }
+            70 => {
+                // E2 = ExprHelper<E2, BOP2, E3> => ActionFn(5);
+                let __sym0 = __pop_NtExprHelper_3cE2_2c_20BOP2_2c_20E3_3e(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action5::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtE2(__nt), __end));
+                22
+            }
+            71 => {
+                // E3 = ExprHelper<E3, BOP3, E4> => ActionFn(6);
+                let __sym0 = __pop_NtExprHelper_3cE3_2c_20BOP3_2c_20E4_3e(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action6::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtE3(__nt), __end));
+                23
+            }
+            72 => {
+                // E4 = ExprHelper<E4, BOP4, E5> => ActionFn(7);
+                let __sym0 = __pop_NtExprHelper_3cE4_2c_20BOP4_2c_20E5_3e(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action7::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtE4(__nt), __end));
+                24
+            }
+            73 => {
+                // E5 = ExprHelper<E5, BOP5, E6> => ActionFn(8);
+                let __sym0 = __pop_NtExprHelper_3cE5_2c_20BOP5_2c_20E6_3e(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action8::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtE5(__nt), __end));
+                25
+            }
+            74 => {
+                // E6 = ExprHelper<E6, BOP6, E7> => ActionFn(9);
+                let __sym0 = __pop_NtExprHelper_3cE6_2c_20BOP6_2c_20E7_3e(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action9::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtE6(__nt), __end));
+                26
+            }
+            75 => {
+                // E7 = ExprHelper<E7, BOP7, E8> => ActionFn(10);
+                let __sym0 = __pop_NtExprHelper_3cE7_2c_20BOP7_2c_20E8_3e(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action10::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtE7(__nt), __end));
+                27
+            }
+            76 => {
+                // E8 = ExprHelper<E8, BOP8, E9> => ActionFn(11);
+                let __sym0 = __pop_NtExprHelper_3cE8_2c_20BOP8_2c_20E9_3e(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action11::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtE8(__nt), __end));
+                28
+            }
+            77 => {
+                // E9 = UOP, E9 => ActionFn(125);
+                let __sym1 = __pop_NtE9(__symbols);
+                let __sym0 = __pop_NtUOP(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action125::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtE9(__nt), __end));
+                29
+            }
+            78 => {
+                // E9 = "*", E9 => ActionFn(13);
+                let __sym1 = __pop_NtE9(__symbols);
+                let __sym0 = __pop_Term_22_2a_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action13::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtE9(__nt), __end));
+                29
+            }
+            79 => {
+                // E9 = E10 => ActionFn(14);
+                let __sym0 = __pop_NtE10(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action14::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtE9(__nt), __end));
+                29
+            }
+            80 => {
+                // ExprHelper<E1, BOP1, E2> = E1, BOP1, E2 => ActionFn(98);
+                let __sym2 = __pop_NtE2(__symbols);
+                let __sym1 = __pop_NtBOP1(__symbols);
+                let __sym0 = __pop_NtE1(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action98::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtExprHelper_3cE1_2c_20BOP1_2c_20E2_3e(__nt), __end));
+                30
+            }
+            81 => {
+                // ExprHelper<E1, BOP1, E2> = E2 => ActionFn(99);
+                let __sym0 = __pop_NtE2(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action99::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtExprHelper_3cE1_2c_20BOP1_2c_20E2_3e(__nt), __end));
+                30
+            }
+            82 => {
+                // ExprHelper<E2, BOP2, E3> = E2, BOP2, E3 => ActionFn(96);
+                let __sym2 = __pop_NtE3(__symbols);
+                let __sym1 = __pop_NtBOP2(__symbols);
+                let __sym0 = __pop_NtE2(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action96::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtExprHelper_3cE2_2c_20BOP2_2c_20E3_3e(__nt), __end));
+                31
+            }
+            83 => {
+                // ExprHelper<E2, BOP2, E3> = E3 => ActionFn(97);
+                let __sym0 = __pop_NtE3(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action97::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtExprHelper_3cE2_2c_20BOP2_2c_20E3_3e(__nt), __end));
+                31
+            }
+            84 => {
+                // ExprHelper<E3, BOP3, E4> = E3, BOP3, E4 => ActionFn(94);
+                let __sym2 = __pop_NtE4(__symbols);
+                let __sym1 = __pop_NtBOP3(__symbols);
+                let __sym0 = __pop_NtE3(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action94::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtExprHelper_3cE3_2c_20BOP3_2c_20E4_3e(__nt), __end));
+                32
+            }
+            85 => {
+                // ExprHelper<E3, BOP3, E4> = E4 => ActionFn(95);
+                let __sym0 = __pop_NtE4(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action95::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtExprHelper_3cE3_2c_20BOP3_2c_20E4_3e(__nt), __end));
+                32
+            }
+            86 => {
+                // ExprHelper<E4, BOP4, E5> = E4, BOP4, E5 => ActionFn(92);
+                let __sym2 = __pop_NtE5(__symbols);
+                let __sym1 = __pop_NtBOP4(__symbols);
+                let __sym0 = __pop_NtE4(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action92::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtExprHelper_3cE4_2c_20BOP4_2c_20E5_3e(__nt), __end));
+                33
+            }
+            87 => {
+                // ExprHelper<E4, BOP4, E5> = E5 => ActionFn(93);
+                let __sym0 = __pop_NtE5(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action93::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtExprHelper_3cE4_2c_20BOP4_2c_20E5_3e(__nt), __end));
+                33
+            }
+            88 => {
+                // ExprHelper<E5, BOP5, E6> = E5, BOP5, E6 => ActionFn(90);
+                let __sym2 = __pop_NtE6(__symbols);
+                let __sym1 = __pop_NtBOP5(__symbols);
+                let __sym0 = __pop_NtE5(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action90::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtExprHelper_3cE5_2c_20BOP5_2c_20E6_3e(__nt), __end));
+                34
+            }
+            89 => {
+                // ExprHelper<E5, BOP5, E6> = E6 => ActionFn(91);
+                let __sym0 = __pop_NtE6(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action91::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtExprHelper_3cE5_2c_20BOP5_2c_20E6_3e(__nt), __end));
+                34
+            }
+            90 => {
+                // ExprHelper<E6, BOP6, E7> = E6, BOP6, E7 => ActionFn(88);
+                let __sym2 = __pop_NtE7(__symbols);
+                let __sym1 = __pop_NtBOP6(__symbols);
+                let __sym0 = __pop_NtE6(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action88::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtExprHelper_3cE6_2c_20BOP6_2c_20E7_3e(__nt), __end));
+                35
+            }
+            91 => {
+                // ExprHelper<E6, BOP6, E7> = E7 => ActionFn(89);
+                let __sym0 = __pop_NtE7(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action89::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtExprHelper_3cE6_2c_20BOP6_2c_20E7_3e(__nt), __end));
+                35
+            }
+            92 => {
+                // ExprHelper<E7, BOP7, E8> = E7, BOP7, E8 => ActionFn(86);
+                let __sym2 = __pop_NtE8(__symbols);
+                let __sym1 = __pop_NtBOP7(__symbols);
+                let __sym0 = __pop_NtE7(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action86::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtExprHelper_3cE7_2c_20BOP7_2c_20E8_3e(__nt), __end));
+                36
+            }
+            93 => {
+                // ExprHelper<E7, BOP7, E8> = E8 => ActionFn(87);
+                let __sym0 = __pop_NtE8(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action87::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtExprHelper_3cE7_2c_20BOP7_2c_20E8_3e(__nt), __end));
+                36
+            }
+            94 => {
+                // ExprHelper<E8, BOP8, E9> = E8, BOP8, E9 => ActionFn(84);
+                let __sym2 = __pop_NtE9(__symbols);
+                let __sym1 = __pop_NtBOP8(__symbols);
+                let __sym0 = __pop_NtE8(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action84::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtExprHelper_3cE8_2c_20BOP8_2c_20E9_3e(__nt), __end));
+                37
+            }
+            95 => {
+                // ExprHelper<E8, BOP8, E9> = E9 => ActionFn(85);
+                let __sym0 = __pop_NtE9(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action85::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtExprHelper_3cE8_2c_20BOP8_2c_20E9_3e(__nt), __end));
+                37
+            }
+            96 => {
+                // IDENTIFIER = r#"[a-zA-Z][a-zA-Z0-9_]*"# => ActionFn(64);
+                let __sym0 = __pop_Termr_23_22_5ba_2dzA_2dZ_5d_5ba_2dzA_2dZ0_2d9___5d_2a_22_23(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action64::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtIDENTIFIER(__nt), __end));
+                38
+            }
+            97 => {
+                // TYPE = "bool" => ActionFn(65);
+                let __sym0 = __pop_Term_22bool_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action65::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtTYPE(__nt), __end));
+                39
+            }
+            98 => {
+                // TYPE = "i8" => ActionFn(66);
+                let __sym0 = __pop_Term_22i8_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action66::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtTYPE(__nt), __end));
+                39
+            }
+            99 => {
+                // TYPE = "i16" => ActionFn(67);
+                let __sym0 = __pop_Term_22i16_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action67::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtTYPE(__nt), __end));
+                39
+            }
+            100 => {
+                // TYPE = "i32" => ActionFn(68);
+                let __sym0 = __pop_Term_22i32_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action68::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtTYPE(__nt), __end));
+                39
+            }
+            101 => {
+                // TYPE = "i64" => ActionFn(69);
+                let __sym0 = __pop_Term_22i64_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action69::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtTYPE(__nt), __end));
+                39
+            }
+            102 => {
+                // TYPE = "i128" => ActionFn(70);
+                let __sym0 = __pop_Term_22i128_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action70::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtTYPE(__nt), __end));
+                39
+            }
+            103 => {
+                // TYPE = "u8" => ActionFn(71);
+                let __sym0 = __pop_Term_22u8_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action71::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtTYPE(__nt), __end));
+                39
+            }
+            104 => {
+                // TYPE = "u16" => ActionFn(72);
+                let __sym0 = __pop_Term_22u16_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action72::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtTYPE(__nt), __end));
+                39
+            }
+            105 => {
+                // TYPE = "u32" => ActionFn(73);
+                let __sym0 = __pop_Term_22u32_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action73::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtTYPE(__nt), __end));
+                39
+            }
+            106 => {
+                // TYPE = "u64" => ActionFn(74);
+                let __sym0 = __pop_Term_22u64_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action74::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtTYPE(__nt), __end));
+                39
+            }
+            107 => {
+                // TYPE = "u128" => ActionFn(75);
+                let __sym0 = __pop_Term_22u128_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action75::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtTYPE(__nt), __end));
+                39
+            }
+            108 => {
+                // TYPE = "usize" => ActionFn(76);
+                let __sym0 = __pop_Term_22usize_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action76::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtTYPE(__nt), __end));
+                39
+            }
+            109 => {
+                // TYPE = "isize" => ActionFn(77);
+                let __sym0 = __pop_Term_22isize_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action77::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtTYPE(__nt), __end));
+                39
+            }
+            110 => {
+                // TYPE = "f32" => ActionFn(78);
+                let __sym0 = __pop_Term_22f32_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action78::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtTYPE(__nt), __end));
+                39
+            }
+            111 => {
+                // TYPE = "f64" => ActionFn(79);
+                let __sym0 = __pop_Term_22f64_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action79::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtTYPE(__nt), __end));
+                39
+            }
+            112 => {
+                // TYPE = "char" => ActionFn(80);
+                let __sym0 = __pop_Term_22char_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action80::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtTYPE(__nt), __end));
+                39
+            }
+            113 => {
+                // TYPE = "" => ActionFn(81);
+                let __sym0 = __pop_Term_22_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action81::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtTYPE(__nt), __end));
+                39
+            }
+            114 => {
+                // UOP = "-" => ActionFn(41);
+                let __sym0 = __pop_Term_22_2d_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action41::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtUOP(__nt), __end));
+                40
+            }
+            115 => {
+                // UOP = "!" => ActionFn(42);
+                let __sym0 = __pop_Term_22_21_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action42::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtUOP(__nt), __end));
+                40
+            }
+            116 => {
+                // __Condition = Condition => ActionFn(0);
+                let __sym0 = __pop_NtCondition(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action0::<>(input, __sym0);
+                return Some(Ok(__nt));
+            }
+            _ => panic!("invalid action code {}", __action)
+        };
+        let __state = *__states.last().unwrap() as usize;
+        let __next_state = __GOTO[__state * 42 + __nonterminal] - 1;
+        __states.push(__next_state);
+        None
+    }
+    fn __pop_Term_22_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_21_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_21_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_21_3d_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_21_3d_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_25_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_25_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_26_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_26_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_26_26_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_26_26_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_28_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_28_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_29_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_29_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_2a_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_2a_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_2b_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_2b_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_2c_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_2c_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_2d_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_2d_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_2e_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_2e_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_2e_2e_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_2e_2e_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_2f_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_2f_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_3a_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_3a_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_3a_3a_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_3a_3a_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_3c_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_3c_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_3c_3c_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_3c_3c_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_3c_3d_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_3c_3d_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_3c_3d_3e_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_3c_3d_3e_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_3d_3d_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_3d_3d_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_3d_3e_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_3d_3e_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_3e_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_3e_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_3e_3d_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_3e_3d_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_3e_3e_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_3e_3e_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22XOR_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22XOR_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_5b_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_5b_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_5d_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_5d_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_5e_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_5e_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22bool_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22bool_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22char_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22char_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22exists_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22exists_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22f32_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22f32_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22f64_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22f64_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22false_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22false_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22forall_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22forall_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22i128_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22i128_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22i16_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22i16_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22i32_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22i32_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22i64_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22i64_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22i8_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22i8_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22in_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22in_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22is_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22is_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22isize_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22isize_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22true_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22true_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22u128_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22u128_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22u16_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22u16_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22u32_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22u32_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22u64_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22u64_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22u8_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22u8_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22usize_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22usize_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_7c_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_7c_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_7c_7c_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_7c_7c_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Termr_23_22_27_28_5b_5e_27_5c_5c_5c_5c_5d_7c_5c_5c_5c_5c_5bnrt0_27_5c_5c_5c_5c_5d_29_27_22_23<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Termr_23_22_27_28_5b_5e_27_5c_5c_5c_5c_5d_7c_5c_5c_5c_5c_5bnrt0_27_5c_5c_5c_5c_5d_29_27_22_23(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Termr_23_22_2d_3f_5b0_2d9_5d_2b_22_23<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Termr_23_22_2d_3f_5b0_2d9_5d_2b_22_23(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Termr_23_22_2d_3f_5b0_2d9_5d_2b_28_5c_5c_2e_5b0_2d9_5d_2b_28_5beE_5d_5b_2d_2b_5d_3f_5b0_2d9_5d_2b_29_3f_7c_5beE_5d_5b_2d_2b_5d_3f_5b0_2d9_5d_2b_29_22_23<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Termr_23_22_2d_3f_5b0_2d9_5d_2b_28_5c_5c_2e_5b0_2d9_5d_2b_28_5beE_5d_5b_2d_2b_5d_3f_5b0_2d9_5d_2b_29_3f_7c_5beE_5d_5b_2d_2b_5d_3f_5b0_2d9_5d_2b_29_22_23(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Termr_23_22_5ba_2dzA_2dZ_5d_5ba_2dzA_2dZ0_2d9___5d_2a_22_23<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Termr_23_22_5ba_2dzA_2dZ_5d_5ba_2dzA_2dZ0_2d9___5d_2a_22_23(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_28_22_3a_22_20_3cTYPE_3e_29<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Types, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_28_22_3a_22_20_3cTYPE_3e_29(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_28_22_3a_22_20_3cTYPE_3e_29_3f<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::option::Option<Types>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_28_22_3a_22_20_3cTYPE_3e_29_3f(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_28_3cCondition_3e_20_22_2c_22_29<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Expression, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_28_3cCondition_3e_20_22_2c_22_29(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_28_3cCondition_3e_20_22_2c_22_29_2a<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::vec::Vec<Expression>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_28_3cCondition_3e_20_22_2c_22_29_2a(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_28_3cCondition_3e_20_22_2c_22_29_2b<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::vec::Vec<Expression>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_28_3cCondition_3e_20_22_2c_22_29_2b(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_40L<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, usize, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_40L(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_40R<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, usize, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_40R(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtAtom<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Expression, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtAtom(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtBOP1<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, BinaryOperator, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtBOP1(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtBOP2<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, BinaryOperator, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtBOP2(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtBOP3<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, BinaryOperator, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtBOP3(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtBOP4<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, BinaryOperator, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtBOP4(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtBOP5<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, BinaryOperator, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtBOP5(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtBOP6<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, BinaryOperator, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtBOP6(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtBOP7<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, BinaryOperator, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtBOP7(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtBOP8<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, BinaryOperator, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtBOP8(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtBound<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Expression, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtBound(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtComma_3cCondition_3e<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Vec<Expression>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtComma_3cCondition_3e(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtCondition<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Expression, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtCondition(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtCondition_3f<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::option::Option<Expression>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtCondition_3f(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtE1<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Expression, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtE1(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtE10<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Expression, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtE10(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtE2<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Expression, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtE2(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtE3<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Expression, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtE3(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtE4<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Expression, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtE4(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtE5<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Expression, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtE5(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtE6<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Expression, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtE6(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtE7<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Expression, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtE7(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtE8<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Expression, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtE8(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtE9<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Expression, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtE9(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtExprHelper_3cE1_2c_20BOP1_2c_20E2_3e<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Expression, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtExprHelper_3cE1_2c_20BOP1_2c_20E2_3e(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtExprHelper_3cE2_2c_20BOP2_2c_20E3_3e<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Expression, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtExprHelper_3cE2_2c_20BOP2_2c_20E3_3e(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtExprHelper_3cE3_2c_20BOP3_2c_20E4_3e<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Expression, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtExprHelper_3cE3_2c_20BOP3_2c_20E4_3e(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtExprHelper_3cE4_2c_20BOP4_2c_20E5_3e<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Expression, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtExprHelper_3cE4_2c_20BOP4_2c_20E5_3e(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtExprHelper_3cE5_2c_20BOP5_2c_20E6_3e<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Expression, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtExprHelper_3cE5_2c_20BOP5_2c_20E6_3e(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtExprHelper_3cE6_2c_20BOP6_2c_20E7_3e<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Expression, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtExprHelper_3cE6_2c_20BOP6_2c_20E7_3e(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtExprHelper_3cE7_2c_20BOP7_2c_20E8_3e<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Expression, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtExprHelper_3cE7_2c_20BOP7_2c_20E8_3e(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtExprHelper_3cE8_2c_20BOP8_2c_20E9_3e<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Expression, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtExprHelper_3cE8_2c_20BOP8_2c_20E9_3e(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtIDENTIFIER<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, String, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtIDENTIFIER(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtTYPE<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Types, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtTYPE(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtUOP<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, UnaryOperator, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtUOP(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt____Condition<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Expression, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt____Condition(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+}
+pub use self::__parse__Condition::parse_Condition;
+mod __intern_token {
+    #![allow(unused_imports)]
+    use std::rc::Rc;
+    use ast::*;
+    use spans;
+    extern crate lalrpop_util as __lalrpop_util;
+    extern crate regex as __regex;
+    pub struct __Matcher<'input> {
+        text: &'input str,
+        consumed: usize,
+        regex_set: __regex::RegexSet,
+        regex_vec: Vec<__regex::Regex>,
+    }
+
+    impl<'input> __Matcher<'input> {
+        pub fn new(s: &'input str) -> __Matcher<'input> {
+            let __strs: &[&str] = &[
+                "^(?u:')((?u:[\0-\\&\\(-\\[\\]-\u{10ffff}])|(?u:\\\\)(?u:['-'0-0\\\\-\\\\n-nr-rt-t]))(?u:')",
+                "^(?u:\\-)?(?u:[0-9])+",
+                "^(?u:\\-)?(?u:[0-9])+((?u:\\.)(?u:[0-9])+((?u:[E-Ee-e])(?u:[-\\+-\\+])?(?u:[0-9])+)?|(?u:[E-Ee-e])(?u:[-\\+-\\+])?(?u:[0-9])+)",
+                "^(?u:[A-Za-z])(?u:[0-9A-Z_-_a-z])*",
+                "^",
+                "^(?u:!)",
+                "^(?u:!=)",
+                "^(?u:%)",
+                "^(?u:\\&)",
+                "^(?u:\\&\\&)",
+                "^(?u:\\()",
+                "^(?u:\\))",
+                "^(?u:\\*)",
+                "^(?u:\\+)",
+                "^(?u:,)",
+                "^(?u:\\-)",
+                "^(?u:\\.)",
+                "^(?u:\\.\\.)",
+                "^(?u:/)",
+                "^(?u::)",
+                "^(?u:::)",
+                "^(?u:<)",
+                "^(?u:<<)",
+                "^(?u:<=)",
+                "^(?u:<=>)",
+                "^(?u:==)",
+                "^(?u:=>)",
+                "^(?u:>)",
+                "^(?u:>=)",
+                "^(?u:>>)",
+                "^(?u:XOR)",
+                "^(?u:\\[)",
+                "^(?u:\\])",
+                "^(?u:\\^)",
+                "^(?u:bool)",
+                "^(?u:char)",
+                "^(?u:exists)",
+                "^(?u:f32)",
+                "^(?u:f64)",
+                "^(?u:false)",
+                "^(?u:forall)",
+                "^(?u:i128)",
+                "^(?u:i16)",
+                "^(?u:i32)",
+                "^(?u:i64)",
+                "^(?u:i8)",
+                "^(?u:in)",
+                "^(?u:is)",
+                "^(?u:isize)",
+                "^(?u:true)",
+                "^(?u:u128)",
+                "^(?u:u16)",
+                "^(?u:u32)",
+                "^(?u:u64)",
+                "^(?u:u8)",
+                "^(?u:usize)",
+                "^(?u:\\|)",
+                "^(?u:\\|\\|)",
+            ];
+            let __regex_set = __regex::RegexSet::new(__strs).unwrap();
+            let __regex_vec = vec![
+                __regex::Regex::new("^(?u:')((?u:[\0-\\&\\(-\\[\\]-\u{10ffff}])|(?u:\\\\)(?u:['-'0-0\\\\-\\\\n-nr-rt-t]))(?u:')").unwrap(),
+                __regex::Regex::new("^(?u:\\-)?(?u:[0-9])+").unwrap(),
+                __regex::Regex::new("^(?u:\\-)?(?u:[0-9])+((?u:\\.)(?u:[0-9])+((?u:[E-Ee-e])(?u:[-\\+-\\+])?(?u:[0-9])+)?|(?u:[E-Ee-e])(?u:[-\\+-\\+])?(?u:[0-9])+)").unwrap(),
+                __regex::Regex::new("^(?u:[A-Za-z])(?u:[0-9A-Z_-_a-z])*").unwrap(),
+                __regex::Regex::new("^").unwrap(),
+                __regex::Regex::new("^(?u:!)").unwrap(),
+                __regex::Regex::new("^(?u:!=)").unwrap(),
+                __regex::Regex::new("^(?u:%)").unwrap(),
+                __regex::Regex::new("^(?u:\\&)").unwrap(),
+                __regex::Regex::new("^(?u:\\&\\&)").unwrap(),
+                __regex::Regex::new("^(?u:\\()").unwrap(),
+                __regex::Regex::new("^(?u:\\))").unwrap(),
+                __regex::Regex::new("^(?u:\\*)").unwrap(),
+                __regex::Regex::new("^(?u:\\+)").unwrap(),
+                __regex::Regex::new("^(?u:,)").unwrap(),
+                __regex::Regex::new("^(?u:\\-)").unwrap(),
+                __regex::Regex::new("^(?u:\\.)").unwrap(),
+                __regex::Regex::new("^(?u:\\.\\.)").unwrap(),
+                __regex::Regex::new("^(?u:/)").unwrap(),
+                __regex::Regex::new("^(?u::)").unwrap(),
+                __regex::Regex::new("^(?u:::)").unwrap(),
+                __regex::Regex::new("^(?u:<)").unwrap(),
+                __regex::Regex::new("^(?u:<<)").unwrap(),
+                __regex::Regex::new("^(?u:<=)").unwrap(),
+                __regex::Regex::new("^(?u:<=>)").unwrap(),
+                __regex::Regex::new("^(?u:==)").unwrap(),
+                __regex::Regex::new("^(?u:=>)").unwrap(),
+                __regex::Regex::new("^(?u:>)").unwrap(),
+                __regex::Regex::new("^(?u:>=)").unwrap(),
+                __regex::Regex::new("^(?u:>>)").unwrap(),
+                __regex::Regex::new("^(?u:XOR)").unwrap(),
+                __regex::Regex::new("^(?u:\\[)").unwrap(),
+                __regex::Regex::new("^(?u:\\])").unwrap(),
+                __regex::Regex::new("^(?u:\\^)").unwrap(),
+                __regex::Regex::new("^(?u:bool)").unwrap(),
+                __regex::Regex::new("^(?u:char)").unwrap(),
+                __regex::Regex::new("^(?u:exists)").unwrap(),
+                __regex::Regex::new("^(?u:f32)").unwrap(),
+                __regex::Regex::new("^(?u:f64)").unwrap(),
+                __regex::Regex::new("^(?u:false)").unwrap(),
+                __regex::Regex::new("^(?u:forall)").unwrap(),
+                __regex::Regex::new("^(?u:i128)").unwrap(),
+                __regex::Regex::new("^(?u:i16)").unwrap(),
+                __regex::Regex::new("^(?u:i32)").unwrap(),
+                __regex::Regex::new("^(?u:i64)").unwrap(),
+                __regex::Regex::new("^(?u:i8)").unwrap(),
+                __regex::Regex::new("^(?u:in)").unwrap(),
+                __regex::Regex::new("^(?u:is)").unwrap(),
+                __regex::Regex::new("^(?u:isize)").unwrap(),
+                __regex::Regex::new("^(?u:true)").unwrap(),
+                __regex::Regex::new("^(?u:u128)").unwrap(),
+                __regex::Regex::new("^(?u:u16)").unwrap(),
+                __regex::Regex::new("^(?u:u32)").unwrap(),
+                __regex::Regex::new("^(?u:u64)").unwrap(),
+                __regex::Regex::new("^(?u:u8)").unwrap(),
+                __regex::Regex::new("^(?u:usize)").unwrap(),
+                __regex::Regex::new("^(?u:\\|)").unwrap(),
+                __regex::Regex::new("^(?u:\\|\\|)").unwrap(),
+            ];
+            __Matcher {
+                text: s,
+                consumed: 0,
+                regex_set: __regex_set,
+                regex_vec: __regex_vec,
+            }
+        }
+    }
+
+    impl<'input> Iterator for __Matcher<'input> {
+        type Item = Result<(usize, (usize, &'input str), usize), __lalrpop_util::ParseError<usize,(usize, &'input str),()>>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            let __text = self.text.trim_left();
+            let __whitespace = self.text.len() - __text.len();
+            let __start_offset = self.consumed + __whitespace;
+            if __text.is_empty() {
+                self.text = __text;
+                self.consumed = __start_offset;
+                None
+            } else {
+                let __matches = self.regex_set.matches(__text);
+                if !__matches.matched_any() {
+                    Some(Err(__lalrpop_util::ParseError::InvalidToken {
+                        location: __start_offset,
+                    }))
+                } else {
+                    let mut __longest_match = 0;
+                    let mut __index = 0;
+                    for __i in 0 .. 58 {
+                        if __matches.matched(__i) {
+                            let __match = self.regex_vec[__i].find(__text).unwrap();
+                            let __len = __match.end();
+                            if __len >= __longest_match {
+                                __longest_match = __len;
+                                __index = __i;
+                            }
+                        }
+                    }
+                    let __result = &__text[..__longest_match];
+                    let __remaining = &__text[__longest_match..];
+                    let __end_offset = __start_offset + __longest_match;
+                    self.text = __remaining;
+                    self.consumed = __end_offset;
+                    Some(Ok((__start_offset, (__index, __result), __end_offset)))
+                }
+            }
+        }
+    }
+}
+
+#[allow(unused_variables)]
+fn __action0<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action1<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, v, _): (usize, String, usize),
+    (_, t, _): (usize, ::std::option::Option<Types>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, lo, _): (usize, Expression, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, hi, _): (usize, Expression, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, body, _): (usize, Expression, usize),
+) -> Expression
+{
+    {
+        let h = spans::end(&body);
+        spans::record(Some(l), h, bounded_forall(v, t, lo, hi, body))
+    }
+}
+
+#[allow(unused_variables)]
+fn __action2<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, v, _): (usize, String, usize),
+    (_, t, _): (usize, ::std::option::Option<Types>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, lo, _): (usize, Expression, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, hi, _): (usize, Expression, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, body, _): (usize, Expression, usize),
+) -> Expression
+{
+    {
+        let h = spans::end(&body);
+        let body = Expression::UnaryExpression(UnaryOperator::Not, Rc::new(body));
+        let exists = Expression::UnaryExpression(UnaryOperator::Not, Rc::new(bounded_forall(v, t, lo, hi, body)));
+        spans::record(Some(l), h, exists)
+    }
+}
+
+#[allow(unused_variables)]
+fn __action3<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action4<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action5<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action6<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action7<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action8<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action9<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action10<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action11<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action12<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, op, _): (usize, UnaryOperator, usize),
+    (_, e, _): (usize, Expression, usize),
+) -> Expression
+{
+    {
+        let h = spans::end(&e);
+        spans::record(Some(l), h, Expression::UnaryExpression(op, Rc::new(e)))
+    }
+}
+
+#[allow(unused_variables)]
+fn __action13<
+    'input,
+>(
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action14<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action15<
+    'input,
+>(
+    input: &'input str,
+    (_, a, _): (usize, Expression, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, i, _): (usize, Expression, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, h, _): (usize, usize, usize),
+) -> Expression
+{
+    {
+        let l = spans::start(&a);
+        spans::record(l, Some(h), Expression::Select(Rc::new(a), Rc::new(i)))
+    }
+}
+
+#[allow(unused_variables)]
+fn __action16<
+    'input,
+>(
+    input: &'input str,
+    (_, a, _): (usize, Expression, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, lo, _): (usize, Expression, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, hi, _): (usize, Expression, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, h, _): (usize, usize, usize),
+) -> Expression
+{
+    {
+        let l = spans::start(&a);
+        let subslice = Expression::FunctionCall("subslice".to_string(), vec![a, lo, hi], Types::Unknown);
+        spans::record(l, Some(h), subslice)
+    }
+}
+
+#[allow(unused_variables)]
+fn __action17<
+    'input,
+>(
+    input: &'input str,
+    (_, r, _): (usize, Expression, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, m, _): (usize, String, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, args, _): (usize, Vec<Expression>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, h, _): (usize, usize, usize),
+) -> Expression
+{
+    {
+        let l = spans::start(&r);
+        let mut all = vec![r];
+        all.extend(args);
+        spans::record(l, Some(h), Expression::FunctionCall(m, all, Types::Unknown))
+    }
+}
+
+#[allow(unused_variables)]
+fn __action18<
+    'input,
+>(
+    input: &'input str,
+    (_, r, _): (usize, Expression, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, f, _): (usize, String, usize),
+    (_, h, _): (usize, usize, usize),
+) -> Expression
+{
+    {
+        let l = spans::start(&r);
+        spans::record(l, Some(h), field(r, f))
+    }
+}
+
+#[allow(unused_variables)]
+fn __action19<
+    'input,
+>(
+    input: &'input str,
+    (_, r, _): (usize, Expression, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, i, _): (usize, &'input str, usize),
+    (_, h, _): (usize, usize, usize),
+) -> Expression
+{
+    {
+        let l = spans::start(&r);
+        spans::record(l, Some(h), field(r, i.to_string()))
+    }
+}
+
+#[allow(unused_variables)]
+fn __action20<
+    'input,
+>(
+    input: &'input str,
+    (_, r, _): (usize, Expression, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, v, _): (usize, String, usize),
+    (_, h, _): (usize, usize, usize),
+) -> Expression
+{
+    {
+        let l = spans::start(&r);
+        spans::record(l,
+                      Some(h),
+                      Expression::FunctionCall("is".to_string(),
+                                               vec![r, Expression::VariableMapping(v, Types::Unknown)],
+                                               Types::Unknown))
+    }
+}
+
+#[allow(unused_variables)]
+fn __action21<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, a, _): (usize, Expression, usize),
+    (_, h, _): (usize, usize, usize),
+) -> Expression
+{
+    spans::record(Some(l), Some(h), a)
+}
+
+#[allow(unused_variables)]
+fn __action22<
+    'input,
+>(
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, __0, _): (usize, Expression, usize),
+    (_, _, _): (usize, &'input str, usize),
+) -> Expression
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action23<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> Expression
+{
+    Expression::BooleanLiteral(true)
+}
+
+#[allow(unused_variables)]
+fn __action24<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> Expression
+{
+    Expression::BooleanLiteral(false)
+}
+
+#[allow(unused_variables)]
+fn __action25<
+    'input,
+>(
+    input: &'input str,
+    (_, n, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, t, _): (usize, Types, usize),
+) -> Expression
+{
+    literal(n, t)
+}
+
+#[allow(unused_variables)]
+fn __action26<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> Expression
+{
+    literal(__0, Types::Unknown)
+}
+
+#[allow(unused_variables)]
+fn __action27<
+    'input,
+>(
+    input: &'input str,
+    (_, n, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, t, _): (usize, Types, usize),
+) -> Expression
+{
+    {
+        float_literal(n, t)
+    }
+}
+
+#[allow(unused_variables)]
+fn __action28<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> Expression
+{
+    float_literal(__0, Types::Unknown)
+}
+
+#[allow(unused_variables)]
+fn __action29<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> Expression
+{
+    char_literal(__0)
+}
+
+#[allow(unused_variables)]
+fn __action30<
+    'input,
+>(
+    input: &'input str,
+    (_, t, _): (usize, Types, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, c, _): (usize, String, usize),
+) -> Expression
+{
+    type_constant(t, &c)
+}
+
+#[allow(unused_variables)]
+fn __action31<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, String, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, __1, _): (usize, Vec<Expression>, usize),
+    (_, _, _): (usize, &'input str, usize),
+) -> Expression
+{
+    Expression::FunctionCall(__0, __1, Types::Unknown)
+}
+
+#[allow(unused_variables)]
+fn __action32<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, String, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, __1, _): (usize, Types, usize),
+) -> Expression
+{
+    Expression::VariableMapping(__0, __1)
+}
+
+#[allow(unused_variables)]
+fn __action33<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, String, usize),
+) -> Expression
+{
+    Expression::VariableMapping(__0, Types::Unknown)
+}
+
+#[allow(unused_variables)]
+fn __action34<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> Expression
+{
+    literal(__0, Types::Unknown)
+}
+
+#[allow(unused_variables)]
+fn __action35<
+    'input,
+>(
+    input: &'input str,
+    (_, t, _): (usize, Types, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, c, _): (usize, String, usize),
+) -> Expression
+{
+    type_constant(t, &c)
+}
+
+#[allow(unused_variables)]
+fn __action36<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, String, usize),
+) -> Expression
+{
+    Expression::VariableMapping(__0, Types::Unknown)
+}
+
+#[allow(unused_variables)]
+fn __action37<
+    'input,
+>(
+    input: &'input str,
+    (_, r, _): (usize, Expression, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, m, _): (usize, String, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, args, _): (usize, Vec<Expression>, usize),
+    (_, _, _): (usize, &'input str, usize),
+) -> Expression
+{
+    {
+        let mut all = vec![r];
+        all.extend(args);
+        Expression::FunctionCall(m, all, Types::Unknown)
+    }
+}
+
+#[allow(unused_variables)]
+fn __action38<
+    'input,
+>(
+    input: &'input str,
+    (_, r, _): (usize, Expression, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, f, _): (usize, String, usize),
+) -> Expression
+{
+    field(r, f)
+}
+
+#[allow(unused_variables)]
+fn __action39<
+    'input,
+>(
+    input: &'input str,
+    (_, r, _): (usize, Expression, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, i, _): (usize, &'input str, usize),
+) -> Expression
+{
+    field(r, i.to_string())
+}
+
+#[allow(unused_variables)]
+fn __action40<
+    'input,
+>(
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, __0, _): (usize, Expression, usize),
+    (_, _, _): (usize, &'input str, usize),
+) -> Expression
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action41<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> UnaryOperator
+{
+    UnaryOperator::Negation
+}
+
+#[allow(unused_variables)]
+fn __action42<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> UnaryOperator
+{
+    UnaryOperator::Not
+}
+
+#[allow(unused_variables)]
+fn __action43<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> BinaryOperator
+{
+    BinaryOperator::And
+}
+
+#[allow(unused_variables)]
+fn __action44<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> BinaryOperator
+{
+    BinaryOperator::Or
+}
+
+#[allow(unused_variables)]
+fn __action45<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> BinaryOperator
+{
+    BinaryOperator::Xor
+}
+
+#[allow(unused_variables)]
+fn __action46<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> BinaryOperator
+{
+    BinaryOperator::Implication
+}
+
+#[allow(unused_variables)]
+fn __action47<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> BinaryOperator
+{
+    BinaryOperator::BiImplication
+}
+
+#[allow(unused_variables)]
+fn __action48<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> BinaryOperator
+{
+    BinaryOperator::LessThan
+}
+
+#[allow(unused_variables)]
+fn __action49<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> BinaryOperator
+{
+    BinaryOperator::LessThanOrEqual
+}
+
+#[allow(unused_variables)]
+fn __action50<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> BinaryOperator
+{
+    BinaryOperator::GreaterThan
+}
+
+#[allow(unused_variables)]
+fn __action51<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> BinaryOperator
+{
+    BinaryOperator::GreaterThanOrEqual
+}
+
+#[allow(unused_variables)]
+fn __action52<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> BinaryOperator
+{
+    BinaryOperator::Equal
+}
+
+#[allow(unused_variables)]
+fn __action53<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> BinaryOperator
+{
+    BinaryOperator::NotEqual
+}
+
+#[allow(unused_variables)]
+fn __action54<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> BinaryOperator
+{
+    BinaryOperator::BitwiseOr
+}
+
+#[allow(unused_variables)]
+fn __action55<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> BinaryOperator
+{
+    BinaryOperator::BitwiseXor
+}
+
+#[allow(unused_variables)]
+fn __action56<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> BinaryOperator
+{
+    BinaryOperator::BitwiseAnd
+}
+
+#[allow(unused_variables)]
+fn __action57<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> BinaryOperator
+{
+    BinaryOperator::BitwiseLeftShift
+}
+
+#[allow(unused_variables)]
+fn __action58<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> BinaryOperator
+{
+    BinaryOperator::BitwiseRightShift
+}
+
+#[allow(unused_variables)]
+fn __action59<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> BinaryOperator
+{
+    BinaryOperator::Addition
+}
+
+#[allow(unused_variables)]
+fn __action60<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> BinaryOperator
+{
+    BinaryOperator::Subtraction
+}
+
+#[allow(unused_variables)]
+fn __action61<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> BinaryOperator
+{
+    BinaryOperator::Multiplication
+}
+
+#[allow(unused_variables)]
+fn __action62<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> BinaryOperator
+{
+    BinaryOperator::Division
+}
+
+#[allow(unused_variables)]
+fn __action63<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> BinaryOperator
+{
+    BinaryOperator::Modulo
+}
+
+#[allow(unused_variables)]
+fn __action64<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> String
+{
+    __0.to_string()
+}
+
+#[allow(unused_variables)]
+fn __action65<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> Types
+{
+    Types::Bool
+}
+
+#[allow(unused_variables)]
+fn __action66<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> Types
+{
+    Types::I8
+}
+
+#[allow(unused_variables)]
+fn __action67<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> Types
+{
+    Types::I16
+}
+
+#[allow(unused_variables)]
+fn __action68<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> Types
+{
+    Types::I32
+}
+
+#[allow(unused_variables)]
+fn __action69<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> Types
+{
+    Types::I64
+}
+
+#[allow(unused_variables)]
+fn __action70<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> Types
+{
+    Types::I128
+}
+
+#[allow(unused_variables)]
+fn __action71<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> Types
+{
+    Types::U8
+}
+
+#[allow(unused_variables)]
+fn __action72<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> Types
+{
+    Types::U16
+}
+
+#[allow(unused_variables)]
+fn __action73<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> Types
+{
+    Types::U32
+}
+
+#[allow(unused_variables)]
+fn __action74<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> Types
+{
+    Types::U64
+}
+
+#[allow(unused_variables)]
+fn __action75<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> Types
+{
+    Types::U128
+}
+
+#[allow(unused_variables)]
+fn __action76<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> Types
+{
+    Types::Usize
+}
+
+#[allow(unused_variables)]
+fn __action77<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> Types
+{
+    Types::Isize
+}
+
+#[allow(unused_variables)]
+fn __action78<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> Types
+{
+    Types::F32
+}
+
+#[allow(unused_variables)]
+fn __action79<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> Types
+{
+    Types::F64
+}
+
+#[allow(unused_variables)]
+fn __action80<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> Types
+{
+    Types::U32
+}
+
+#[allow(unused_variables)]
+fn __action81<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> Types
+{
+    Types::Unknown
+}
+
+#[allow(unused_variables)]
+fn __action82<
+    'input,
+>(
+    input: &'input str,
+    (_, v, _): (usize, ::std::vec::Vec<Expression>, usize),
+    (_, e, _): (usize, ::std::option::Option<Expression>, usize),
+) -> Vec<Expression>
+{
+    match e {
+        None => v,
+        Some(e) => {
+            let mut v = v;
+            v.push(e);
+            v
+        }
+    }
+}
+
+#[allow(unused_variables)]
+fn __action83<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> usize
+{
+    __lookbehind.clone()
+}
+
+#[allow(unused_variables)]
+fn __action84<
+    'input,
+>(
+    input: &'input str,
+    (_, left, _): (usize, Expression, usize),
+    (_, op, _): (usize, BinaryOperator, usize),
+    (_, right, _): (usize, Expression, usize),
+) -> Expression
+{
+    {
+        let (l, h) = (spans::start(&left), spans::end(&right));
+        spans::record(l, h, Expression::BinaryExpression(Rc::new(left), op, Rc::new(right)))
+    }
+}
+
+#[allow(unused_variables)]
+fn __action85<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action86<
+    'input,
+>(
+    input: &'input str,
+    (_, left, _): (usize, Expression, usize),
+    (_, op, _): (usize, BinaryOperator, usize),
+    (_, right, _): (usize, Expression, usize),
+) -> Expression
+{
+    {
+        let (l, h) = (spans::start(&left), spans::end(&right));
+        spans::record(l, h, Expression::BinaryExpression(Rc::new(left), op, Rc::new(right)))
+    }
+}
+
+#[allow(unused_variables)]
+fn __action87<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action88<
+    'input,
+>(
+    input: &'input str,
+    (_, left, _): (usize, Expression, usize),
+    (_, op, _): (usize, BinaryOperator, usize),
+    (_, right, _): (usize, Expression, usize),
+) -> Expression
+{
+    {
+        let (l, h) = (spans::start(&left), spans::end(&right));
+        spans::record(l, h, Expression::BinaryExpression(Rc::new(left), op, Rc::new(right)))
+    }
+}
+
+#[allow(unused_variables)]
+fn __action89<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action90<
+    'input,
+>(
+    input: &'input str,
+    (_, left, _): (usize, Expression, usize),
+    (_, op, _): (usize, BinaryOperator, usize),
+    (_, right, _): (usize, Expression, usize),
+) -> Expression
+{
+    {
+        let (l, h) = (spans::start(&left), spans::end(&right));
+        spans::record(l, h, Expression::BinaryExpression(Rc::new(left), op, Rc::new(right)))
+    }
+}
+
+#[allow(unused_variables)]
+fn __action91<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action92<
+    'input,
+>(
+    input: &'input str,
+    (_, left, _): (usize, Expression, usize),
+    (_, op, _): (usize, BinaryOperator, usize),
+    (_, right, _): (usize, Expression, usize),
+) -> Expression
+{
+    {
+        let (l, h) = (spans::start(&left), spans::end(&right));
+        spans::record(l, h, Expression::BinaryExpression(Rc::new(left), op, Rc::new(right)))
+    }
+}
+
+#[allow(unused_variables)]
+fn __action93<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action94<
+    'input,
+>(
+    input: &'input str,
+    (_, left, _): (usize, Expression, usize),
+    (_, op, _): (usize, BinaryOperator, usize),
+    (_, right, _): (usize, Expression, usize),
+) -> Expression
+{
+    {
+        let (l, h) = (spans::start(&left), spans::end(&right));
+        spans::record(l, h, Expression::BinaryExpression(Rc::new(left), op, Rc::new(right)))
+    }
+}
+
+#[allow(unused_variables)]
+fn __action95<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action96<
+    'input,
+>(
+    input: &'input str,
+    (_, left, _): (usize, Expression, usize),
+    (_, op, _): (usize, BinaryOperator, usize),
+    (_, right, _): (usize, Expression, usize),
+) -> Expression
+{
+    {
+        let (l, h) = (spans::start(&left), spans::end(&right));
+        spans::record(l, h, Expression::BinaryExpression(Rc::new(left), op, Rc::new(right)))
+    }
+}
+
+#[allow(unused_variables)]
+fn __action97<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action98<
+    'input,
+>(
+    input: &'input str,
+    (_, left, _): (usize, Expression, usize),
+    (_, op, _): (usize, BinaryOperator, usize),
+    (_, right, _): (usize, Expression, usize),
+) -> Expression
+{
+    {
+        let (l, h) = (spans::start(&left), spans::end(&right));
+        spans::record(l, h, Expression::BinaryExpression(Rc::new(left), op, Rc::new(right)))
+    }
+}
+
+#[allow(unused_variables)]
+fn __action99<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action100<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Types, usize),
+) -> ::std::option::Option<Types>
+{
+    Some(__0)
+}
+
+#[allow(unused_variables)]
+fn __action101<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> ::std::option::Option<Types>
+{
+    None
+}
+
+#[allow(unused_variables)]
+fn __action102<
+    'input,
+>(
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, __0, _): (usize, Types, usize),
+) -> Types
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action103<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> usize
+{
+    __lookahead.clone()
+}
+
+#[allow(unused_variables)]
+fn __action104<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Expression, usize),
+) -> ::std::option::Option<Expression>
+{
+    Some(__0)
+}
+
+#[allow(unused_variables)]
+fn __action105<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> ::std::option::Option<Expression>
+{
+    None
+}
+
+#[allow(unused_variables)]
+fn __action106<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> ::std::vec::Vec<Expression>
+{
+    vec![]
+}
+
+#[allow(unused_variables)]
+fn __action107<
+    'input,
+>(
+    input: &'input str,
+    (_, v, _): (usize, ::std::vec::Vec<Expression>, usize),
+) -> ::std::vec::Vec<Expression>
+{
+    v
+}
+
+#[allow(unused_variables)]
+fn __action108<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Expression, usize),
+    (_, _, _): (usize, &'input str, usize),
+) -> Expression
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action109<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Expression, usize),
+) -> ::std::vec::Vec<Expression>
+{
+    vec![__0]
+}
+
+#[allow(unused_variables)]
+fn __action110<
+    'input,
+>(
+    input: &'input str,
+    (_, v, _): (usize, ::std::vec::Vec<Expression>, usize),
+    (_, e, _): (usize, Expression, usize),
+) -> ::std::vec::Vec<Expression>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+fn __action111<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, Types, usize),
+) -> ::std::option::Option<Types>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action102(
+        input,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action100(
+        input,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action112<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, String, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, Types, usize),
+    __5: (usize, &'input str, usize),
+    __6: (usize, Expression, usize),
+    __7: (usize, &'input str, usize),
+    __8: (usize, Expression, usize),
+    __9: (usize, &'input str, usize),
+    __10: (usize, Expression, usize),
+) -> Expression
+{
+    let __start0 = __3.0.clone();
+    let __end0 = __4.2.clone();
+    let __temp0 = __action111(
+        input,
+        __3,
+        __4,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action1(
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+        __5,
+        __6,
+        __7,
+        __8,
+        __9,
+        __10,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action113<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, String, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, Expression, usize),
+    __5: (usize, &'input str, usize),
+    __6: (usize, Expression, usize),
+    __7: (usize, &'input str, usize),
+    __8: (usize, Expression, usize),
+) -> Expression
+{
+    let __start0 = __2.2.clone();
+    let __end0 = __3.0.clone();
+    let __temp0 = __action101(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action1(
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+        __8,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action114<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, String, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, Types, usize),
+    __5: (usize, &'input str, usize),
+    __6: (usize, Expression, usize),
+    __7: (usize, &'input str, usize),
+    __8: (usize, Expression, usize),
+    __9: (usize, &'input str, usize),
+    __10: (usize, Expression, usize),
+) -> Expression
+{
+    let __start0 = __3.0.clone();
+    let __end0 = __4.2.clone();
+    let __temp0 = __action111(
+        input,
+        __3,
+        __4,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action2(
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+        __5,
+        __6,
+        __7,
+        __8,
+        __9,
+        __10,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action115<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, String, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, Expression, usize),
+    __5: (usize, &'input str, usize),
+    __6: (usize, Expression, usize),
+    __7: (usize, &'input str, usize),
+    __8: (usize, Expression, usize),
+) -> Expression
+{
+    let __start0 = __2.2.clone();
+    let __end0 = __3.0.clone();
+    let __temp0 = __action101(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action2(
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+        __8,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action116<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, Expression, usize),
+    __1: (usize, &'input str, usize),
+) -> ::std::vec::Vec<Expression>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action108(
+        input,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action109(
+        input,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action117<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, ::std::vec::Vec<Expression>, usize),
+    __1: (usize, Expression, usize),
+    __2: (usize, &'input str, usize),
+) -> ::std::vec::Vec<Expression>
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action108(
+        input,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action110(
+        input,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action118<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, ::std::option::Option<Expression>, usize),
+) -> Vec<Expression>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action106(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action82(
+        input,
+        __temp0,
+        __0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action119<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, ::std::vec::Vec<Expression>, usize),
+    __1: (usize, ::std::option::Option<Expression>, usize),
+) -> Vec<Expression>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action107(
+        input,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action82(
+        input,
+        __temp0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action120<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, String, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Types, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, Expression, usize),
+    __6: (usize, &'input str, usize),
+    __7: (usize, Expression, usize),
+    __8: (usize, &'input str, usize),
+    __9: (usize, Expression, usize),
+) -> Expression
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action103(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action112(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+        __8,
+        __9,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action121<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, String, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Expression, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, Expression, usize),
+    __6: (usize, &'input str, usize),
+    __7: (usize, Expression, usize),
+) -> Expression
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action103(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action113(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action122<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, String, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Types, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, Expression, usize),
+    __6: (usize, &'input str, usize),
+    __7: (usize, Expression, usize),
+    __8: (usize, &'input str, usize),
+    __9: (usize, Expression, usize),
+) -> Expression
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action103(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action114(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+        __8,
+        __9,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action123<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, String, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Expression, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, Expression, usize),
+    __6: (usize, &'input str, usize),
+    __7: (usize, Expression, usize),
+) -> Expression
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action103(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action115(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action124<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, Expression, usize),
+    __1: (usize, usize, usize),
+) -> Expression
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action103(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action21(
+        input,
+        __temp0,
+        __0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action125<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, UnaryOperator, usize),
+    __1: (usize, Expression, usize),
+) -> Expression
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action103(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action12(
+        input,
+        __temp0,
+        __0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action126<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, Expression, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Expression, usize),
+    __3: (usize, &'input str, usize),
+) -> Expression
+{
+    let __start0 = __3.2.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action83(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action15(
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action127<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, Expression, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Expression, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, Expression, usize),
+    __5: (usize, &'input str, usize),
+) -> Expression
+{
+    let __start0 = __5.2.clone();
+    let __end0 = __5.2.clone();
+    let __temp0 = __action83(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action16(
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action128<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, Expression, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, String, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, Vec<Expression>, usize),
+    __5: (usize, &'input str, usize),
+) -> Expression
+{
+    let __start0 = __5.2.clone();
+    let __end0 = __5.2.clone();
+    let __temp0 = __action83(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action17(
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action129<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, Expression, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, String, usize),
+) -> Expression
+{
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action83(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action18(
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action130<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, Expression, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, &'input str, usize),
+) -> Expression
+{
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action83(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action19(
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action131<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, Expression, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, String, usize),
+) -> Expression
+{
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action83(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action20(
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action132<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, Expression, usize),
+) -> Expression
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action83(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action124(
+        input,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action133<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, Expression, usize),
+) -> Vec<Expression>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action104(
+        input,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action118(
+        input,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action134<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> Vec<Expression>
+{
+    let __start0 = __lookbehind.clone();
+    let __end0 = __lookahead.clone();
+    let __temp0 = __action105(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action118(
+        input,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action135<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, ::std::vec::Vec<Expression>, usize),
+    __1: (usize, Expression, usize),
+) -> Vec<Expression>
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action104(
+        input,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action119(
+        input,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action136<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, ::std::vec::Vec<Expression>, usize),
+) -> Vec<Expression>
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action105(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action119(
+        input,
+        __0,
+        __temp0,
+    )
+}
+
+pub trait __ToTriple<'input, > {
+    type Error;
+    fn to_triple(value: Self) -> Result<(usize,(usize, &'input str),usize),Self::Error>;
+}
+
+impl<'input, > __ToTriple<'input, > for (usize, (usize, &'input str), usize) {
+    type Error = ();
+    fn to_triple(value: Self) -> Result<(usize,(usize, &'input str),usize),()> {
+        Ok(value)
+    }
+}
+impl<'input, > __ToTriple<'input, > for Result<(usize, (usize, &'input str), usize),()> {
+    type Error = ();
+    fn to_triple(value: Self) -> Result<(usize,(usize, &'input str),usize),()> {
+        value
+    }
+}
@@ -0,0 +1,548 @@
+use std::collections::HashMap;
+use std::env;
+use std::process::Command;
+use syntax::ast::{Attribute, LitKind, MetaItemKind, NestedMetaItem, NestedMetaItemKind};
+use syntax::codemap::{CodeMap, Span};
+
+// The attributes Stanley reads, by their default names
+pub const ATTRIBUTES: &'static [&'static str] = &["requires",
+                                                  "ensures",
+                                                  "ensures_err",
+                                                  "invariant",
+                                                  "variant",
+                                                  "condition",
+                                                  "constant_time",
+                                                  "flows",
+                                                  "deterministic",
+                                                  "equiv_to",
+                                                  "contract_stable",
+                                                  "pledge",
+                                                  "history_invariant",
+                                                  "lock_invariant",
+                                                  "harness",
+                                                  "assume_pre",
+                                                  "expect_fail",
+                                                  "exact_bitwidth",
+                                                  "no_panic",
+                                                  "debug_assertions"];
+
+const ENVIRONMENT_PREFIX: &'static str = "STANLEY_";
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Stage {
+    // MIR as handed to plugin passes, closest to the source
+    Built,
+    // MIR after the standard cleanup and optimization passes
+    Optimized,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Format {
+    // Unformatted clauses are listed and fail the build
+    Check,
+    // Unformatted clauses are rewritten in the source
+    Write,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Integers {
+    // Machine integers, exact for every operation
+    Bitvector,
+    // Unbounded integers in the range of their type, assuming arithmetic
+    // does not overflow, outside code marked `exact_bitwidth`
+    Mathematical,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Unfinished {
+    // Reaching `unimplemented!()` fails verification
+    Fail,
+    // Code marked unimplemented is assumed to do whatever is needed
+    Trust,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum DebugAssertions {
+    // `debug_assert!` is an obligation like `assert!`, as in debug builds
+    Verify,
+    // Its condition is assumed to hold after it
+    Assume,
+    // It is skipped, as in release builds
+    Ignore,
+}
+
+pub fn debug_assertions(value: &str) -> DebugAssertions {
+    match value {
+        "verify" => DebugAssertions::Verify,
+        "assume" => DebugAssertions::Assume,
+        "ignore" => DebugAssertions::Ignore,
+        _ => {
+            error!("Unknown `debug_assertions` mode `{}`, expected `verify`, `assume` or \
+                    `ignore`",
+                   value)
+        }
+    }
+}
+
+// How a class of obligations is reported
+#[derive(Clone, Copy, PartialEq)]
+pub enum Lint {
+    Allow,
+    // Checked and reported, without failing the function
+    Warn,
+    Deny,
+}
+
+fn lint(name: &str, value: &str) -> Lint {
+    match value {
+        "allow" => Lint::Allow,
+        "warn" => Lint::Warn,
+        "deny" => Lint::Deny,
+        _ => error!("Unknown level `{}` for `{}`, expected `allow`, `warn` or `deny`", value, name),
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Emit {
+    // The SMT-LIB script of every obligation, before it is solved
+    Smt,
+    // Every variable of a counterexample, compiler temporaries included
+    Model,
+}
+
+// How the solver is run
+#[derive(Clone)]
+pub struct Solver {
+    // The binary, looked up on the path unless it names a file
+    pub binary: String,
+    // Seconds the solver may take on one script
+    pub timeout: Option<u64>,
+}
+
+// An argument of a list attribute; flags have an empty value
+pub struct Argument {
+    pub value: String,
+    pub span: Span,
+}
+
+pub struct Config {
+    // Where to write the summaries of this run
+    pub summary: Option<String>,
+    // Summaries of a previous run to diff contracts and results against
+    pub diff: Option<String>,
+    // Record of the stable contracts of public functions
+    pub contracts: Option<String>,
+    // Where to write the manifest of proved public functions
+    pub manifest: Option<String>,
+    // Where to write the diagnostics of the run for editors
+    pub diagnostics: Option<String>,
+    // Obligations known not to be proved, and whether to record this run's
+    // failures there instead of checking against them
+    pub baseline: Option<String>,
+    pub update_baseline: bool,
+    // Whether the conditions of contracts are checked or rewritten for format
+    pub fmt: Option<Format>,
+    // The identifier of the obligation whose whole context is printed
+    pub explain: Option<String>,
+    // Directory to write the SMT-LIB of every obligation to
+    pub smt: Option<String>,
+    // Directory to archive the script and proof of every proved obligation in
+    pub proofs: Option<String>,
+    // Random inputs each proved obligation is evaluated on, to catch
+    // encodings that disagree with Rust's semantics
+    pub differential: Option<usize>,
+    // Seconds all obligations of the crate may take together
+    pub budget: Option<u64>,
+    // Solver processes the conjuncts of one obligation may be split across
+    pub jobs: usize,
+    pub solver: Solver,
+    // Only obligations whose identifiers contain it are solved
+    pub filter: Option<String>,
+    // What is printed besides the results
+    pub emit: Vec<Emit>,
+    // Measure specification strength by checking mutated postconditions
+    pub mutate: bool,
+    // Verify generic functions once per concrete instantiation in the crate
+    pub monomorphize: bool,
+    // Model atomics as sequentially consistent shared variables
+    pub approximate_concurrency: bool,
+    // Report branches no run satisfying the precondition takes
+    pub dead_branches: bool,
+    // Check every function of the crate not to panic, contract or not
+    pub no_panic: bool,
+    // How functions reaching `unimplemented!()` are treated
+    pub unfinished: Unfinished,
+    // How `debug_assert!` is treated outside functions deciding for
+    // themselves
+    pub debug_assertions: DebugAssertions,
+    // Whether integer casts must not truncate
+    pub truncation: Lint,
+    // How integers are modelled by the solver
+    pub integers: Integers,
+    // Which MIR the verification conditions are generated from
+    pub stage: Stage,
+    // Prefix of the tool-style spelling of every attribute
+    pub namespace: String,
+    // Attributes renamed to avoid colliding with other tools
+    pub renamed: HashMap<String, String>,
+}
+
+impl Config {
+    pub fn from_args(args: &[NestedMetaItem]) -> Config {
+        let mut config = Config {
+            summary: None,
+            diff: None,
+            contracts: None,
+            manifest: None,
+            diagnostics: None,
+            baseline: None,
+            update_baseline: false,
+            fmt: None,
+            explain: None,
+            smt: None,
+            proofs: None,
+            differential: None,
+            budget: None,
+            jobs: 1,
+            solver: Solver {
+                binary: "z3".to_string(),
+                timeout: None,
+            },
+            filter: None,
+            emit: Vec::new(),
+            mutate: false,
+            monomorphize: false,
+            approximate_concurrency: false,
+            dead_branches: false,
+            no_panic: false,
+            unfinished: Unfinished::Fail,
+            debug_assertions: DebugAssertions::Verify,
+            truncation: Lint::Allow,
+            integers: Integers::Bitvector,
+            stage: Stage::Built,
+            namespace: "stanley".to_string(),
+            renamed: HashMap::new(),
+        };
+
+        for arg in args {
+            if let NestedMetaItemKind::MetaItem(ref item) = arg.node {
+                if let MetaItemKind::Word = item.node {
+                    if !config.set_flag(&item.name.as_str(), true) {
+                        error!("Unknown plugin flag `{}`", item.name);
+                    }
+
+                    continue;
+                }
+
+                if let MetaItemKind::NameValue(ref literal) = item.node {
+                    if let LitKind::Str(ref value, _) = literal.node {
+                        if !config.set_option(&item.name.as_str(), &value.as_str()) {
+                            error!("Unknown plugin option `{}`", item.name);
+                        }
+
+                        continue;
+                    }
+                }
+
+                error!("Plugin options must look like `name` or `name = \"value\"`, found `{}`",
+                       item.name)
+            }
+        }
+
+        config.set_from_environment();
+        config
+    }
+
+    // `STANLEY_NAME=value` overrides the plugin option `name`, and
+    // `STANLEY_FLAG=1` or `=0` turns a flag on or off, as extra arguments
+    // are hard to pass to the plugin through cargo. Other tools may set
+    // variables of their own with the prefix, so unknown ones are ignored
+    fn set_from_environment(&mut self) {
+        for (variable, value) in env::vars() {
+            if !variable.starts_with(ENVIRONMENT_PREFIX) {
+                continue;
+            }
+
+            let name = variable[ENVIRONMENT_PREFIX.len()..].to_lowercase();
+            let known = match value.as_ref() {
+                "1" | "true" if self.set_flag(&name, true) => true,
+                "0" | "false" if self.set_flag(&name, false) => true,
+                _ => self.set_option(&name, &value),
+            };
+
+            if !known {
+                println!("   warning: ignoring `{}`, which names no option", variable);
+            }
+        }
+    }
+
+    fn set_flag(&mut self, name: &str, on: bool) -> bool {
+        match name {
+            "mutate" => self.mutate = on,
+            "monomorphize" => self.monomorphize = on,
+            "approximate_concurrency" => self.approximate_concurrency = on,
+            "dead_branches" => self.dead_branches = on,
+            "no_panic" => self.no_panic = on,
+            "update_baseline" => self.update_baseline = on,
+            _ => return false,
+        }
+
+        true
+    }
+
+    fn set_option(&mut self, name: &str, value: &str) -> bool {
+        match name {
+            "summary" => self.summary = Some(value.to_string()),
+            "diff" => self.diff = Some(value.to_string()),
+            "contracts" => self.contracts = Some(value.to_string()),
+            "manifest" => self.manifest = Some(value.to_string()),
+            "diagnostics" => self.diagnostics = Some(value.to_string()),
+            "baseline" => self.baseline = Some(value.to_string()),
+            "explain" => self.explain = Some(value.to_string()),
+            "fmt" => {
+                self.fmt = match value {
+                    "check" => Some(Format::Check),
+                    "write" => Some(Format::Write),
+                    _ => error!("Unknown `fmt` mode `{}`, expected `check` or `write`", value),
+                }
+            }
+            "smt" => self.smt = Some(value.to_string()),
+            "proofs" => self.proofs = Some(value.to_string()),
+            "differential" => {
+                self.differential = Some(value.parse().unwrap_or_else(|_| {
+                    error!("`differential` expects a number of samples, found `{}`", value)
+                }))
+            }
+            "budget" => {
+                self.budget = Some(value.parse().unwrap_or_else(|_| {
+                    error!("`budget` expects a number of seconds, found `{}`", value)
+                }))
+            }
+            "jobs" => {
+                self.jobs = match value.parse() {
+                    Ok(jobs) if jobs > 0 => jobs,
+                    _ => error!("`jobs` expects a positive number of solvers, found `{}`", value),
+                }
+            }
+            "solver" => self.solver.binary = value.to_string(),
+            "timeout" => {
+                self.solver.timeout = match value.parse() {
+                    Ok(timeout) if timeout > 0 => Some(timeout),
+                    _ => {
+                        error!("`timeout` expects a positive number of seconds, found `{}`",
+                               value)
+                    }
+                }
+            }
+            "filter" => self.filter = Some(value.to_string()),
+            "emit" => {
+                self.emit = value.split(',')
+                    .map(|emit| match emit.trim() {
+                             "smt" => Emit::Smt,
+                             "model" => Emit::Model,
+                             emit => {
+                                 error!("Unknown `emit` kind `{}`, expected `smt` or `model`",
+                                        emit)
+                             }
+                         })
+                    .collect()
+            }
+            "namespace" => self.namespace = value.to_string(),
+            // Also the name of the attribute choosing the mode of a
+            // function, which cannot be renamed
+            "debug_assertions" => self.debug_assertions = debug_assertions(value),
+            attribute if ATTRIBUTES.contains(&attribute) => {
+                self.renamed.insert(attribute.to_string(), value.to_string());
+            }
+            "unfinished" => {
+                self.unfinished = match value {
+                    "fail" => Unfinished::Fail,
+                    "trust" => Unfinished::Trust,
+                    _ => {
+                        error!("Unknown `unfinished` mode `{}`, expected `fail` or `trust`",
+                               value)
+                    }
+                }
+            }
+            "truncation" => self.truncation = lint("truncation", value),
+            "integers" => {
+                self.integers = match value {
+                    "bitvector" => Integers::Bitvector,
+                    "mathematical" => Integers::Mathematical,
+                    _ => {
+                        error!("Unknown `integers` model `{}`, expected `bitvector` or \
+                                `mathematical`",
+                               value)
+                    }
+                }
+            }
+            "stage" => {
+                self.stage = match value {
+                    "built" => Stage::Built,
+                    "optimized" => Stage::Optimized,
+                    _ => error!("Unknown MIR stage `{}`, expected `built` or `optimized`", value),
+                }
+            }
+            _ => return false,
+        }
+
+        true
+    }
+
+    pub fn solver_version(&self) -> String {
+        Command::new(&self.solver.binary)
+            .arg("--version")
+            .output()
+            .ok()
+            .and_then(|output| String::from_utf8(output.stdout).ok())
+            .map_or("an unknown Z3".to_string(), |version| version.trim().to_string())
+    }
+
+    // Stanley, the solver and the options changing how obligations are
+    // encoded, which together decide the results of a run
+    pub fn producer(&self) -> String {
+        let mut options = vec![match self.stage {
+                                   Stage::Built => "stage=built",
+                                   Stage::Optimized => "stage=optimized",
+                               }];
+
+        if self.approximate_concurrency {
+            options.push("approximate_concurrency");
+        }
+
+        if self.monomorphize {
+            options.push("monomorphize");
+        }
+
+        if self.unfinished == Unfinished::Trust {
+            options.push("unfinished=trust");
+        }
+
+        if self.integers == Integers::Mathematical {
+            options.push("integers=mathematical");
+        }
+
+        match self.debug_assertions {
+            DebugAssertions::Verify => {}
+            DebugAssertions::Assume => options.push("debug_assertions=assume"),
+            DebugAssertions::Ignore => options.push("debug_assertions=ignore"),
+        }
+
+        format!("Stanley {} with {} ({})",
+                env!("CARGO_PKG_VERSION"),
+                self.solver_version(),
+                options.join(", "))
+    }
+
+    // A renamed attribute is only recognized under its new name; otherwise
+    // both `name` and `namespace::name` are
+    pub fn spellings(&self, name: &str) -> Vec<String> {
+        match self.renamed.get(name) {
+            Some(spelling) => vec![spelling.clone()],
+            None => vec![name.to_string(), format!("{}::{}", self.namespace, name)],
+        }
+    }
+
+    pub fn recognizes(&self, attr: &Attribute, name: &str) -> bool {
+        self.spellings(name).contains(&path(attr))
+    }
+
+    pub fn emits(&self, emit: Emit) -> bool {
+        self.emit.contains(&emit)
+    }
+}
+
+pub fn path(attr: &Attribute) -> String {
+    attr.path
+        .segments
+        .iter()
+        .map(|segment| segment.identifier.name.as_str().to_string())
+        .collect::<Vec<_>>()
+        .join("::")
+}
+
+fn one_of(names: &[&str]) -> String {
+    names.iter().map(|name| format!("`{}`", name)).collect::<Vec<_>>().join(", ")
+}
+
+// The arguments of an attribute in order, where a key may be repeated
+pub fn argument_list(attr: &Attribute,
+                     keys: &[&str],
+                     flags: &[&str],
+                     codemap: &CodeMap)
+                     -> Vec<(String, Argument)> {
+    let name = path(attr);
+    let items = attr.meta_item_list().unwrap_or_else(|| {
+        error!("{}: expected `#[{}({} = \"...\")]`",
+               codemap.span_to_string(attr.span),
+               name,
+               keys[0])
+    });
+    let mut arguments = Vec::new();
+
+    for item in items {
+        let location = codemap.span_to_string(item.span);
+        let meta = match item.node {
+            NestedMetaItemKind::MetaItem(ref meta) => meta,
+            NestedMetaItemKind::Literal(_) => {
+                error!("{}: arguments of `{}` must be named, e.g. `{} = \"...\"`",
+                       location,
+                       name,
+                       keys[0])
+            }
+        };
+        let key = meta.name.as_str().to_string();
+
+        let value = match meta.node {
+            MetaItemKind::NameValue(ref literal) if keys.contains(&&*key) => {
+                match literal.node {
+                    LitKind::Str(ref value, _) => value.to_string(),
+                    _ => error!("{}: the value of `{}` must be a string", location, key),
+                }
+            }
+            MetaItemKind::Word if flags.contains(&&*key) => String::new(),
+            MetaItemKind::List(_) if keys.contains(&&*key) || flags.contains(&&*key) => {
+                error!("{}: `{}` does not take a list", location, key)
+            }
+            MetaItemKind::Word if keys.contains(&&*key) => {
+                error!("{}: `{}` needs a value, e.g. `{} = \"...\"`", location, key, key)
+            }
+            MetaItemKind::NameValue(_) if flags.contains(&&*key) => {
+                error!("{}: `{}` is a flag and takes no value", location, key)
+            }
+            _ => {
+                let accepted = keys.iter().chain(flags.iter()).cloned().collect::<Vec<_>>();
+                error!("{}: `{}` only accepts {}, found `{}`",
+                       location,
+                       name,
+                       one_of(&accepted),
+                       key)
+            }
+        };
+
+        arguments.push((key, Argument { value: value, span: item.span }));
+    }
+
+    arguments
+}
+
+// The arguments of an attribute by key, where none may be given twice
+pub fn arguments(attr: &Attribute,
+                 keys: &[&str],
+                 flags: &[&str],
+                 codemap: &CodeMap)
+                 -> HashMap<String, Argument> {
+    let mut arguments = HashMap::new();
+
+    for (key, argument) in argument_list(attr, keys, flags, codemap) {
+        if arguments.contains_key(&key) {
+            error!("{}: `{}` is given more than once",
+                   codemap.span_to_string(argument.span),
+                   key);
+        }
+
+        arguments.insert(key, argument);
+    }
+
+    arguments
+}
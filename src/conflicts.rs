@@ -0,0 +1,119 @@
+use {CONDITION_FLAGS, CONDITION_KEYS};
+use config::{self, Config};
+use syntax::ast::Attribute;
+use syntax::codemap::{CodeMap, Span};
+
+// A contract can reach a function through several attributes: repeated
+// ones, namespaced spellings, the keys of the deprecated `#[condition]`,
+// and attributes a macro writes next to hand-written ones. Repeated
+// `requires` and `ensures` clauses hold together, but a clause given twice
+// is likely one source duplicating another, and only the last `invariant`
+// or `variant` is used; both are warned about with where each came from
+
+const KEYS: &'static [&'static str] = &["requires", "ensures", "ensures_err", "invariant",
+                                        "variant"];
+
+// Keys of which only the last clause given is used
+const SINGLE: &'static [&'static str] = &["invariant", "variant"];
+
+struct Source {
+    key: &'static str,
+    text: String,
+    // How the clause was written, as in `#[stanley::requires]` or
+    // `#[condition(pre)]`
+    form: String,
+    span: Span,
+}
+
+// The attribute key of a key of `#[condition]`
+fn condition_key(key: &str) -> Option<&'static str> {
+    match key {
+        "pre" => Some("requires"),
+        "post" => Some("ensures"),
+        "post_err" => Some("ensures_err"),
+        "invariant" => Some("invariant"),
+        "variant" => Some("variant"),
+        _ => None,
+    }
+}
+
+fn sources(attrs: &[Attribute], config: &Config, codemap: &CodeMap) -> Vec<Source> {
+    let mut found = Vec::new();
+
+    for attr in attrs {
+        if let Some(&key) = KEYS.iter().find(|&&key| config.recognizes(attr, key)) {
+            if let Some(text) = attr.value_str() {
+                found.push(Source {
+                               key: key,
+                               text: text.to_string(),
+                               form: format!("`#[{}]`", config::path(attr)),
+                               span: attr.span,
+                           });
+            }
+        } else if config.recognizes(attr, "condition") {
+            let arguments = config::argument_list(attr, CONDITION_KEYS, CONDITION_FLAGS, codemap);
+
+            for (name, argument) in arguments {
+                if let Some(key) = condition_key(&name) {
+                    found.push(Source {
+                                   key: key,
+                                   text: argument.value,
+                                   form: format!("`#[{}({})]`", config::path(attr), name),
+                                   span: argument.span,
+                               });
+                }
+            }
+        }
+    }
+
+    found
+}
+
+fn provenance(source: &Source, codemap: &CodeMap) -> String {
+    format!("      `{}` from {} at {}",
+            source.text,
+            source.form,
+            codemap.span_to_string(source.span))
+}
+
+pub fn check(name: &str, attrs: &[Attribute], config: &Config, codemap: &CodeMap) {
+    let sources = sources(attrs, config, codemap);
+
+    for &key in KEYS {
+        let given = sources.iter().filter(|source| source.key == key).collect::<Vec<_>>();
+
+        if SINGLE.contains(&key) && given.iter().any(|source| source.text != given[0].text) {
+            println!("   warning: {} is given different `{}` clauses, and only the last one is \
+                      used:",
+                     name,
+                     key);
+
+            for source in &given {
+                println!("{}", provenance(source, codemap));
+            }
+
+            continue;
+        }
+
+        for (index, source) in given.iter().enumerate() {
+            let repeats = given.iter()
+                .filter(|other| other.text == source.text)
+                .collect::<Vec<_>>();
+
+            // Reported once, at the first occurrence
+            if repeats.len() < 2 || given[..index].iter().any(|other| other.text == source.text) {
+                continue;
+            }
+
+            println!("   warning: {} is given the `{}` clause `{}` {} times:",
+                     name,
+                     key,
+                     source.text,
+                     repeats.len());
+
+            for repeat in repeats {
+                println!("{}", provenance(repeat, codemap));
+            }
+        }
+    }
+}
@@ -0,0 +1,179 @@
+use {MirData, field_path, gen_expression, gen_lvalue, parse_attributes,
+     substitute_variable_with_expression};
+use assume;
+use ast::{self, BinaryOperator, Expression};
+use option;
+use result;
+use rustc::hir;
+use rustc::hir::def_id::DefId;
+use rustc::mir::*;
+use rustc::ty::TypeVariants;
+use self_composition::{self, Composition};
+use std::rc::Rc;
+use structs;
+use syntax::codemap::Span;
+
+// A call to a function of the crate with a contract is verified against
+// the contract instead of the callee's body: the caller must establish the
+// precondition, and continues from any result satisfying the
+// postcondition. The callee's own proof covers the rest, so verification
+// composes across the crate. Callees taking mutable references are not
+// called through their contracts, which do not say what they leave behind
+// the references, so their calls are only verified in a harness, where they
+// are inlined
+
+// Names the result of the call, about which only the postcondition is known
+const RESULT_SUFFIX: &'static str = "$returned";
+
+fn binary(left: Expression, op: BinaryOperator, right: Expression) -> Expression {
+    Expression::BinaryExpression(Rc::new(left), op, Rc::new(right))
+}
+
+fn takes_mutable_references(mir: &Mir) -> bool {
+    mir.args_iter().any(|arg| match mir.local_decls[arg].ty.sty {
+                            TypeVariants::TyRef(_, ref mt) => mt.mutbl == hir::MutMutable,
+                            _ => false,
+                        })
+}
+
+// The precondition and postcondition of a callee whose calls are verified
+// against its contract
+pub fn of(def_id: DefId, data: &MirData) -> Option<(String, String)> {
+    if !def_id.is_local() || data.composition.is_relational() {
+        return None;
+    }
+
+    let codemap = data.tcx.sess.codemap();
+    let (pre, post) = parse_attributes(&data.tcx.get_attrs(def_id), &data.config, codemap);
+
+    if pre == "" || takes_mutable_references(&data.tcx.item_mir(def_id)) {
+        None
+    } else {
+        Some((pre, post))
+    }
+}
+
+pub fn call(wp: &Expression,
+            def_id: DefId,
+            contract: (String, String),
+            args: &[Operand],
+            destination: Lvalue,
+            index: usize,
+            depth: usize,
+            span: Span,
+            data: &MirData)
+            -> Expression {
+    let level = match data.composition {
+        Composition::Inlined(level) => level + 1,
+        _ => 1,
+    };
+
+    let callee_mir = data.tcx.item_mir(def_id);
+    let callee = MirData::new(data.tcx,
+                              &callee_mir,
+                              data.theories.clone(),
+                              data.config.clone())
+        .with_composition(Composition::Inlined(level));
+
+    let (pre, post) = contract;
+    let mut pre = callee.composition.rename(&callee.resolve_condition(&pre));
+    let mut post = callee.composition.rename(&callee.resolve_condition(&post));
+
+    // Entry values of the callee are the arguments of the call
+    for (param, arg) in callee.mir.args_iter().zip(args.iter()) {
+        let decl = &callee.mir.local_decls[param];
+        let name = decl.name.unwrap().as_str().to_string();
+
+        // and the fields of a struct those of the struct passed
+        if let Operand::Consume(ref lvalue) = *arg {
+            if let Some((path, _)) = field_path(lvalue, data) {
+                for field in structs::fields(data.tcx, &name, decl.ty) {
+                    let arg = data.composition.rename(&structs::rebase(&field, &name, &path));
+                    let entry = self_composition::rename_with(&field, ast::OLD_SUFFIX);
+
+                    for field in &[field, entry] {
+                        let field = callee.composition.rename(field);
+                        pre = substitute_variable_with_expression(&pre, &field, &arg);
+                        post = substitute_variable_with_expression(&post, &field, &arg);
+                    }
+                }
+            }
+        }
+
+        let param_var = Expression::VariableMapping(name, ast::type_to_enum(decl.ty));
+        let arg = data.composition.rename(&gen_expression(arg, data));
+
+        for param in &[param_var.clone(),
+                       self_composition::rename_with(&param_var, ast::OLD_SUFFIX)] {
+            let param = callee.composition.rename(param);
+            pre = substitute_variable_with_expression(&pre, &param, &arg);
+            post = substitute_variable_with_expression(&post, &param, &arg);
+        }
+    }
+
+    let ret = Expression::VariableMapping("ret".to_string(),
+                                          ast::type_to_enum(callee.mir.return_ty));
+    let dest = gen_lvalue(destination.clone(), data);
+    // Every visit of an unrolled loop calls anew
+    let suffix = format!("{}{}_{}", RESULT_SUFFIX, index, depth);
+    let result = self_composition::rename_with(&data.composition.rename(&dest), &suffix);
+    let mut after = substitute_variable_with_expression(wp,
+                                                        &data.composition.rename(&dest),
+                                                        &result);
+    post = substitute_variable_with_expression(&post, &callee.composition.rename(&ret), &result);
+
+    // The fields of a struct or tuple returned are those of the destination
+    if let Some((path, _)) = field_path(&destination, data) {
+        for field in structs::fields(data.tcx, "ret", callee.mir.return_ty) {
+            let dest_field = data.composition.rename(&structs::rebase(&field, "ret", &path));
+            let result_field = self_composition::rename_with(&dest_field, &suffix);
+            after = substitute_variable_with_expression(&after, &dest_field, &result_field);
+            post = substitute_variable_with_expression(&post,
+                                                       &callee.composition.rename(&field),
+                                                       &result_field);
+        }
+    }
+
+    // The tag of an option travels with its payload
+    if option::holds_option(&destination, data) {
+        after = substitute_variable_with_expression(&after,
+                                                    &data.composition.rename(&option::tag(&dest)),
+                                                    &option::tag(&result));
+        post = substitute_variable_with_expression(&post,
+                                                   &callee.composition
+                                                        .rename(&option::tag(&ret)),
+                                                   &option::tag(&result));
+    }
+
+    // So do the tag and the `Err` payload of a result
+    if result::holds_result(&destination, data) {
+        let ty = result::error_type(callee.mir.return_ty);
+        let parts = [(result::tag(&dest), result::tag(&ret), result::tag(&result)),
+                     (result::error(&dest, ty),
+                      result::error(&ret, ty),
+                      result::error(&result, ty))];
+
+        for &(ref dest_part, ref ret_part, ref result_part) in parts.iter() {
+            after = substitute_variable_with_expression(&after,
+                                                        &data.composition.rename(dest_part),
+                                                        result_part);
+            post = substitute_variable_with_expression(&post,
+                                                       &callee.composition.rename(ret_part),
+                                                       result_part);
+        }
+    }
+
+    let wp = binary(post, BinaryOperator::Implication, after);
+
+    if assume::is_marked(span, &data.assumed_calls) {
+        let codemap = data.tcx.sess.codemap();
+        data.assumptions.borrow_mut().push(format!("precondition of `{}` at {}",
+                                                   data.tcx.item_path_str(def_id),
+                                                   codemap.span_to_string(span)));
+        wp
+    } else if data.probe.is_some() {
+        wp
+    } else {
+        binary(pre, BinaryOperator::And, wp)
+    }
+}
@@ -0,0 +1,63 @@
+use {MirData, is_panic};
+use config::{self, Config, DebugAssertions};
+use rustc::mir::*;
+use syntax::ast::Attribute;
+
+// `debug_assert!`, `debug_assert_eq!` and `debug_assert_ne!` panic like
+// `assert!` in debug builds and are skipped in release builds, and code
+// uses them both for checks and for documenting what it relies on. With
+// `verify` their failure is an obligation, with `assume` the runs failing
+// them are not followed, and with `ignore` the branch failing them goes on
+// as the other does, as if they were not there. The plugin option gives
+// the mode of the crate and `#[debug_assertions = "..."]` that of a
+// function, also where it is inlined
+
+// Compiler-generated blocks between a failed assertion and its panic,
+// which build the message
+const MAX_STEPS: usize = 16;
+
+pub fn mode(attrs: &[Attribute], config: &Config) -> DebugAssertions {
+    match attrs.iter().find(|attr| config.recognizes(attr, "debug_assertions")) {
+        Some(attr) => {
+            let value = attr.value_str().unwrap_or_else(|| {
+                error!("Expected `#[debug_assertions = \"verify\" | \"assume\" | \"ignore\"]`")
+            });
+            config::debug_assertions(&value.as_str())
+        }
+        None => config.debug_assertions,
+    }
+}
+
+// The panic `block` leads to without branching, if any
+fn panic_block(block: usize, data: &MirData) -> Option<usize> {
+    let mut block = block;
+
+    for _ in 0..MAX_STEPS {
+        match data.block_data[block].terminator().kind {
+            TerminatorKind::Call { ref func, .. } if is_panic(func) => return Some(block),
+            TerminatorKind::Call { destination: Some((_, target)), .. } |
+            TerminatorKind::Goto { target } => block = target.index(),
+            _ => return None,
+        }
+    }
+
+    None
+}
+
+// Whether `block` leads to the panic of a failed debug assertion, which is
+// only known by the macro it was written with
+pub fn is_debug_assertion(block: usize, data: &MirData) -> bool {
+    let codemap = data.tcx.sess.codemap();
+
+    panic_block(block, data).map_or(false, |panic| {
+        let span = data.block_data[panic].terminator().source_info.span.source_callsite();
+        codemap.span_to_snippet(span).map_or(false, |macro_call| {
+            macro_call.starts_with("debug_assert")
+        })
+    })
+}
+
+// Whether reaching `block` is not a failure under the mode of `data`
+pub fn is_excused(block: usize, data: &MirData) -> bool {
+    data.debug_assertions != DebugAssertions::Verify && is_debug_assertion(block, data)
+}
@@ -0,0 +1,122 @@
+use baseline;
+use manifest::quote;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::Write;
+use summary::{self, Status, Summary};
+use syntax::codemap::{CodeMap, Span};
+
+// Editors learn about results through a JSON file rewritten at the end of
+// every run: one diagnostic per obligation that was not proved, placed on
+// the function it belongs to and carrying the counterexample found. The
+// file is replaced in one rename, so a watcher never reads half of it
+
+pub struct Location {
+    file: String,
+    // One-based lines and zero-based columns, like the compiler's own
+    line: usize,
+    column: usize,
+    end_line: usize,
+    end_column: usize,
+}
+
+thread_local! {
+    static LOCATIONS: RefCell<HashMap<String, Location>> = RefCell::new(HashMap::new());
+    static COUNTEREXAMPLES: RefCell<HashMap<String, Vec<(String, String)>>> =
+        RefCell::new(HashMap::new());
+}
+
+pub fn locate(name: &str, span: Span, codemap: &CodeMap) {
+    let start = codemap.lookup_char_pos(span.lo);
+    let end = codemap.lookup_char_pos(span.hi);
+    let location = Location {
+        file: start.file.name.clone(),
+        line: start.line,
+        column: start.col.0,
+        end_line: end.line,
+        end_column: end.col.0,
+    };
+
+    LOCATIONS.with(|locations| locations.borrow_mut().insert(name.to_string(), location));
+}
+
+// The values of the source variables refuting an obligation
+pub fn counterexample(label: &str, values: Vec<(String, String)>) {
+    COUNTEREXAMPLES.with(|found| found.borrow_mut().insert(label.to_string(), values));
+}
+
+fn severity(status: Status) -> Option<&'static str> {
+    match status {
+        Status::Valid => None,
+        Status::Invalid | Status::Error => Some("error"),
+        Status::Skipped => Some("warning"),
+    }
+}
+
+fn message(summary: &Summary) -> String {
+    match summary.status {
+        Status::Invalid => format!("{} does not hold", summary.label),
+        Status::Error => format!("{} could not be checked", summary.label),
+        _ => format!("{} was skipped: the time budget was spent", summary.label),
+    }
+}
+
+fn diagnostic(summary: &Summary, severity: &str, location: &Location) -> String {
+    let counterexample: Vec<String> = COUNTEREXAMPLES.with(|found| {
+        found.borrow()
+            .get(&summary.label)
+            .map_or(Vec::new(), |values| {
+                values.iter()
+                    .map(|&(ref name, ref value)| {
+                             format!("{{\"name\": {}, \"value\": {}}}",
+                                     quote(name),
+                                     quote(value))
+                         })
+                    .collect()
+            })
+    });
+
+    format!("  {{\"file\": {}, \"line\": {}, \"column\": {}, \"end_line\": {}, \
+             \"end_column\": {}, \"severity\": \"{}\", \"id\": {}, \"label\": {}, \
+             \"message\": {}, \"counterexample\": [{}]}}",
+            quote(&location.file),
+            location.line,
+            location.column,
+            location.end_line,
+            location.end_column,
+            severity,
+            quote(&baseline::id(&summary.label)),
+            quote(&summary.label),
+            quote(&message(summary)),
+            counterexample.join(", "))
+}
+
+pub fn save(path: &str, summaries: &[Summary]) {
+    let diagnostics = LOCATIONS.with(|locations| {
+        let locations = locations.borrow();
+
+        summaries.iter()
+            .filter_map(|summary| {
+                let name = summary::function(&summary.label);
+
+                match (severity(summary.status), locations.get(name)) {
+                    (Some(severity), Some(location)) => {
+                        Some(diagnostic(summary, severity, location))
+                    }
+                    _ => None,
+                }
+            })
+            .collect::<Vec<_>>()
+    });
+
+    let partial = format!("{}.partial", path);
+    File::create(&partial)
+        .and_then(|mut file| if diagnostics.is_empty() {
+                      writeln!(file, "[]")
+                  } else {
+                      writeln!(file, "[\n{}\n]", diagnostics.join(",\n"))
+                  })
+        .and_then(|_| fs::rename(&partial, path))
+        .unwrap_or_else(|e| error!("Cannot write `{}`: {}", path, e));
+}
@@ -0,0 +1,206 @@
+use {MirData, assign, gen_expression, gen_integral, gen_lvalue, referenced};
+use ast::{self, BinaryOperator, Expression, UnaryOperator};
+use option;
+use result;
+use rustc::mir::*;
+use rustc::ty::{AdtDef, Ty, TypeVariants};
+use std::rc::Rc;
+
+// An enum other than `Option` and `Result` is modelled by its discriminant,
+// `name$variant`, and the fields of each variant, named by the variant as
+// in `name$Circle.0`. A `match` switches on the discriminant, and the
+// bindings of an arm, its guard included, read the fields of the variant
+// the arm matched. Specifications test the variant with `x is Circle`
+
+const DISCRIMINANT_SUFFIX: &'static str = "$variant";
+
+fn enum_adt(ty: Ty) -> Option<&AdtDef> {
+    match ty.sty {
+        TypeVariants::TyRef(_, ref referenced) => enum_adt(referenced.ty),
+        TypeVariants::TyAdt(adt, _) if adt.is_enum() && !ast::is_option_adt(adt) &&
+                                       !ast::is_result_adt(adt) => Some(adt),
+        _ => None,
+    }
+}
+
+pub fn is_enum(ty: Ty) -> bool {
+    enum_adt(ty).is_some()
+}
+
+fn lvalue_type<'a, 'tcx>(lvalue: &Lvalue<'tcx>, data: &MirData<'a, 'tcx>) -> Ty<'tcx> {
+    lvalue.ty(data.mir, data.tcx).to_ty(data.tcx)
+}
+
+pub fn holds_enum(lvalue: &Lvalue, data: &MirData) -> bool {
+    is_enum(lvalue_type(lvalue, data))
+}
+
+fn name(value: &Expression) -> &str {
+    match *value {
+        Expression::VariableMapping(ref name, _) => name,
+        _ => error!("Only enums held in variables and fields are supported, found `{}`", value),
+    }
+}
+
+// The discriminant of `variant`, as the switches of a `match` list it
+pub fn discriminant_value(adt: &AdtDef, variant: usize) -> Expression {
+    gen_integral(&adt.variants[variant].disr_val)
+}
+
+pub fn discriminant(value: &Expression, adt: &AdtDef) -> Expression {
+    Expression::VariableMapping(format!("{}{}", name(value), DISCRIMINANT_SUFFIX),
+                                ast::determine_evaluation_type(&discriminant_value(adt, 0)))
+}
+
+// The path of the fields of `variant` of the enum at `path`
+pub fn variant_path(path: &str, ty: Ty, variant: usize) -> String {
+    match enum_adt(ty) {
+        Some(adt) => format!("{}${}", path, adt.variants[variant].name),
+        None => path.to_string(),
+    }
+}
+
+// The variant an lvalue was cast to, for the fields it projects
+pub fn downcast(lvalue: &Lvalue) -> Option<usize> {
+    match *lvalue {
+        Lvalue::Projection(ref pro) => {
+            match pro.elem {
+                ProjectionElem::Downcast(_, variant) => Some(variant),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+pub fn field_name(ty: Ty, variant: usize, field: Field) -> Option<String> {
+    enum_adt(ty).map(|adt| adt.variants[variant].fields[field.index()].name.as_str().to_string())
+}
+
+pub fn read_discriminant(lvalue: &Lvalue, data: &MirData) -> Expression {
+    let adt = enum_adt(lvalue_type(lvalue, data)).unwrap();
+    discriminant(&gen_lvalue(lvalue.clone(), data), adt)
+}
+
+// The discriminant and every field of the enum at `value`
+fn parts<'a, 'tcx>(value: &Expression,
+                   ty: Ty<'tcx>,
+                   data: &MirData<'a, 'tcx>)
+                   -> Vec<Expression> {
+    let (adt, substs) = match ty.sty {
+        TypeVariants::TyRef(_, ref referenced) => return parts(value, referenced.ty, data),
+        TypeVariants::TyAdt(adt, substs) => (adt, substs),
+        _ => return Vec::new(),
+    };
+    let mut found = vec![discriminant(value, adt)];
+
+    for (index, variant) in adt.variants.iter().enumerate() {
+        let path = variant_path(name(value), ty, index);
+
+        for field in &variant.fields {
+            found.push(Expression::VariableMapping(format!("{}.{}", path, field.name),
+                                                   ast::type_to_enum(field.ty(data.tcx,
+                                                                              substs))));
+        }
+    }
+
+    found
+}
+
+// Applied after the statement's own weakest precondition: constructing an
+// enum sets its discriminant and the fields of its variant, and copying,
+// moving or borrowing a whole enum carries all of its parts along
+pub fn statement(wp: Expression, stmt: &Statement, data: &MirData) -> Expression {
+    match stmt.kind {
+        StatementKind::Assign(ref lvalue, ref rvalue) => {
+            let ty = lvalue_type(lvalue, data);
+            let adt = match enum_adt(ty) {
+                Some(adt) => adt,
+                None => return wp,
+            };
+            let dest = gen_lvalue(lvalue.clone(), data);
+
+            match *rvalue {
+                Rvalue::Aggregate(AggregateKind::Adt(_, variant, _, _), ref operands) => {
+                    let path = variant_path(name(&dest), ty, variant);
+                    let mut wp = wp;
+
+                    for (field, operand) in adt.variants[variant].fields.iter().zip(operands) {
+                        let value = gen_expression(operand, data);
+                        let ty = ast::determine_evaluation_type(&value);
+                        let part = Expression::VariableMapping(format!("{}.{}", path, field.name),
+                                                               ty);
+                        wp = assign(&wp, &part, &value, data);
+                    }
+
+                    assign(&wp,
+                           &discriminant(&dest, adt),
+                           &discriminant_value(adt, variant),
+                           data)
+                }
+                Rvalue::Use(Operand::Consume(ref source)) |
+                Rvalue::Ref(_, _, ref source) => {
+                    let source = gen_lvalue(source.clone(), data);
+
+                    parts(&dest, ty, data)
+                        .iter()
+                        .zip(parts(&source, ty, data).iter())
+                        .fold(wp, |wp, (dest, source)| assign(&wp, dest, source, data))
+                }
+                _ => wp,
+            }
+        }
+        StatementKind::SetDiscriminant { ref lvalue, variant_index } => {
+            let adt = match enum_adt(lvalue_type(lvalue, data)) {
+                Some(adt) => adt,
+                None => return wp,
+            };
+
+            assign(&wp,
+                   &discriminant(&gen_lvalue(lvalue.clone(), data), adt),
+                   &discriminant_value(adt, variant_index),
+                   data)
+        }
+        _ => wp,
+    }
+}
+
+// `value is Variant` in specifications, also for options and results
+pub fn test(value: &Expression, variant: &Expression, data: &MirData) -> Expression {
+    let ty = option::specified_type(value, data)
+        .map(referenced)
+        .unwrap_or_else(|| {
+                            error!("`is` expects an argument, a field or `ret`, found `{}`", value)
+                        });
+    let variant = match *variant {
+        Expression::VariableMapping(ref variant, _) => variant.as_str(),
+        _ => error!("`is` expects the name of a variant, found `{}`", variant),
+    };
+    let not = |expression| Expression::UnaryExpression(UnaryOperator::Not, Rc::new(expression));
+
+    if ast::is_option(ty) {
+        return match variant {
+                   "Some" => option::tag(value),
+                   "None" => not(option::tag(value)),
+                   _ => error!("`Option` has no variant `{}`", variant),
+               };
+    }
+
+    if ast::is_result(ty) {
+        return match variant {
+                   "Ok" => result::tag(value),
+                   "Err" => not(result::tag(value)),
+                   _ => error!("`Result` has no variant `{}`", variant),
+               };
+    }
+
+    let adt = enum_adt(ty).unwrap_or_else(|| error!("`{}` is not an enum", value));
+    let index = adt.variants
+        .iter()
+        .position(|v| &*v.name.as_str() == variant)
+        .unwrap_or_else(|| error!("`{}` has no variant `{}`", ty, variant));
+
+    Expression::BinaryExpression(Rc::new(discriminant(value, adt)),
+                                 BinaryOperator::Equal,
+                                 Rc::new(discriminant_value(adt, index)))
+}
@@ -0,0 +1,325 @@
+use ast::{self, BinaryOperator, Expression, Types, UnaryOperator};
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+
+// Concrete evaluation of prepared verification conditions with Rust's own
+// semantics, to cross-check what the solver proves: a condition it proves
+// valid must hold for every input, so a sampled input falsifying it means
+// the encoding and the intended semantics disagree
+
+#[derive(Clone)]
+enum Value {
+    Bool(bool),
+    Bits(u64, Types),
+    // An array by its root name, with the elements stored into it since
+    Array(String, Types, Vec<(u64, u64)>),
+}
+
+fn size(ty: Types) -> u32 {
+    ::bitvector_size(ty) as u32
+}
+
+fn mask(value: u64, ty: Types) -> u64 {
+    match size(ty) {
+        64 => value,
+        bits => value & ((1u64 << bits) - 1),
+    }
+}
+
+fn signed(value: u64, ty: Types) -> i64 {
+    let shift = 64 - size(ty);
+    ((value << shift) as i64) >> shift
+}
+
+// A small deterministic generator, so a reported input can be reproduced
+struct Samples {
+    state: u64,
+}
+
+impl Samples {
+    fn next(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+
+    // Mostly boundary values, where encodings tend to go wrong
+    fn value(&mut self, ty: Types) -> u64 {
+        if ty == Types::Bool {
+            return self.next() & 1;
+        }
+
+        let bits = size(ty);
+        let min = if ast::is_signed(ty) { 1u64 << (bits - 1) } else { 0 };
+        let boundaries = [0, 1, mask(!0, ty), min, mask(min.wrapping_sub(1), ty), 2];
+
+        match self.next() % 3 {
+            0 => boundaries[(self.next() % boundaries.len() as u64) as usize],
+            1 => mask(self.next() % 16, ty),
+            _ => mask(self.next(), ty),
+        }
+    }
+}
+
+// The variables of an expression with their types
+pub fn variables(expression: &Expression, found: &mut BTreeMap<String, Types>) {
+    match *expression {
+        Expression::BinaryExpression(ref left, _, ref right) => {
+            variables(left, found);
+            variables(right, found);
+        }
+        Expression::UnaryExpression(_, ref expr) |
+        Expression::Forall(_, _, ref expr) => variables(expr, found),
+        Expression::VariableMapping(ref name, ty) => {
+            found.insert(name.clone(), ty);
+        }
+        Expression::Length(ref array) => {
+            found.insert(format!("{}$len", ast::array_name(array)), Types::Usize);
+        }
+        Expression::Select(ref array, ref index) => {
+            variables(array, found);
+            variables(index, found);
+        }
+        Expression::Store(ref array, ref index, ref value) => {
+            variables(array, found);
+            variables(index, found);
+            variables(value, found);
+        }
+        Expression::FunctionCall(_, ref args, _) => {
+            for arg in args {
+                variables(arg, found);
+            }
+        }
+        _ => {}
+    }
+}
+
+struct Input {
+    scalars: BTreeMap<String, u64>,
+    seed: u64,
+}
+
+impl Input {
+    // Unwritten elements of an input array, fixed by the name and index
+    fn element(&self, array: &str, index: u64, ty: Types) -> u64 {
+        let mut samples = Samples {
+            state: array.bytes().fold(self.seed ^ index.wrapping_mul(0x9e3779b97f4a7c15),
+                                      |h, b| (h ^ b as u64).wrapping_mul(0x100000001b3)) | 1,
+        };
+
+        samples.value(ty)
+    }
+}
+
+fn bits(value: Value) -> Option<(u64, Types)> {
+    match value {
+        Value::Bits(bits, ty) => Some((bits, ty)),
+        Value::Bool(b) => Some((b as u64, Types::Bool)),
+        Value::Array(..) => None,
+    }
+}
+
+fn boolean(value: Value) -> Option<bool> {
+    match value {
+        Value::Bool(b) => Some(b),
+        _ => None,
+    }
+}
+
+fn compare(op: BinaryOperator, l: u64, r: u64, ty: Types) -> bool {
+    let ordering = if ast::is_signed(ty) {
+        signed(l, ty).cmp(&signed(r, ty))
+    } else {
+        l.cmp(&r)
+    };
+
+    match op {
+        BinaryOperator::LessThan => ordering == Ordering::Less,
+        BinaryOperator::LessThanOrEqual => ordering != Ordering::Greater,
+        BinaryOperator::GreaterThan => ordering == Ordering::Greater,
+        _ => ordering != Ordering::Less,
+    }
+}
+
+// `None` where Rust leaves the result undefined, like division by zero,
+// so the sample says nothing
+fn arithmetic(op: BinaryOperator, l: u64, r: u64, ty: Types) -> Option<u64> {
+    let result = match op {
+        BinaryOperator::Addition => l.wrapping_add(r),
+        BinaryOperator::Subtraction => l.wrapping_sub(r),
+        BinaryOperator::Multiplication => l.wrapping_mul(r),
+        BinaryOperator::Division | BinaryOperator::Modulo if r == 0 => return None,
+        BinaryOperator::Division if ast::is_signed(ty) => {
+            signed(l, ty).wrapping_div(signed(r, ty)) as u64
+        }
+        BinaryOperator::Modulo if ast::is_signed(ty) => {
+            signed(l, ty).wrapping_rem(signed(r, ty)) as u64
+        }
+        BinaryOperator::Division => l / r,
+        BinaryOperator::Modulo => l % r,
+        BinaryOperator::BitwiseAnd => l & r,
+        BinaryOperator::BitwiseOr => l | r,
+        BinaryOperator::BitwiseXor => l ^ r,
+        BinaryOperator::BitwiseLeftShift | BinaryOperator::BitwiseRightShift
+            if r >= size(ty) as u64 => return None,
+        BinaryOperator::BitwiseLeftShift => l << r,
+        BinaryOperator::BitwiseRightShift if ast::is_signed(ty) => (signed(l, ty) >> r) as u64,
+        BinaryOperator::BitwiseRightShift => l >> r,
+        _ => unreachable!(),
+    };
+
+    Some(mask(result, ty))
+}
+
+fn evaluate(expression: &Expression, input: &Input) -> Option<Value> {
+    match *expression {
+        Expression::BooleanLiteral(b) => Some(Value::Bool(b)),
+        Expression::BitVector(value, ty) => Some(Value::Bits(mask(value as u64, ty), ty)),
+        Expression::VariableMapping(ref name, Types::Bool) => {
+            Some(Value::Bool(input.scalars[name] == 1))
+        }
+        Expression::VariableMapping(ref name, ty) => Some(Value::Bits(input.scalars[name], ty)),
+        Expression::Length(ref array) => {
+            Some(Value::Bits(input.scalars[&format!("{}$len", ast::array_name(array))],
+                             Types::Usize))
+        }
+        Expression::Array(ref name, ty) => Some(Value::Array(name.clone(), ty, Vec::new())),
+        Expression::Store(ref array, ref index, ref value) => {
+            match (evaluate(array, input), evaluate(index, input).and_then(bits)) {
+                (Some(Value::Array(name, ty, mut stored)), Some((index, _))) => {
+                    let (value, _) = match evaluate(value, input).and_then(bits) {
+                        Some(value) => value,
+                        None => return None,
+                    };
+                    stored.retain(|&(i, _)| i != index);
+                    stored.push((index, value));
+                    Some(Value::Array(name, ty, stored))
+                }
+                _ => None,
+            }
+        }
+        Expression::Select(ref array, ref index) => {
+            match (evaluate(array, input), evaluate(index, input).and_then(bits)) {
+                (Some(Value::Array(name, ty, stored)), Some((index, _))) => {
+                    let element = stored.iter()
+                        .find(|&&(i, _)| i == index)
+                        .map_or_else(|| input.element(&name, index, ty), |&(_, value)| value);
+
+                    if ty == Types::Bool {
+                        Some(Value::Bool(element & 1 == 1))
+                    } else {
+                        Some(Value::Bits(element, ty))
+                    }
+                }
+                _ => None,
+            }
+        }
+        Expression::UnaryExpression(ref op, ref expr) => {
+            match (op, evaluate(expr, input)) {
+                (&UnaryOperator::Not, Some(Value::Bool(b))) => Some(Value::Bool(!b)),
+                (&UnaryOperator::Not, Some(Value::Bits(value, ty))) => {
+                    Some(Value::Bits(mask(!value, ty), ty))
+                }
+                (&UnaryOperator::Negation, Some(Value::Bits(value, ty))) => {
+                    Some(Value::Bits(mask(value.wrapping_neg(), ty), ty))
+                }
+                // Narrowing keeps the low bits, as `as` does
+                (&UnaryOperator::Convert(target), Some(Value::Bits(value, ty)))
+                    if size(target) < size(ty) => Some(Value::Bits(mask(value, target), target)),
+                (&UnaryOperator::Convert(target), Some(Value::Bits(value, ty))) => {
+                    let extended = if ast::is_signed(ty) {
+                        signed(value, ty) as u64
+                    } else {
+                        value
+                    };
+                    Some(Value::Bits(mask(extended, target), target))
+                }
+                _ => None,
+            }
+        }
+        Expression::BinaryExpression(ref left, op, ref right) => {
+            match op {
+                BinaryOperator::And | BinaryOperator::Or | BinaryOperator::Implication => {
+                    let l = match evaluate(left, input).and_then(boolean) {
+                        Some(l) => l,
+                        None => return None,
+                    };
+
+                    match (op, l) {
+                        (BinaryOperator::And, false) => return Some(Value::Bool(false)),
+                        (BinaryOperator::Or, true) => return Some(Value::Bool(true)),
+                        (BinaryOperator::Implication, false) => return Some(Value::Bool(true)),
+                        _ => evaluate(right, input),
+                    }
+                }
+                _ => {
+                    let (l, ty) = match evaluate(left, input).and_then(bits) {
+                        Some(l) => l,
+                        None => return None,
+                    };
+                    let (r, _) = match evaluate(right, input).and_then(bits) {
+                        Some(r) => r,
+                        None => return None,
+                    };
+
+                    match op {
+                        BinaryOperator::Equal | BinaryOperator::BiImplication => {
+                            Some(Value::Bool(l == r))
+                        }
+                        BinaryOperator::NotEqual => Some(Value::Bool(l != r)),
+                        BinaryOperator::Xor => Some(Value::Bool(l != r)),
+                        BinaryOperator::LessThan |
+                        BinaryOperator::LessThanOrEqual |
+                        BinaryOperator::GreaterThan |
+                        BinaryOperator::GreaterThanOrEqual => {
+                            Some(Value::Bool(compare(op, l, r, ty)))
+                        }
+                        _ => arithmetic(op, l, r, ty).map(|value| Value::Bits(value, ty)),
+                    }
+                }
+            }
+        }
+        Expression::Float(..) |
+        Expression::FunctionCall(..) |
+        Expression::Forall(..) => None,
+    }
+}
+
+// 128-bit and floating-point values do not fit the samples, so conditions
+// using them are not cross-checked
+fn is_sampled(ty: Types) -> bool {
+    ty != Types::I128 && ty != Types::U128 && !ast::is_float(ty)
+}
+
+// The first of `samples` random inputs falsifying a condition the solver
+// proved valid, as variable assignments
+pub fn counterexample(condition: &Expression, samples: usize) -> Option<Vec<(String, i64)>> {
+    if ast::any_type(condition, &|ty| !is_sampled(ty)) {
+        return None;
+    }
+
+    let mut found = BTreeMap::new();
+    variables(condition, &mut found);
+
+    let mut generator = Samples { state: 0x2545f4914f6cdd1d };
+
+    for _ in 0..samples {
+        let input = Input {
+            scalars: found.iter().map(|(name, &ty)| (name.clone(), generator.value(ty))).collect(),
+            seed: generator.next(),
+        };
+
+        if let Some(Value::Bool(false)) = evaluate(condition, &input) {
+            let shown = |(name, &ty): (&String, &Types)| {
+                let value = input.scalars[name];
+                let value = if ast::is_signed(ty) { signed(value, ty) } else { value as i64 };
+                (name.clone(), value)
+            };
+
+            return Some(found.iter().map(shown).collect());
+        }
+    }
+
+    None
+}
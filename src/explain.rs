@@ -0,0 +1,144 @@
+use {MirData, ledger};
+use ast::{BinaryOperator, Expression};
+use baseline;
+use config::Config;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use summary::Status;
+
+// `explain = "id"` prints everything about one obligation once it is
+// solved: its clause, the MIR it was generated from, the condition handed
+// to the solver, what the proof assumed and the counterexample. The pieces
+// are gathered while the obligation is discharged and printed when its
+// result is recorded
+
+// Conditions are broken at their connectives beyond this width
+const WIDTH: usize = 80;
+
+struct Context {
+    mir: Vec<String>,
+    condition: String,
+    assumptions: Vec<String>,
+    counterexample: Vec<(String, String)>,
+}
+
+thread_local! {
+    static CONTEXTS: RefCell<HashMap<String, Context>> = RefCell::new(HashMap::new());
+    static EXPLAINED: Cell<bool> = Cell::new(false);
+}
+
+pub fn matches(label: &str, config: &Config) -> bool {
+    config.explain.as_ref().map_or(false, |id| *id == baseline::id(label))
+}
+
+fn is_connective(op: BinaryOperator) -> bool {
+    match op {
+        BinaryOperator::And |
+        BinaryOperator::Or |
+        BinaryOperator::Implication |
+        BinaryOperator::BiImplication => true,
+        _ => false,
+    }
+}
+
+// One operand per line, each under the connective joining it to the
+// previous one and indented by its depth
+fn pretty(expression: &Expression, depth: usize, lines: &mut Vec<String>) {
+    let flat = expression.to_string();
+    let indent = "  ".repeat(depth);
+
+    match *expression {
+        Expression::BinaryExpression(ref left, op, ref right) if is_connective(op) &&
+                                                                 indent.len() + flat.len() >
+                                                                 WIDTH => {
+            pretty(left, depth + 1, lines);
+            lines.push(format!("{}{}", indent, op));
+            pretty(right, depth + 1, lines);
+        }
+        _ => lines.push(format!("{}{}", indent, flat)),
+    }
+}
+
+fn mir_lines(data: &MirData) -> Vec<String> {
+    let mut lines = Vec::new();
+
+    for (index, block) in data.block_data.iter().enumerate() {
+        lines.push(format!("bb{}:", index));
+
+        for statement in &block.statements {
+            lines.push(format!("    {:?}", statement));
+        }
+
+        lines.push(format!("    {:?}", block.terminator().kind));
+    }
+
+    lines
+}
+
+// Called with the condition as handed to the solver
+pub fn context(label: &str, condition: &Expression, data: &MirData) {
+    let mut lines = Vec::new();
+    pretty(condition, 0, &mut lines);
+
+    let context = Context {
+        mir: mir_lines(data),
+        condition: lines.join("\n"),
+        assumptions: ledger(data),
+        counterexample: Vec::new(),
+    };
+
+    CONTEXTS.with(|contexts| contexts.borrow_mut().insert(label.to_string(), context));
+}
+
+pub fn counterexample(label: &str, values: Vec<(String, String)>) {
+    CONTEXTS.with(|contexts| if let Some(context) = contexts.borrow_mut().get_mut(label) {
+                      context.counterexample = values;
+                  });
+}
+
+fn section(title: &str, lines: &[String]) {
+    println!("   {}:", title);
+
+    if lines.is_empty() {
+        println!("      (none)");
+    }
+
+    for line in lines {
+        for part in line.lines() {
+            println!("      {}", part);
+        }
+    }
+}
+
+pub fn print(label: &str, status: Status, pre: &str, post: &str) {
+    let context = match CONTEXTS.with(|contexts| contexts.borrow_mut().remove(label)) {
+        Some(context) => context,
+        None => return,
+    };
+    let clause = vec![format!("requires: {}", if pre == "" { "true" } else { pre }),
+                      format!("checks: {}", if post == "" { "the body" } else { post })];
+    let values = context.counterexample
+        .iter()
+        .map(|&(ref name, ref value)| format!("{} = {}", name, value))
+        .collect::<Vec<_>>();
+
+    EXPLAINED.with(|explained| explained.set(true));
+
+    println!("Explanation of `{}` ({:?}): {}", baseline::id(label), status, label);
+    section("clause", &clause);
+    section("MIR", &context.mir);
+    section("verification condition", &[context.condition]);
+    section("assumptions", &context.assumptions);
+    section("counterexample", &values);
+}
+
+// Reports an identifier no obligation of the run has
+pub fn finish(config: &Config) {
+    if let Some(ref id) = config.explain {
+        if !EXPLAINED.with(|explained| explained.get()) {
+            println!("!! no obligation of this run has the identifier `{}`; identifiers are listed \
+                      in baselines and diagnostics",
+                     id);
+        }
+    }
+}
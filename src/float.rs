@@ -0,0 +1,52 @@
+use ast::{self, BinaryOperator, Expression, UnaryOperator};
+use std::rc::Rc;
+
+// Floats are encoded in the theory of floating point, which follows IEEE
+// 754 as Rust does. The classifying methods are spelled out in comparisons,
+// so they mean the same in specifications and in the code
+
+pub const PREDICATES: [&'static str; 3] = ["is_nan", "is_infinite", "is_finite"];
+
+fn binary(left: Expression, op: BinaryOperator, right: Expression) -> Expression {
+    Expression::BinaryExpression(Rc::new(left), op, Rc::new(right))
+}
+
+fn not(expression: Expression) -> Expression {
+    Expression::UnaryExpression(UnaryOperator::Not, Rc::new(expression))
+}
+
+// Whether `x` is `NaN`, infinite or finite, by the name of the method
+pub fn predicate(name: &str, x: &Expression) -> Expression {
+    let ty = ast::determine_evaluation_type(x);
+    let nan = binary(x.clone(), BinaryOperator::NotEqual, x.clone());
+    let infinite = binary(binary(x.clone(),
+                                 BinaryOperator::Equal,
+                                 ast::type_constant(ty, "INFINITY")),
+                          BinaryOperator::Or,
+                          binary(x.clone(),
+                                 BinaryOperator::Equal,
+                                 ast::type_constant(ty, "NEG_INFINITY")));
+
+    match name {
+        "is_nan" => nan,
+        "is_infinite" => infinite,
+        _ => not(binary(nan, BinaryOperator::Or, infinite)),
+    }
+}
+
+// `is_nan`, `is_infinite` and `is_finite` in specifications
+pub fn expand(name: &str, args: &[Expression]) -> Option<Expression> {
+    if !PREDICATES.contains(&name) {
+        return None;
+    }
+
+    if args.len() != 1 {
+        error!("`{}` expects 1 arguments, found {}", name, args.len());
+    }
+
+    if !ast::is_float(ast::determine_evaluation_type(&args[0])) {
+        error!("`{}` expects a float of known type, found `{}`", name, args[0]);
+    }
+
+    Some(predicate(name, &args[0]))
+}
@@ -0,0 +1,427 @@
+use condition_parser;
+use config::{Config, Format};
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::{Read, Write};
+use syntax::ast::Attribute;
+use syntax::codemap::CodeMap;
+
+// `fmt = "check"` or `"write"` formats the conditions of contracts: one
+// space around binary operators and after commas, none inside brackets or
+// before `:` and `.`, no parentheses around whole conditions or single
+// operands, and the clauses of a function in the order below. Formatting
+// never changes what a condition means: text that does not parse the same
+// after formatting is left as written
+
+const CLAUSES: &'static [&'static str] = &["requires", "ensures", "ensures_err", "invariant",
+                                          "variant"];
+
+const SYMBOLS: &'static [&'static str] = &["<=>", "=>", "&&", "||", "==", "!=", "<=", ">=",
+                                           "<<", ">>", "..", "::", "+", "-", "*", "/", "%",
+                                           "<", ">", "&", "|", "^", "!", "(", ")", "[", "]",
+                                           ",", ":", "."];
+
+const BINARY: &'static [&'static str] = &["<=>", "=>", "&&", "||", "==", "!=", "<=", ">=",
+                                          "<<", ">>", "+", "-", "*", "/", "%", "<", ">", "&",
+                                          "|", "^", "XOR"];
+
+const KEYWORDS: &'static [&'static str] = &["forall", "exists", "in", "is", "XOR"];
+
+const TYPES: &'static [&'static str] = &["bool", "i8", "i16", "i32", "i64", "i128", "u8",
+                                         "u16", "u32", "u64", "u128", "usize", "isize", "f32",
+                                         "f64", "char"];
+
+// A clause to rewrite, by its place in its file
+struct Edit {
+    line: usize,
+    end: usize,
+    found: String,
+    formatted: String,
+}
+
+thread_local! {
+    static EDITS: RefCell<BTreeMap<(String, usize), Edit>> = RefCell::new(BTreeMap::new());
+}
+
+#[derive(Clone, PartialEq)]
+enum Token {
+    Word(String),
+    Number(String),
+    Char(String),
+    Symbol(&'static str),
+}
+
+fn take_while<F: Fn(char) -> bool>(chars: &[char], start: usize, f: F) -> usize {
+    let mut end = start;
+
+    while end < chars.len() && f(chars[end]) {
+        end += 1;
+    }
+
+    end
+}
+
+// Digits with an optional fraction and exponent, as the grammar reads them
+fn number_end(chars: &[char], start: usize) -> usize {
+    let digit = |c: char| c.is_digit(10);
+    let mut end = take_while(chars, start, &digit);
+
+    if end + 1 < chars.len() && chars[end] == '.' && digit(chars[end + 1]) {
+        end = take_while(chars, end + 1, &digit);
+    }
+
+    if end < chars.len() && (chars[end] == 'e' || chars[end] == 'E') {
+        let sign = if end + 1 < chars.len() && (chars[end + 1] == '-' || chars[end + 1] == '+') {
+            1
+        } else {
+            0
+        };
+
+        if end + 1 + sign < chars.len() && digit(chars[end + 1 + sign]) {
+            end = take_while(chars, end + 1 + sign, &digit);
+        }
+    }
+
+    end
+}
+
+fn tokenize(text: &str) -> Option<Vec<Token>> {
+    let chars = text.chars().collect::<Vec<_>>();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let rest = chars[i..].iter().cloned().collect::<String>();
+
+        if c.is_whitespace() {
+            i += 1;
+        } else if c.is_alphabetic() {
+            let end = take_while(&chars, i, |c| c.is_alphanumeric() || c == '_');
+            tokens.push(Token::Word(chars[i..end].iter().cloned().collect()));
+            i = end;
+        } else if c.is_digit(10) ||
+                  (c == '-' && i + 1 < chars.len() && chars[i + 1].is_digit(10)) {
+            // A minus sign directly before digits belongs to the literal
+            let end = number_end(&chars, if c == '-' { i + 1 } else { i });
+            tokens.push(Token::Number(chars[i..end].iter().cloned().collect()));
+            i = end;
+        } else if c == '\'' {
+            let mut end = i + 1;
+
+            while end < chars.len() && chars[end] != '\'' {
+                end += if chars[end] == '\\' { 2 } else { 1 };
+            }
+
+            if end >= chars.len() {
+                return None;
+            }
+
+            tokens.push(Token::Char(chars[i..end + 1].iter().cloned().collect()));
+            i = end + 1;
+        } else {
+            let symbol = match SYMBOLS.iter().find(|symbol| rest.starts_with(*symbol)) {
+                Some(symbol) => *symbol,
+                None => return None,
+            };
+
+            tokens.push(Token::Symbol(symbol));
+            i += symbol.len();
+        }
+    }
+
+    Some(tokens)
+}
+
+// Whether a token can end an operand, which makes a following `-` or `*`
+// binary and a following `(` or `[` a call or an index
+fn ends_operand(token: Option<&Token>) -> bool {
+    match token {
+        Some(&Token::Word(ref word)) => !KEYWORDS.contains(&&**word),
+        Some(&Token::Number(_)) |
+        Some(&Token::Char(_)) => true,
+        Some(&Token::Symbol(symbol)) => symbol == ")" || symbol == "]",
+        None => false,
+    }
+}
+
+fn closing(tokens: &[Token], open: usize) -> Option<usize> {
+    let mut depth = 0;
+
+    for (i, token) in tokens.iter().enumerate().skip(open) {
+        match *token {
+            Token::Symbol("(") => depth += 1,
+            Token::Symbol(")") => {
+                depth -= 1;
+
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+// Whether what the parentheses enclose is a single operand
+fn is_operand(tokens: &[Token]) -> bool {
+    let mut depth = 0;
+
+    for token in tokens {
+        match *token {
+            Token::Symbol("(") | Token::Symbol("[") => depth += 1,
+            Token::Symbol(")") | Token::Symbol("]") => depth -= 1,
+            _ if depth > 0 => {}
+            Token::Word(ref word) if KEYWORDS.contains(&&**word) => return false,
+            Token::Symbol(symbol) if BINARY.contains(&symbol) || symbol == "!" ||
+                                     symbol == "," || symbol == ":" => return false,
+            _ => {}
+        }
+    }
+
+    true
+}
+
+fn unparenthesize(mut tokens: Vec<Token>) -> Vec<Token> {
+    while tokens.first() == Some(&Token::Symbol("(")) &&
+          closing(&tokens, 0) == Some(tokens.len() - 1) {
+        tokens = tokens[1..tokens.len() - 1].to_vec();
+    }
+
+    let mut i = 0;
+
+    while i < tokens.len() {
+        if tokens[i] == Token::Symbol("(") && (i == 0 || !ends_operand(tokens.get(i - 1))) {
+            if let Some(end) = closing(&tokens, i) {
+                let doubled = tokens[i + 1] == Token::Symbol("(") &&
+                              closing(&tokens, i + 1) == Some(end - 1);
+
+                if doubled || is_operand(&tokens[i + 1..end]) {
+                    tokens.remove(end);
+                    tokens.remove(i);
+                    continue;
+                }
+            }
+        }
+
+        i += 1;
+    }
+
+    tokens
+}
+
+fn text(token: &Token) -> &str {
+    match *token {
+        Token::Word(ref text) |
+        Token::Number(ref text) |
+        Token::Char(ref text) => text,
+        Token::Symbol(symbol) => symbol,
+    }
+}
+
+fn space(tokens: &[Token], i: usize) -> bool {
+    let (previous, current) = (&tokens[i - 1], &tokens[i]);
+    let binary = |at: usize| {
+        BINARY.contains(&text(&tokens[at])) &&
+        (text(&tokens[at]) != "-" && text(&tokens[at]) != "*" || at > 0 &&
+                                                                 ends_operand(tokens.get(at - 1)))
+    };
+    let unary = |at: usize| {
+        let symbol = text(&tokens[at]);
+        symbol == "!" || (symbol == "-" || symbol == "*") && !binary(at)
+    };
+
+    if binary(i) || binary(i - 1) || *previous == Token::Symbol(",") {
+        return true;
+    }
+
+    match text(current) {
+        "," | ")" | "]" | "." | ".." | "::" | ":" => return false,
+        _ => {}
+    }
+
+    // `- 1` negates a literal, which `-1` would be
+    if unary(i - 1) {
+        return match *current {
+                   Token::Number(_) => text(previous) == "-",
+                   _ => false,
+               };
+    }
+
+    match text(previous) {
+        "(" | "[" | "." | ".." | "::" => return false,
+        // A type annotation, unlike the colon before the body of a quantifier
+        ":" => {
+            let annotation = TYPES.contains(&text(current)) &&
+                             tokens.get(i + 1) != Some(&Token::Symbol("::"));
+            return !annotation;
+        }
+        _ => {}
+    }
+
+    match text(current) {
+        "(" | "[" => !ends_operand(Some(previous)),
+        _ => true,
+    }
+}
+
+fn render(tokens: &[Token]) -> String {
+    let mut out = String::new();
+
+    for (i, token) in tokens.iter().enumerate() {
+        if i > 0 && space(tokens, i) {
+            out.push(' ');
+        }
+
+        out.push_str(text(token));
+    }
+
+    out
+}
+
+fn same_meaning(a: &str, b: &str) -> bool {
+    match (condition_parser::parse_Condition(a), condition_parser::parse_Condition(b)) {
+        (Ok(a), Ok(b)) => format!("{:?}", a) == format!("{:?}", b),
+        _ => false,
+    }
+}
+
+// The formatted condition, or the condition as written when it does not
+// parse or formatting would change its meaning
+pub fn format(condition: &str) -> String {
+    let tokens = match tokenize(condition) {
+        Some(tokens) => tokens,
+        None => return condition.to_string(),
+    };
+
+    let formatted = render(&unparenthesize(tokens.clone()));
+    if same_meaning(condition, &formatted) {
+        return formatted;
+    }
+
+    let spaced = render(&tokens);
+    if same_meaning(condition, &spaced) {
+        return spaced;
+    }
+
+    condition.to_string()
+}
+
+fn rank(attr: &Attribute, config: &Config) -> Option<usize> {
+    CLAUSES.iter().position(|clause| config.recognizes(attr, clause))
+}
+
+// The attribute as written, with its condition formatted
+fn formatted(attr: &Attribute, codemap: &CodeMap) -> Option<String> {
+    let snippet = match codemap.span_to_snippet(attr.span) {
+        Ok(snippet) => snippet,
+        Err(_) => return None,
+    };
+    let (first, last) = match (snippet.find('"'), snippet.rfind('"')) {
+        (Some(first), Some(last)) if first < last => (first, last),
+        _ => return None,
+    };
+    let condition = match attr.value_str() {
+        Some(condition) => format(&condition.as_str()),
+        None => return None,
+    };
+
+    Some(format!("{}{}{}",
+                 &snippet[..first + 1],
+                 condition.replace('\\', "\\\\").replace('"', "\\\""),
+                 &snippet[last..]))
+}
+
+// Records the clauses of a function that are not formatted, or not in
+// order; the clauses keep their places and are moved between them
+pub fn function(attrs: &[Attribute], config: &Config, codemap: &CodeMap) {
+    let clauses = attrs.iter()
+        .filter_map(|attr| rank(attr, config).map(|rank| (rank, attr)))
+        .collect::<Vec<_>>();
+    let mut ordered = clauses.clone();
+    ordered.sort_by_key(|&(rank, _)| rank);
+
+    for (&(_, slot), &(_, clause)) in clauses.iter().zip(ordered.iter()) {
+        let found = match codemap.span_to_snippet(slot.span) {
+            Ok(found) => found,
+            Err(_) => continue,
+        };
+        let text = match formatted(clause, codemap) {
+            Some(text) => text,
+            None => continue,
+        };
+
+        if text == found {
+            continue;
+        }
+
+        let start = codemap.lookup_byte_offset(slot.span.lo);
+        let end = codemap.lookup_byte_offset(slot.span.hi);
+        let edit = Edit {
+            line: codemap.lookup_char_pos(slot.span.lo).line,
+            end: end.pos.0 as usize,
+            found: found,
+            formatted: text,
+        };
+
+        EDITS.with(|edits| {
+                       edits.borrow_mut().insert((start.fm.name.clone(), start.pos.0 as usize),
+                                                 edit)
+                   });
+    }
+}
+
+fn write_file(path: &str, edits: &[(usize, &Edit)]) {
+    let mut source = String::new();
+    File::open(path)
+        .and_then(|mut file| file.read_to_string(&mut source))
+        .unwrap_or_else(|e| error!("Cannot read `{}`: {}", path, e));
+
+    // From the end, so earlier offsets stay valid
+    for &(start, edit) in edits.iter().rev() {
+        source = format!("{}{}{}", &source[..start], edit.formatted, &source[edit.end..]);
+    }
+
+    let partial = format!("{}.partial", path);
+    File::create(&partial)
+        .and_then(|mut file| file.write_all(source.as_bytes()))
+        .and_then(|_| fs::rename(&partial, path))
+        .unwrap_or_else(|e| error!("Cannot write `{}`: {}", path, e));
+}
+
+// Rewrites the clauses recorded, or with `check`, lists them and fails
+pub fn finish(mode: Format) {
+    EDITS.with(|edits| {
+        let edits = edits.borrow();
+        let mut files = BTreeMap::new();
+
+        for (&(ref file, start), edit) in edits.iter() {
+            files.entry(file.clone()).or_insert_with(Vec::new).push((start, edit));
+        }
+
+        for (file, edits) in &files {
+            match mode {
+                Format::Check => {
+                    for &(_, edit) in edits {
+                        println!("!! [UNFORMATTED] -- {}:{}: `{}` should read `{}`",
+                                 file,
+                                 edit.line,
+                                 edit.found,
+                                 edit.formatted);
+                    }
+                }
+                Format::Write => {
+                    write_file(file, edits);
+                    println!("   note: formatted {} clauses in `{}`", edits.len(), file);
+                }
+            }
+        }
+
+        if mode == Format::Check && !edits.is_empty() {
+            error!("{} clauses are not formatted; `fmt = \"write\"` formats them", edits.len());
+        }
+    });
+}
@@ -0,0 +1,275 @@
+use ast::{self, BinaryOperator, Expression, Types, UnaryOperator};
+use config::{Config, Solver};
+use parallel::{self, Outcome};
+use referenced;
+use regex::Regex;
+use rustc::mir::Mir;
+use rustc::ty::{Ty, TyCtxt, TypeVariants};
+use std::collections::BTreeMap;
+use std::io::{Result, Write};
+use syntax::ast::Attribute;
+
+// With `integers = "mathematical"`, obligations are solved over the
+// mathematical integers instead of bitvectors, which the solver handles
+// much faster for arithmetic. Every variable is known to lie in the range
+// of its type, but arithmetic never wraps: the model relies on arithmetic
+// not overflowing. Functions marked `exact_bitwidth`, functions whose
+// arguments or result have a type marked `exact_bitwidth`, and conditions
+// using bitwise operators, shifts or conversions keep the bitvector model
+
+// Whether the function needs exact bitvector reasoning, being marked or
+// taking or returning a marked type
+pub fn exact<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
+                       attrs: &[Attribute],
+                       mir: &Mir<'tcx>,
+                       config: &Config)
+                       -> bool {
+    let marked = |ty: Ty<'tcx>| match referenced(ty).sty {
+        TypeVariants::TyAdt(adt, _) => {
+            tcx.get_attrs(adt.did).iter().any(|attr| config.recognizes(attr, "exact_bitwidth"))
+        }
+        _ => false,
+    };
+
+    attrs.iter().any(|attr| config.recognizes(attr, "exact_bitwidth")) ||
+    mir.args_iter().map(|arg| mir.local_decls[arg].ty).chain(Some(mir.return_ty)).any(marked)
+}
+
+fn is_integer(ty: Types) -> bool {
+    match ty {
+        Types::Bool | Types::Void | Types::Unknown | Types::F32 | Types::F64 => false,
+        _ => true,
+    }
+}
+
+// Whether the condition means the same over the integers, as long as
+// nothing overflows
+pub fn expressible(condition: &Expression) -> bool {
+    match *condition {
+        Expression::BinaryExpression(ref left, op, ref right) => {
+            let boolean = ast::determine_evaluation_type(left) == Types::Bool;
+            let bitwise = match op {
+                BinaryOperator::BitwiseOr |
+                BinaryOperator::BitwiseAnd |
+                BinaryOperator::BitwiseXor => !boolean,
+                BinaryOperator::BitwiseLeftShift |
+                BinaryOperator::BitwiseRightShift => true,
+                _ => false,
+            };
+
+            !bitwise && expressible(left) && expressible(right)
+        }
+        Expression::UnaryExpression(UnaryOperator::Not, ref expr) => {
+            ast::determine_evaluation_type(expr) == Types::Bool && expressible(expr)
+        }
+        Expression::UnaryExpression(UnaryOperator::Negation, ref expr) => expressible(expr),
+        Expression::Length(ref expr) => expressible(expr),
+        Expression::Select(ref array, ref index) => expressible(array) && expressible(index),
+        Expression::Store(ref array, ref index, ref value) => {
+            expressible(array) && expressible(index) && expressible(value)
+        }
+        Expression::VariableMapping(_, ty) => !ast::is_float(ty),
+        Expression::Array(_, element) => !ast::is_float(element),
+        Expression::BitVector(..) |
+        Expression::BooleanLiteral(_) => true,
+        Expression::UnaryExpression(UnaryOperator::Convert(_), _) |
+        Expression::Float(..) |
+        Expression::FunctionCall(..) |
+        Expression::Forall(..) => false,
+    }
+}
+
+fn sort(ty: Types) -> &'static str {
+    if ty == Types::Bool { "Bool" } else { "Int" }
+}
+
+// The smallest and largest value of an integer type, as SMT-LIB terms
+fn bounds(ty: Types) -> (String, String) {
+    let size = ::bitvector_size(ty);
+
+    if ast::is_signed(ty) {
+        let half = 1u128 << (size - 1);
+        (format!("(- {})", half), (half - 1).to_string())
+    } else if size == 128 {
+        ("0".to_string(), u128::max_value().to_string())
+    } else {
+        ("0".to_string(), ((1u128 << size) - 1).to_string())
+    }
+}
+
+fn declarations(expression: &Expression, found: &mut BTreeMap<String, (String, Types)>) {
+    match *expression {
+        Expression::BinaryExpression(ref left, _, ref right) |
+        Expression::Select(ref left, ref right) => {
+            declarations(left, found);
+            declarations(right, found);
+        }
+        Expression::UnaryExpression(_, ref expr) => declarations(expr, found),
+        Expression::VariableMapping(ref name, ty) => {
+            found.insert(name.clone(), (sort(ty).to_string(), ty));
+        }
+        // Elements are not known to be in range, which only loses proofs
+        Expression::Array(ref name, element) => {
+            found.insert(name.clone(),
+                         (format!("(Array Int {})", sort(element)), Types::Unknown));
+        }
+        Expression::Length(ref array) => {
+            found.insert(format!("{}$len", ast::array_name(array)),
+                         ("Int".to_string(), Types::Usize));
+        }
+        Expression::Store(ref array, ref index, ref value) => {
+            declarations(array, found);
+            declarations(index, found);
+            declarations(value, found);
+        }
+        _ => {}
+    }
+}
+
+fn application<W: Write>(out: &mut W, function: &str, args: &[&Expression]) -> Result<()> {
+    write!(out, "({}", function)?;
+
+    for arg in args {
+        write!(out, " ")?;
+        term(out, arg)?;
+    }
+
+    write!(out, ")")
+}
+
+// Rust's signed division and remainder truncate towards zero, while those
+// of the integers round the quotient down
+fn truncated<W: Write>(out: &mut W,
+                       op: BinaryOperator,
+                       left: &Expression,
+                       right: &Expression)
+                       -> Result<()> {
+    let function = if op == BinaryOperator::Division { "div" } else { "mod" };
+
+    write!(out, "(let (($a ")?;
+    term(out, left)?;
+    write!(out, ") ($b ")?;
+    term(out, right)?;
+    write!(out, ")) (let (($q ({} (abs $a) (abs $b)))) ", function)?;
+
+    if op == BinaryOperator::Division {
+        write!(out, "(ite (= (< $a 0) (< $b 0)) $q (- $q))))")
+    } else {
+        write!(out, "(ite (< $a 0) (- $q) $q)))")
+    }
+}
+
+fn operator(op: BinaryOperator) -> &'static str {
+    match op {
+        BinaryOperator::Addition => "+",
+        BinaryOperator::Subtraction => "-",
+        BinaryOperator::Multiplication => "*",
+        BinaryOperator::Division => "div",
+        BinaryOperator::Modulo => "mod",
+        BinaryOperator::BitwiseOr | BinaryOperator::Or => "or",
+        BinaryOperator::BitwiseAnd | BinaryOperator::And => "and",
+        BinaryOperator::BitwiseXor | BinaryOperator::Xor => "xor",
+        BinaryOperator::LessThan => "<",
+        BinaryOperator::LessThanOrEqual => "<=",
+        BinaryOperator::GreaterThan => ">",
+        BinaryOperator::GreaterThanOrEqual => ">=",
+        BinaryOperator::Equal | BinaryOperator::BiImplication => "=",
+        BinaryOperator::NotEqual => "distinct",
+        BinaryOperator::Implication => "=>",
+        // Shifts are not expressible
+        BinaryOperator::BitwiseLeftShift | BinaryOperator::BitwiseRightShift => unreachable!(),
+    }
+}
+
+fn term<W: Write>(out: &mut W, expression: &Expression) -> Result<()> {
+    match *expression {
+        Expression::BinaryExpression(ref left, op, ref right) => {
+            if let Some(lowered) = ast::boolean_ordering(left, op, right) {
+                return term(out, &lowered);
+            }
+
+            match op {
+                BinaryOperator::Division |
+                BinaryOperator::Modulo if ast::is_signed(ast::determine_evaluation_type(left)) => {
+                    truncated(out, op, left, right)
+                }
+                _ => application(out, operator(op), &[left, right]),
+            }
+        }
+        Expression::UnaryExpression(UnaryOperator::Negation, ref expr) => {
+            application(out, "-", &[expr])
+        }
+        Expression::UnaryExpression(_, ref expr) => application(out, "not", &[expr]),
+        Expression::VariableMapping(ref name, _) |
+        Expression::Array(ref name, _) => write!(out, "|{}|", name),
+        Expression::Length(ref array) => write!(out, "|{}$len|", ast::array_name(array)),
+        Expression::Select(ref array, ref index) => application(out, "select", &[array, index]),
+        Expression::Store(ref array, ref index, ref value) => {
+            application(out, "store", &[array, index, value])
+        }
+        Expression::BooleanLiteral(value) => write!(out, "{}", value),
+        // Literals keep 64 bits, extended by their signedness
+        Expression::BitVector(value, ty) if ast::is_signed(ty) && value < 0 => {
+            write!(out, "(- {})", -(value as i128))
+        }
+        Expression::BitVector(value, ty) if ast::is_signed(ty) => write!(out, "{}", value),
+        Expression::BitVector(value, ty) => {
+            let size = ::bitvector_size(ty);
+            let mask = if size >= 64 { !0 } else { (1u64 << size) - 1 };
+            write!(out, "{}", value as u64 & mask)
+        }
+        // Not expressible
+        _ => unreachable!(),
+    }
+}
+
+// A complete script checking the validity of `condition` over the
+// integers, which is valid when the script is unsat
+pub fn write<W: Write>(out: &mut W, label: &str, condition: &Expression) -> Result<()> {
+    writeln!(out, "; {}", label)?;
+    writeln!(out, "(set-logic AUFNIA)")?;
+
+    let mut found = BTreeMap::new();
+    declarations(condition, &mut found);
+
+    for (name, &(ref sort, ty)) in &found {
+        writeln!(out, "(declare-fun |{}| () {})", name, sort)?;
+
+        if is_integer(ty) {
+            let (lo, hi) = bounds(ty);
+            writeln!(out, "(assert (<= {} |{}| {}))", lo, name, hi)?;
+        }
+    }
+
+    write!(out, "(assert (not ")?;
+    term(out, condition)?;
+    writeln!(out, "))")?;
+    writeln!(out, "(check-sat)")
+}
+
+// Non-negative values of the model in hexadecimal, like those of
+// bitvectors; negative ones are left out
+fn model(text: &str) -> Vec<(String, String)> {
+    let re = Regex::new(r"\(define-fun \|?([^|\s]+)\|? \(\) Int\s+([0-9]+)\)").unwrap();
+
+    re.captures_iter(text)
+        .filter_map(|cap| {
+                        cap[2].parse::<u64>()
+                            .ok()
+                            .map(|value| (cap[1].to_string(), format!("{:x}", value)))
+                    })
+        .collect()
+}
+
+pub fn solve(label: &str, condition: &Expression, solver: &Solver) -> Outcome {
+    let mut script = Vec::new();
+    write(&mut script, label, condition)
+        .and_then(|_| writeln!(script, "(get-model)"))
+        .unwrap_or_else(|e| error!("Cannot encode `{}`: {}", label, e));
+
+    match parallel::run(&script, solver) {
+        Ok(ref output) if output.starts_with("unsat") => Outcome::Valid,
+        Ok(ref output) if output.starts_with("sat") => Outcome::Invalid(model(output)),
+        Ok(output) | Err(output) => Outcome::Error(output),
+    }
+}
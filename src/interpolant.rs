@@ -0,0 +1,163 @@
+use {MirData, gen_expression, gen_stmt, is_valid, source_name};
+use abduction;
+use ast::{self, BinaryOperator, Expression, Types};
+use invariant;
+use rustc::mir::*;
+use std::rc::Rc;
+
+// When a loop proof only holds up to the unrolling bound, an invariant cut
+// at the header would make it unbounded. A Craig interpolant between the
+// runs reaching the header and the obligation after it is such a cut, but
+// the solver interface gives no interpolants, so candidates of the shapes
+// they usually take stand in for them: the loop guard relaxed to hold on
+// leaving too, as `i <= n` for `while i < n`, alone and with the sign and
+// constant comparisons of abduction, and those comparisons alone. Each is
+// checked as a given invariant would be, and the first proving the function
+// for any number of iterations is suggested
+
+// Candidates checked at most, as each takes a proof of the whole function
+const MAX_CANDIDATES: usize = 64;
+
+fn binary(left: Expression, op: BinaryOperator, right: Expression) -> Expression {
+    Expression::BinaryExpression(Rc::new(left), op, Rc::new(right))
+}
+
+// The condition of staying in the loop, on the state at the header
+fn guard(header: usize, data: &MirData) -> Option<Expression> {
+    let block = data.block_data[header];
+    let continues = match block.terminator().kind {
+        TerminatorKind::SwitchInt { ref discr, ref values, .. } if values.len() == 1 => {
+            // The only value given is that of `false`, which leaves
+            gen_expression(discr, data)
+        }
+        _ => return None,
+    };
+
+    if ast::determine_evaluation_type(&continues) != Types::Bool {
+        return None;
+    }
+
+    Some(block.statements
+             .iter()
+             .rev()
+             .fold(continues, |condition, stmt| gen_stmt(condition, stmt.clone(), data)))
+}
+
+// The comparisons of the guard that still hold when it fails last
+fn relaxed(guard: &Expression) -> Vec<Expression> {
+    match *guard {
+        Expression::BinaryExpression(ref left, op, ref right) => {
+            let (left, right) = ((**left).clone(), (**right).clone());
+
+            match op {
+                BinaryOperator::LessThan => {
+                    vec![binary(left, BinaryOperator::LessThanOrEqual, right)]
+                }
+                BinaryOperator::GreaterThan => {
+                    vec![binary(left, BinaryOperator::GreaterThanOrEqual, right)]
+                }
+                BinaryOperator::NotEqual => {
+                    vec![binary(left.clone(), BinaryOperator::LessThanOrEqual, right.clone()),
+                         binary(left, BinaryOperator::GreaterThanOrEqual, right)]
+                }
+                BinaryOperator::And => {
+                    relaxed(&left).into_iter().chain(relaxed(&right)).collect()
+                }
+                _ => Vec::new(),
+            }
+        }
+        _ => Vec::new(),
+    }
+}
+
+// A candidate as written in a specification, unless it names a variable the
+// source does not have
+fn written(candidate: &Expression, data: &MirData) -> Option<Expression> {
+    match *candidate {
+        Expression::BinaryExpression(ref left, op, ref right) => {
+            match (written(left, data), written(right, data)) {
+                (Some(left), Some(right)) => Some(binary(left, op, right)),
+                _ => None,
+            }
+        }
+        Expression::UnaryExpression(ref op, ref expr) => {
+            written(expr, data).map(|expr| Expression::UnaryExpression(op.clone(), Rc::new(expr)))
+        }
+        Expression::VariableMapping(ref name, ty) => {
+            source_name(name, data).map(|name| Expression::VariableMapping(name, ty))
+        }
+        Expression::BitVector(..) |
+        Expression::Float(..) |
+        Expression::BooleanLiteral(_) => Some(candidate.clone()),
+        _ => None,
+    }
+}
+
+fn variables(expression: &Expression, found: &mut Vec<Expression>) {
+    match *expression {
+        Expression::BinaryExpression(ref left, _, ref right) => {
+            variables(left, found);
+            variables(right, found);
+        }
+        Expression::UnaryExpression(_, ref expr) => variables(expr, found),
+        Expression::VariableMapping(..) => {
+            if !found.contains(expression) {
+                found.push(expression.clone());
+            }
+        }
+        _ => {}
+    }
+}
+
+fn candidates(guard: &Expression, pre: &Expression, post: &Expression) -> Vec<Expression> {
+    let mut guard_variables = Vec::new();
+    variables(guard, &mut guard_variables);
+    let comparisons = abduction::candidates(&guard_variables, &[guard, pre, post]);
+    let relaxed = relaxed(guard);
+
+    let mut candidates = relaxed.clone();
+
+    for relaxed in &relaxed {
+        for comparison in &comparisons {
+            candidates.push(binary(relaxed.clone(), BinaryOperator::And, comparison.clone()));
+        }
+    }
+
+    candidates.extend(comparisons);
+    candidates.truncate(MAX_CANDIDATES);
+    candidates
+}
+
+// Suggests an invariant of the only loop of `name` under which it is proved
+// for any number of iterations
+pub fn suggest(name: &str, pre: &Expression, post: &Expression, data: &MirData) {
+    let header = match invariant::single_header(data.mir) {
+        Some(header) if data.invariant.is_none() => header,
+        _ => return,
+    };
+    let guard = match guard(header, data) {
+        Some(guard) => guard,
+        None => return,
+    };
+
+    for candidate in candidates(&guard, pre, post) {
+        let text = match written(&candidate, data) {
+            Some(text) => text,
+            None => continue,
+        };
+
+        let invariant = invariant::at(header, candidate, data);
+        let cut = data.with_composition(data.composition).with_invariant(Rc::new(invariant));
+        let verification_condition = binary(pre.clone(),
+                                            BinaryOperator::Implication,
+                                            cut.weakest_precondition(post));
+
+        if is_valid(&verification_condition, &cut) && !cut.truncated.get() {
+            println!("   note: {} is proved for any number of iterations with \
+                      `#[invariant = \"{}\"]`",
+                     name,
+                     text);
+            return;
+        }
+    }
+}
@@ -0,0 +1,287 @@
+use {MirData, gen_block, gen_lvalue, local_name, parse_condition, walk_and_replace};
+use ast::{self, BinaryOperator, Expression, Types};
+use rustc::mir::*;
+use rustc_data_structures::indexed_vec::Idx;
+use std::rc::Rc;
+use theory;
+use variant;
+
+// A loop invariant cuts the loop at its header. The weakest precondition of
+// the body requires the invariant wherever a path reaches the header, and
+// the loop itself is proved from the header for an arbitrary state
+// satisfying it: every path back to the header must restore it, and every
+// path leaving the loop must establish the postcondition. The variables the
+// loop assigns take fresh names in that state, so the proof of the loop
+// knows nothing about them beyond the invariant, while entry values and
+// the variables the loop leaves alone keep theirs
+
+const LOOP_SUFFIX: &'static str = "$loop";
+
+pub struct Invariant {
+    pub header: usize,
+    pub condition: Expression,
+    // The variables the loop assigns, by their names in conditions
+    modified: Vec<String>,
+}
+
+fn successors(mir: &Mir, block: usize) -> Vec<usize> {
+    mir.basic_blocks()[BasicBlock::new(block)]
+        .terminator()
+        .successors()
+        .iter()
+        .map(|successor| successor.index())
+        .collect()
+}
+
+// The targets of back edges, found by a depth-first search from the entry
+fn headers(mir: &Mir) -> Vec<usize> {
+    let count = mir.basic_blocks().len();
+    let mut visited = vec![false; count];
+    let mut on_stack = vec![false; count];
+    let mut stack = vec![(0, 0)];
+    let mut found = Vec::new();
+
+    visited[0] = true;
+    on_stack[0] = true;
+
+    while let Some((block, next)) = stack.pop() {
+        let targets = successors(mir, block);
+
+        if next == targets.len() {
+            on_stack[block] = false;
+            continue;
+        }
+
+        stack.push((block, next + 1));
+        let target = targets[next];
+
+        if on_stack[target] {
+            if !found.contains(&target) {
+                found.push(target);
+            }
+        } else if !visited[target] {
+            visited[target] = true;
+            on_stack[target] = true;
+            stack.push((target, 0));
+        }
+    }
+
+    found
+}
+
+// The blocks on some path from the header back to it
+fn body(mir: &Mir, header: usize) -> Vec<usize> {
+    let count = mir.basic_blocks().len();
+    let mut reached = vec![false; count];
+    let mut work = vec![header];
+
+    while let Some(block) = work.pop() {
+        for target in successors(mir, block) {
+            if !reached[target] {
+                reached[target] = true;
+                work.push(target);
+            }
+        }
+    }
+
+    // Those of the reached blocks from which the header is reached again
+    let mut returns = vec![false; count];
+    returns[header] = true;
+    let mut changed = true;
+
+    while changed {
+        changed = false;
+
+        for block in 0..count {
+            if reached[block] && !returns[block] &&
+               successors(mir, block).iter().any(|&target| returns[target]) {
+                returns[block] = true;
+                changed = true;
+            }
+        }
+    }
+
+    (0..count).filter(|&block| returns[block]).collect()
+}
+
+fn root(lvalue: &Lvalue) -> Option<Local> {
+    match *lvalue {
+        Lvalue::Local(local) => Some(local),
+        Lvalue::Static(_) => None,
+        Lvalue::Projection(ref projection) => root(&projection.base),
+    }
+}
+
+fn add(lvalue: &Lvalue, data: &MirData, found: &mut Vec<String>) {
+    if let Some(local) = root(lvalue) {
+        let name = local_name(local, data);
+
+        if !found.contains(&name) {
+            found.push(name);
+        }
+    }
+}
+
+fn modified(header: usize, data: &MirData) -> Vec<String> {
+    let mut found = Vec::new();
+
+    for block in body(data.mir, header) {
+        let block = data.block_data[block];
+
+        for stmt in &block.statements {
+            match stmt.kind {
+                StatementKind::Assign(ref lvalue, ref rvalue) => {
+                    add(lvalue, data, &mut found);
+
+                    // What is borrowed mutably may be written through the borrow
+                    if let Rvalue::Ref(_, BorrowKind::Mut, ref borrowed) = *rvalue {
+                        add(borrowed, data, &mut found);
+                    }
+                }
+                StatementKind::SetDiscriminant { ref lvalue, .. } => add(lvalue, data, &mut found),
+                _ => {}
+            }
+        }
+
+        if let TerminatorKind::Call { destination: Some((ref lvalue, _)), .. } =
+            block.terminator().kind {
+            add(lvalue, data, &mut found);
+        }
+    }
+
+    found
+}
+
+// The header of the only loop of a body, if it has exactly one
+pub fn single_header(mir: &Mir) -> Option<usize> {
+    let headers = headers(mir);
+
+    if headers.len() == 1 { Some(headers[0]) } else { None }
+}
+
+// `condition`, already resolved, as the invariant of the loop at `header`
+pub fn at(header: usize, condition: Expression, data: &MirData) -> Invariant {
+    Invariant {
+        header: header,
+        modified: modified(header, data),
+        condition: condition,
+    }
+}
+
+pub fn loops(mir: &Mir) -> usize {
+    headers(mir).len()
+}
+
+// A condition of the function `name` at its loop, with the user variables it
+// names resolved to the locals holding them
+pub fn resolve_locals(name: &str, condition: &str, data: &MirData) -> Expression {
+    let mut expression = parse_condition(condition.to_string());
+
+    for local in data.mir.vars_iter() {
+        let var = match data.mir.local_decls[local].name {
+            Some(var) => var.as_str().to_string(),
+            None => continue,
+        };
+
+        if data.mir.vars_iter().filter(|&other| other != local).any(|other| {
+            data.mir.local_decls[other].name.map_or(false, |n| *n.as_str() == *var)
+        }) {
+            error!("`{}` names several variables of {}; rename one to use it in a condition",
+                   var,
+                   name);
+        }
+
+        expression = theory::bind(&expression, &var, &gen_lvalue(Lvalue::Local(local), data));
+    }
+
+    let condition = walk_and_replace(expression, data);
+    ast::ty_check(&condition).unwrap_or_else(|e| error!("{}", e));
+    condition
+}
+
+// The invariant of the only loop of the function `name`
+pub fn resolve(name: &str, condition: &str, data: &MirData) -> Invariant {
+    let headers = headers(data.mir);
+    let header = match headers.len() {
+        0 => error!("{} has an `invariant` but no loop", name),
+        1 => headers[0],
+        loops => {
+            error!("{} has {} loops; `invariant` is only supported for a single loop",
+                   name,
+                   loops)
+        }
+    };
+
+    let condition = resolve_locals(name, condition, data);
+
+    if ast::determine_evaluation_type(&condition) != Types::Bool {
+        error!("The invariant `{}` of {} must be boolean", condition, name);
+    }
+
+    at(header, condition, data)
+}
+
+fn is_modified(name: &str, invariant: &Invariant) -> bool {
+    let base = &name[..name.find(|c| c == '$' || c == '.').unwrap_or(name.len())];
+    !name.contains(ast::OLD_SUFFIX) && invariant.modified.iter().any(|modified| modified == base)
+}
+
+// Gives the variables the loop assigns their names in the arbitrary state,
+// which is also the state the variant decreases from
+fn havoc(expression: &Expression, invariant: &Invariant) -> Expression {
+    let rename = |name: &String| {
+        let name = name.replace(variant::HEAD_SUFFIX, "");
+
+        if is_modified(&name, invariant) {
+            name + LOOP_SUFFIX
+        } else {
+            name
+        }
+    };
+
+    match *expression {
+        Expression::BinaryExpression(ref left, op, ref right) => {
+            Expression::BinaryExpression(Rc::new(havoc(left, invariant)),
+                                         op,
+                                         Rc::new(havoc(right, invariant)))
+        }
+        Expression::UnaryExpression(ref op, ref expr) => {
+            Expression::UnaryExpression(op.clone(), Rc::new(havoc(expr, invariant)))
+        }
+        Expression::VariableMapping(ref name, ty) => Expression::VariableMapping(rename(name), ty),
+        Expression::Array(ref name, ty) => Expression::Array(rename(name), ty),
+        Expression::FunctionCall(ref name, ref args, ty) => {
+            Expression::FunctionCall(name.clone(),
+                                     args.iter().map(|arg| havoc(arg, invariant)).collect(),
+                                     ty)
+        }
+        Expression::Length(ref array) => Expression::Length(Rc::new(havoc(array, invariant))),
+        Expression::Select(ref array, ref index) => {
+            Expression::Select(Rc::new(havoc(array, invariant)), Rc::new(havoc(index, invariant)))
+        }
+        Expression::Store(ref array, ref index, ref value) => {
+            Expression::Store(Rc::new(havoc(array, invariant)),
+                              Rc::new(havoc(index, invariant)),
+                              Rc::new(havoc(value, invariant)))
+        }
+        Expression::Forall(ref var, ty, ref body) => {
+            Expression::Forall(rename(var), ty, Rc::new(havoc(body, invariant)))
+        }
+        Expression::BitVector(..) |
+        Expression::Float(..) |
+        Expression::BooleanLiteral(_) => expression.clone(),
+    }
+}
+
+// That the loop preserves its invariant and establishes `post` on leaving,
+// from an arbitrary state at the header satisfying the invariant
+pub fn preserved(invariant: &Invariant, post: &Expression, data: &MirData) -> Expression {
+    data.path.borrow_mut().push(invariant.header);
+    let wp = gen_block(invariant.header, 0, data, post);
+    data.path.borrow_mut().pop();
+
+    havoc(&Expression::BinaryExpression(Rc::new(invariant.condition.clone()),
+                                        BinaryOperator::Implication,
+                                        Rc::new(wp)),
+          invariant)
+}
@@ -0,0 +1,205 @@
+use ast::{self, BinaryOperator, Expression, Types};
+use config::Solver;
+use rustproof_libsmt::backends::smtlib2::SMTRes;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+// A library of arithmetic lemmas the solver has trouble finding through
+// nonlinear bit-level arithmetic: division and remainder bounds, the
+// distributivity of multiplication, products bounded by their factors and
+// the midpoint of a sum. Every term of a matching shape in an obligation
+// gets its instance of the lemma, which like those of midpoints is proved
+// on its own first and only then assumed. Instances are proved once per
+// run
+
+// Instances proved for one obligation, beyond which its terms get none
+const MAX_INSTANCES: usize = 32;
+
+thread_local! {
+    static PROVED: RefCell<HashMap<Expression, bool>> = RefCell::new(HashMap::new());
+}
+
+fn binary(left: Expression, op: BinaryOperator, right: Expression) -> Expression {
+    Expression::BinaryExpression(Rc::new(left), op, Rc::new(right))
+}
+
+fn is_literal(expression: &Expression, value: i64) -> bool {
+    match *expression {
+        Expression::BitVector(v, _) => v == value,
+        _ => false,
+    }
+}
+
+fn is_unsigned(expression: &Expression) -> bool {
+    match ast::determine_evaluation_type(expression) {
+        Types::Bool | Types::Void | Types::Unknown | Types::F32 | Types::F64 => false,
+        ty => !ast::is_signed(ty),
+    }
+}
+
+fn is_integer(expression: &Expression) -> bool {
+    match ast::determine_evaluation_type(expression) {
+        Types::Bool | Types::Void | Types::Unknown | Types::F32 | Types::F64 => false,
+        _ => true,
+    }
+}
+
+fn nonzero(divisor: &Expression) -> Expression {
+    let zero = Expression::BitVector(0, ast::determine_evaluation_type(divisor));
+    binary(divisor.clone(), BinaryOperator::NotEqual, zero)
+}
+
+fn add(found: &mut Vec<Expression>, lemma: Expression) {
+    if !found.contains(&lemma) {
+        found.push(lemma);
+    }
+}
+
+// For unsigned `a / b`: the quotient is at most `a`, and multiplied back
+// by `b` it does not exceed `a`
+fn quotient(a: &Expression, b: &Expression, term: &Expression, found: &mut Vec<Expression>) {
+    add(found,
+        binary(nonzero(b),
+               BinaryOperator::Implication,
+               binary(term.clone(), BinaryOperator::LessThanOrEqual, a.clone())));
+    add(found,
+        binary(nonzero(b),
+               BinaryOperator::Implication,
+               binary(binary(term.clone(), BinaryOperator::Multiplication, b.clone()),
+                      BinaryOperator::LessThanOrEqual,
+                      a.clone())));
+}
+
+// For unsigned `a % b`: the remainder is below `b` and at most `a`
+fn remainder(a: &Expression, b: &Expression, term: &Expression, found: &mut Vec<Expression>) {
+    add(found,
+        binary(nonzero(b),
+               BinaryOperator::Implication,
+               binary(binary(term.clone(), BinaryOperator::LessThan, b.clone()),
+                      BinaryOperator::And,
+                      binary(term.clone(), BinaryOperator::LessThanOrEqual, a.clone()))));
+}
+
+// Multiplication distributes over addition and subtraction, wrapping or
+// not
+fn distributive(a: &Expression, b: &Expression, term: &Expression, found: &mut Vec<Expression>) {
+    let spread = |factor: &Expression, sum: &Expression, left: bool| match *sum {
+        Expression::BinaryExpression(ref x, op, ref y) if op == BinaryOperator::Addition ||
+                                                          op == BinaryOperator::Subtraction => {
+            let times = |z: &Expression| if left {
+                binary(factor.clone(), BinaryOperator::Multiplication, z.clone())
+            } else {
+                binary(z.clone(), BinaryOperator::Multiplication, factor.clone())
+            };
+            Some(binary(term.clone(), BinaryOperator::Equal, binary(times(x), op, times(y))))
+        }
+        _ => None,
+    };
+
+    for lemma in spread(a, b, true).into_iter().chain(spread(b, a, false)) {
+        add(found, lemma);
+    }
+}
+
+// For an unsigned product that does not wrap, each nonzero factor is at
+// most the product
+fn product(a: &Expression, b: &Expression, term: &Expression, found: &mut Vec<Expression>) {
+    for &(factor, other) in &[(a, b), (b, a)] {
+        let exact = binary(binary(term.clone(), BinaryOperator::Division, other.clone()),
+                           BinaryOperator::Equal,
+                           factor.clone());
+        add(found,
+            binary(binary(nonzero(other), BinaryOperator::And, exact),
+                   BinaryOperator::Implication,
+                   binary(factor.clone(), BinaryOperator::LessThanOrEqual, term.clone())));
+    }
+}
+
+// `(a + b) / 2`, also halved by a shift, lies between `a` and `b` when the
+// sum does not wrap
+fn halved_sum(sum: &Expression, term: &Expression, found: &mut Vec<Expression>) {
+    let (a, b) = match *sum {
+        Expression::BinaryExpression(ref a, BinaryOperator::Addition, ref b) => (&**a, &**b),
+        _ => return,
+    };
+    let exact = binary(a.clone(), BinaryOperator::LessThanOrEqual, sum.clone());
+
+    for &(lo, hi) in &[(a, b), (b, a)] {
+        let ordered = binary(lo.clone(), BinaryOperator::LessThanOrEqual, hi.clone());
+        add(found,
+            binary(binary(ordered, BinaryOperator::And, exact.clone()),
+                   BinaryOperator::Implication,
+                   binary(binary(lo.clone(), BinaryOperator::LessThanOrEqual, term.clone()),
+                          BinaryOperator::And,
+                          binary(term.clone(), BinaryOperator::LessThanOrEqual, hi.clone()))));
+    }
+}
+
+fn candidates(expression: &Expression, found: &mut Vec<Expression>) {
+    if let Expression::BinaryExpression(ref a, op, ref b) = *expression {
+        match op {
+            BinaryOperator::Division if is_unsigned(a) => {
+                quotient(a, b, expression, found);
+
+                if is_literal(b, 2) {
+                    halved_sum(a, expression, found);
+                }
+            }
+            BinaryOperator::BitwiseRightShift if is_unsigned(a) && is_literal(b, 1) => {
+                halved_sum(a, expression, found);
+            }
+            BinaryOperator::Modulo if is_unsigned(a) => remainder(a, b, expression, found),
+            BinaryOperator::Multiplication if is_integer(a) => {
+                distributive(a, b, expression, found);
+
+                if is_unsigned(a) {
+                    product(a, b, expression, found);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    match *expression {
+        Expression::BinaryExpression(ref left, _, ref right) => {
+            candidates(left, found);
+            candidates(right, found);
+        }
+        Expression::UnaryExpression(_, ref expr) |
+        Expression::Length(ref expr) => candidates(expr, found),
+        Expression::Select(ref array, ref index) => {
+            candidates(array, found);
+            candidates(index, found);
+        }
+        Expression::Store(ref array, ref index, ref value) => {
+            candidates(array, found);
+            candidates(index, found);
+            candidates(value, found);
+        }
+        _ => {}
+    }
+}
+
+fn proved(lemma: &Expression, solver: &Solver) -> bool {
+    if let Some(proved) = PROVED.with(|proved| proved.borrow().get(lemma).cloned()) {
+        return proved;
+    }
+
+    let proved = match ::solve_prepared(&ast::simplify_expression(lemma), solver) {
+        SMTRes::Unsat(..) => true,
+        _ => false,
+    };
+
+    PROVED.with(|cache| cache.borrow_mut().insert(lemma.clone(), proved));
+    proved
+}
+
+// The instances of the library for the terms of a prepared condition that
+// hold
+pub fn lemmas(condition: &Expression, solver: &Solver) -> Vec<Expression> {
+    let mut found = Vec::new();
+    candidates(condition, &mut found);
+
+    found.into_iter().take(MAX_INSTANCES).filter(|lemma| proved(lemma, solver)).collect()
+}
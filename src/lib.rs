@@ -1,4 +1,4 @@
-#![feature(plugin_registrar, rustc_private)]
+#![feature(i128_type, plugin_registrar, rustc_private)]
 
 #[macro_export]
 macro_rules! error {
@@ -28,127 +28,2005 @@ extern crate rustc_plugin;
 extern crate rustc_trans;
 extern crate rustc_data_structures;
 extern crate rustc_const_math;
+extern crate rustc_mir;
 
 use ast::{BinaryOperator, Expression, Types, UnaryOperator};
+use config::{ATTRIBUTES, Config, DebugAssertions, Emit, Integers, Lint, Solver, Stage,
+             Unfinished};
+use obligation::ObligationGenerator;
+use parallel::Outcome;
+use self_composition::Composition;
+use summary::{StableContract, Status, Summary};
+use theory::Theory;
 use petgraph::graph::NodeIndex;
-use regex::Regex;
+use rustc::hir;
+use rustc::hir::def_id::DefId;
+use rustc::hir::map as hir_map;
 use rustc::middle::const_val::ConstVal;
 use rustc::mir::*;
 use rustc::mir::transform::{MirPass, MirSource, Pass};
-use rustc::ty::{TyCtxt, TypeVariants};
-use rustc_const_math::ConstInt;
+use rustc::session::Session;
+use rustc::ty::{Ty, TyCtxt, TypeVariants};
+use rustc::ty::subst::Subst;
+use rustc_const_math::{ConstFloat, ConstInt, ConstIsize, ConstUsize};
 use rustc_data_structures::indexed_vec::Idx;
+use rustc_mir::transform::copy_prop::CopyPropagation;
+use rustc_mir::transform::deaggregator::Deaggregator;
+use rustc_mir::transform::instcombine::InstCombine;
+use rustc_mir::transform::simplify::{SimplifyCfg, SimplifyLocals};
+use rustc_mir::transform::simplify_branches::SimplifyBranches;
 use rustc_plugin::Registry;
 use rustproof_libsmt::backends::backend::*;
 use rustproof_libsmt::backends::smtlib2::*;
-use rustproof_libsmt::backends::z3;
 use rustproof_libsmt::logics::qf_aufbv::*;
-use rustproof_libsmt::theories::{bitvec, core};
+use rustproof_libsmt::theories::{array_ex, bitvec, core};
 
+use std::cell::{Cell, RefCell};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::fmt::Debug;
-use syntax::ast::{Attribute, MetaItemKind, NestedMetaItemKind};
+use std::fs;
+use std::path::Path;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+use syntax::ast::{Attribute, NodeId, UintTy};
+use syntax::codemap::{CodeMap, Span};
 use syntax::feature_gate::AttributeType;
 
-mod ast;
+mod abduction;
+mod arithmetic;
+mod arrays;
+mod assertions;
+mod assume;
+pub mod ast;
+mod atomic;
+mod baseline;
+mod borrows;
+mod builtins;
+mod cast;
+mod checks;
 mod condition_parser;
+mod config;
+mod conflicts;
+mod contract;
+mod debug;
+mod diagnostics;
+mod enums;
+mod eval;
+mod explain;
+mod float;
+mod formatter;
+mod integers;
+mod interpolant;
+mod invariant;
+mod lemmas;
+mod lock;
+mod manifest;
+mod midpoint;
+mod mutation;
+mod numeric;
+pub mod obligation;
+mod option;
+mod ordering;
+mod parallel;
+mod pledge;
+mod proof;
+mod quantifier;
+mod report;
+mod result;
+mod self_composition;
+mod smtlib;
+mod spans;
+mod structs;
+mod summary;
+pub mod theory;
+mod thread;
+mod uninit;
+mod variant;
+
+struct StanleyMir {
+    config: Rc<Config>,
+    generators: Vec<Box<ObligationGenerator>>,
+    theories: Rc<Vec<Theory>>,
+    previous: HashMap<String, Summary>,
+    summaries: Vec<Summary>,
+    // What produced this run's results, and the obligations an interrupted
+    // earlier run proved, with their costs and hashes
+    producer: String,
+    resumed: HashMap<String, (Status, u64, String)>,
+    instantiations: HashSet<String>,
+    // Results of solved obligations, keyed by their normalized form
+    solved: HashMap<Expression, (Status, String)>,
+    // Recorded stable contracts of public functions, and those seen this run
+    stable: HashMap<String, StableContract>,
+    stable_seen: HashSet<String>,
+    // When verification started, and what each label's obligation cost
+    started: Instant,
+    costs: HashMap<String, u64>,
+    skipped: usize,
+    // Obligations left out by the filter
+    filtered: usize,
+    // What the proved obligations took for granted, with the labels of
+    // those relying on each
+    ledger: BTreeMap<String, BTreeSet<String>>,
+    // Public functions whose contracts were proved
+    verified: Vec<manifest::Entry>,
+}
+
+pub struct MirData<'a, 'tcx: 'a> {
+    tcx: TyCtxt<'a, 'tcx, 'tcx>,
+    block_data: Vec<&'a BasicBlockData<'tcx>>,
+    mir: &'a Mir<'tcx>,
+    composition: Composition,
+    theories: Rc<Vec<Theory>>,
+    config: Rc<Config>,
+    // Set when unrolling was cut off, making any proof only bounded
+    truncated: Cell<bool>,
+    // Set when atomics were assumed sequentially consistent
+    approximated_atomics: Cell<bool>,
+    // Set when a path reached `unimplemented!()`
+    unfinished: Cell<bool>,
+    // Blocks on the path being generated, so revisiting one is a loop
+    // iteration rather than another branch joining it
+    path: RefCell<Vec<usize>>,
+    // Whether calls to any function of the crate are inlined, not only to
+    // `const fn`s
+    inline_local: bool,
+    // Whether integer casts must not truncate
+    check_casts: bool,
+    // Whether obligations may be solved over the mathematical integers
+    mathematical_integers: bool,
+    // How debug assertions are treated in this body
+    debug_assertions: DebugAssertions,
+    // Spans of the calls marked `#[assume_pre]`, and the assumptions made
+    assumed_calls: Rc<Vec<Span>>,
+    assumptions: RefCell<Vec<String>>,
+    // A block whose reachability is checked: reaching it is the only failure
+    probe: Option<usize>,
+    // Whether the failure probed is instead that of the check ending the
+    // block
+    probe_check: bool,
+    // The source names of user variables by their names in conditions;
+    // `None` for compiler temporaries
+    source_names: Rc<HashMap<String, Option<String>>>,
+    // The invariant cutting the loop of the function, if one was given
+    invariant: Option<Rc<invariant::Invariant>>,
+    // The variant of a termination check
+    variant: Option<Rc<variant::Variant>>,
+}
+
+impl<'a, 'tcx> MirData<'a, 'tcx> {
+    pub fn tcx(&self) -> TyCtxt<'a, 'tcx, 'tcx> {
+        self.tcx
+    }
+
+    pub fn mir(&self) -> &'a Mir<'tcx> {
+        self.mir
+    }
+
+    pub fn weakest_precondition(&self, post_expression: &Expression) -> Expression {
+        let wp = gen(0, 0, self, post_expression);
+        let wp = match self.invariant {
+            Some(ref invariant) => {
+                Expression::BinaryExpression(Rc::new(wp),
+                                             BinaryOperator::And,
+                                             Rc::new(invariant::preserved(invariant,
+                                                                          post_expression,
+                                                                          self)))
+            }
+            None => wp,
+        };
+
+        ast::resolve_old(&wp)
+    }
+
+    pub fn resolve_condition(&self, condition: &str) -> Expression {
+        let expression = walk_and_replace(parse_condition(condition.to_string()), self);
+        ast::ty_check(&expression).unwrap_or_else(|e| error!("{}", e));
+        expression
+    }
+
+    fn new(tcx: TyCtxt<'a, 'tcx, 'tcx>,
+           mir: &'a Mir<'tcx>,
+           theories: Rc<Vec<Theory>>,
+           config: Rc<Config>)
+           -> MirData<'a, 'tcx> {
+        let source_names = mir.local_decls
+            .indices()
+            .filter_map(|local| match mir.local_kind(local) {
+                LocalKind::Var => {
+                    Some((gen_name!("var", local),
+                          mir.local_decls[local].name.map(|name| name.as_str().to_string())))
+                }
+                LocalKind::Temp => Some((gen_name!("tmp", local), None)),
+                _ => None,
+            })
+            .collect();
+        let debug_assertions = config.debug_assertions;
+
+        MirData {
+            tcx: tcx,
+            block_data: mir.basic_blocks().iter().collect(),
+            mir: mir,
+            composition: Composition::Single,
+            theories: theories,
+            config: config,
+            truncated: Cell::new(false),
+            approximated_atomics: Cell::new(false),
+            unfinished: Cell::new(false),
+            path: RefCell::new(Vec::new()),
+            inline_local: false,
+            check_casts: false,
+            mathematical_integers: false,
+            debug_assertions: debug_assertions,
+            assumed_calls: Rc::new(Vec::new()),
+            assumptions: RefCell::new(Vec::new()),
+            probe: None,
+            probe_check: false,
+            source_names: Rc::new(source_names),
+            invariant: None,
+            variant: None,
+        }
+    }
+
+    fn with_local_inlining(mut self) -> MirData<'a, 'tcx> {
+        self.inline_local = true;
+        self
+    }
+
+    fn with_cast_checks(mut self) -> MirData<'a, 'tcx> {
+        self.check_casts = true;
+        self
+    }
+
+    fn with_mathematical_integers(mut self) -> MirData<'a, 'tcx> {
+        self.mathematical_integers = true;
+        self
+    }
+
+    fn with_debug_assertions(mut self, mode: DebugAssertions) -> MirData<'a, 'tcx> {
+        self.debug_assertions = mode;
+        self
+    }
+
+    fn with_assumed_calls(mut self, calls: Rc<Vec<Span>>) -> MirData<'a, 'tcx> {
+        self.assumed_calls = calls;
+        self
+    }
+
+    fn with_invariant(mut self, invariant: Rc<invariant::Invariant>) -> MirData<'a, 'tcx> {
+        self.invariant = Some(invariant);
+        self
+    }
+
+    fn with_variant(mut self, variant: Rc<variant::Variant>) -> MirData<'a, 'tcx> {
+        self.variant = Some(variant);
+        self
+    }
+
+    fn with_composition(&self, composition: Composition) -> MirData<'a, 'tcx> {
+        MirData {
+            tcx: self.tcx,
+            block_data: self.block_data.clone(),
+            mir: self.mir,
+            composition: composition,
+            theories: self.theories.clone(),
+            config: self.config.clone(),
+            truncated: Cell::new(false),
+            approximated_atomics: Cell::new(false),
+            unfinished: Cell::new(false),
+            path: RefCell::new(Vec::new()),
+            inline_local: self.inline_local,
+            check_casts: self.check_casts,
+            mathematical_integers: self.mathematical_integers,
+            debug_assertions: self.debug_assertions,
+            assumed_calls: self.assumed_calls.clone(),
+            assumptions: RefCell::new(Vec::new()),
+            probe: self.probe,
+            probe_check: self.probe_check,
+            source_names: self.source_names.clone(),
+            // The invariant names the variables of a single run
+            invariant: if composition == Composition::Single {
+                self.invariant.clone()
+            } else {
+                None
+            },
+            variant: if composition == Composition::Single {
+                self.variant.clone()
+            } else {
+                None
+            },
+        }
+    }
+}
+
+impl StanleyMir {
+    fn new(config: Config, extensions: Extensions) -> StanleyMir {
+        let producer = config.producer();
+        let previous = match config.diff {
+            Some(ref path) => {
+                let mut previous = summary::load(path);
+                let written_by = summary::producer(path);
+
+                // Timings and results of another encoder or solver say little
+                // about this one, so they are compared but never relied on
+                if written_by.as_ref() != Some(&producer) {
+                    println!("   note: `{}` was written by {}, not {}; its solver costs are \
+                              ignored",
+                             path,
+                             written_by.unwrap_or("an older Stanley".to_string()),
+                             producer);
+
+                    for summary in previous.values_mut() {
+                        summary.cost = 0;
+                    }
+                }
+
+                previous
+            }
+            None => HashMap::new(),
+        };
+
+        let resumed = match config.summary {
+            Some(ref path) => summary::load_journal(&summary::journal(path), &producer),
+            None => HashMap::new(),
+        };
+
+        if !resumed.is_empty() {
+            println!("   note: resuming an interrupted run, {} obligations were already solved",
+                     resumed.len());
+        }
+
+        let stable = match config.contracts {
+            Some(ref path) if Path::new(path).exists() => summary::load_stable(path),
+            _ => HashMap::new(),
+        };
+
+        StanleyMir {
+            config: Rc::new(config),
+            generators: extensions.generators,
+            theories: Rc::new(extensions.theories),
+            previous: previous,
+            summaries: Vec::new(),
+            producer: producer,
+            resumed: resumed,
+            instantiations: HashSet::new(),
+            solved: HashMap::new(),
+            stable: stable,
+            stable_seen: HashSet::new(),
+            started: Instant::now(),
+            costs: HashMap::new(),
+            skipped: 0,
+            filtered: 0,
+            ledger: BTreeMap::new(),
+            verified: Vec::new(),
+        }
+    }
+
+    fn record(&mut self, label: String, status: Status, pre: &str, post: &str) {
+        if let Some(previous) = self.previous.get(&label) {
+            if previous.status == Status::Valid && status != Status::Valid &&
+               status != Status::Skipped {
+                println!("!! [REGRESSED] -- {} ({:?} -> {:?})", label, previous.status, status);
+            }
+        }
+
+        let cost = self.costs.get(&label).cloned().unwrap_or(0);
+
+        if explain::matches(&label, &self.config) {
+            explain::print(&label, status, pre, post);
+        }
+
+        self.summaries.push(Summary {
+                                label: label,
+                                status: status,
+                                pre: pre.to_string(),
+                                post: post.to_string(),
+                                cost: cost,
+                            });
+    }
+
+    fn discharge(&mut self,
+                 label: &str,
+                 verification_condition: &Expression,
+                 data: &MirData)
+                 -> Status {
+        if let Some(ref filter) = self.config.filter {
+            if !baseline::id(label).contains(&filter[..]) {
+                println!("[SKIPPED] -- {} (filter)", label);
+                self.filtered += 1;
+                return Status::Skipped;
+            }
+        }
+
+        let normalized = ast::normalize_variables(&ast::simplify_expression(verification_condition),
+                                                  &mut HashMap::new());
+
+        // The obligation being explained is always solved again, so its
+        // context and counterexample are at hand
+        let explained = explain::matches(label, &self.config);
+
+        if explained &&
+           (self.solved.contains_key(&normalized) || self.resumed.contains_key(label)) {
+            println!("   note: solving `{}` again to explain it", label);
+        }
+
+        // Cloned, so the ledger can be entered while the result is at hand
+        let solved = if explained { None } else { self.solved.get(&normalized).cloned() };
+
+        if let Some((status, first)) = solved {
+            match status {
+                Status::Valid => println!("[VALID] -- {} (same obligation as {})", label, first),
+                Status::Invalid => {
+                    println!("!! [INVALID] -- {} (same obligation as {})", label, first)
+                }
+                Status::Error => println!("[ERROR] -- {} (same obligation as {})", label, first),
+                Status::Skipped => unreachable!(),
+            }
+
+            if status == Status::Valid {
+                self.enter_ledger(label, data);
+            }
+
+            return status;
+        }
+
+        let hash = manifest::hash(format!("{:?}", normalized).bytes());
+
+        // Only proofs are taken over, so failures are solved again and
+        // report their counterexamples
+        let resumed = if explained { None } else { self.resumed.get(label).cloned() };
+
+        if let Some((Status::Valid, cost, resumed)) = resumed {
+            if resumed == hash {
+                println!("[VALID] -- {} (resumed)", label);
+                self.enter_ledger(label, data);
+                self.costs.insert(label.to_string(), cost);
+                self.solved.insert(normalized, (Status::Valid, label.to_string()));
+                return Status::Valid;
+            }
+        }
+
+        if !explained && self.over_budget(label) {
+            println!("[SKIPPED] -- {} (budget)", label);
+            self.skipped += 1;
+            return Status::Skipped;
+        }
+
+        let started = Instant::now();
+        let status = discharge(label, verification_condition, data);
+        let cost = milliseconds(started.elapsed());
+        self.costs.insert(label.to_string(), cost);
+        self.solved.insert(normalized, (status, label.to_string()));
+
+        if let Some(ref path) = self.config.summary {
+            summary::append_journal(&summary::journal(path),
+                                    &self.producer,
+                                    label,
+                                    status,
+                                    cost,
+                                    &hash);
+        }
+
+        if status == Status::Valid {
+            self.enter_ledger(label, data);
+        }
+
+        status
+    }
+
+    fn enter_ledger(&mut self, label: &str, data: &MirData) {
+        for assumption in ledger(data) {
+            self.ledger.entry(assumption).or_insert_with(BTreeSet::new).insert(label.to_string());
+        }
+    }
+
+    // With a budget, an obligation is skipped once it is spent, or when it
+    // took longer than what is left in the previous run. Obligations that
+    // did not hold then are always attempted while time remains, and skipping
+    // the slow ones leaves room for the cheap ones after them
+    fn over_budget(&self, label: &str) -> bool {
+        let budget = match self.config.budget {
+            Some(seconds) => seconds * 1000,
+            None => return false,
+        };
+        let remaining = budget.saturating_sub(milliseconds(self.started.elapsed()));
+        let expected = match self.previous.get(label) {
+            Some(previous) if previous.status == Status::Valid => previous.cost,
+            _ => 0,
+        };
+
+        remaining == 0 || expected > remaining
+    }
+
+    fn diff_contract(&self, name: &str, pre: &Expression, post: &Expression, data: &MirData) {
+        let previous = match self.previous.get(name) {
+            Some(previous) if previous.post != "" => previous,
+            _ => return,
+        };
+
+        let (strengthened, weakened) =
+            contract_changes(&previous.pre, &previous.post, pre, post, data);
+
+        if strengthened {
+            println!("!! [CONTRACT] -- {}: precondition strengthened from `{}`",
+                     name,
+                     previous.pre);
+        }
+
+        if weakened {
+            println!("!! [CONTRACT] -- {}: postcondition weakened from `{}`",
+                     name,
+                     previous.post);
+        }
+    }
+
+    fn check_stability(&mut self,
+                       name: &str,
+                       since: String,
+                       pre: (&str, &Expression),
+                       post: (&str, &Expression),
+                       data: &MirData) {
+        self.stable_seen.insert(name.to_string());
+
+        let recorded = match self.stable.get(name) {
+            Some(recorded) => recorded.clone(),
+            None => {
+                self.stable.insert(name.to_string(),
+                                   StableContract {
+                                       name: name.to_string(),
+                                       since: since,
+                                       pre: pre.0.to_string(),
+                                       post: post.0.to_string(),
+                                   });
+                return;
+            }
+        };
+
+        let (strengthened, weakened) =
+            contract_changes(&recorded.pre, &recorded.post, pre.1, post.1, data);
+
+        if strengthened {
+            println!("!! [SEMVER] -- {}: precondition strengthened since {} (breaking change)",
+                     name,
+                     recorded.since);
+        }
+
+        if weakened {
+            println!("!! [SEMVER] -- {}: postcondition weakened since {} (breaking change)",
+                     name,
+                     recorded.since);
+        }
+
+        if !strengthened && !weakened && (recorded.pre != pre.0 || recorded.post != post.0) {
+            println!("   [SEMVER] -- {}: contract changed compatibly since {}",
+                     name,
+                     recorded.since);
+        }
+    }
+
+    fn verify_instantiations<'a, 'tcx>(&mut self, tcx: TyCtxt<'a, 'tcx, 'tcx>, mir: &Mir<'tcx>) {
+        for block in mir.basic_blocks() {
+            if let TerminatorKind::Call { ref func, .. } = block.terminator().kind {
+                let (def_id, substs) = match *func {
+                    Operand::Constant(ref c) => {
+                        match c.literal {
+                            Literal::Value { value: ConstVal::Function(def_id, substs) } => {
+                                (def_id, substs)
+                            }
+                            _ => continue,
+                        }
+                    }
+                    _ => continue,
+                };
+
+                if !def_id.is_local() || substs.types().next().is_none() {
+                    continue;
+                }
+
+                let instance = format!("{}::<{}>",
+                                       tcx.item_path_str(def_id),
+                                       substs.types()
+                                           .map(|ty| ty.to_string())
+                                           .collect::<Vec<_>>()
+                                           .join(", "));
+
+                if self.instantiations.contains(&instance) {
+                    continue;
+                }
+
+                self.instantiations.insert(instance.clone());
+
+                let node_id = tcx.hir.as_local_node_id(def_id).unwrap();
+                let generic_mir = tcx.item_mir(def_id);
+                let instance_mir = tcx.erase_regions(&generic_mir.subst(tcx, substs));
+
+                self.verify(tcx,
+                            MirSource::Fn(node_id),
+                            &instance,
+                            tcx.hir.attrs(node_id),
+                            &instance_mir);
+            }
+        }
+    }
+
+    fn verify<'a, 'tcx>(&mut self,
+                        tcx: TyCtxt<'a, 'tcx, 'tcx>,
+                        src: MirSource,
+                        name: &str,
+                        attrs: &[Attribute],
+                        mir: &Mir<'tcx>) {
+        let name = name.to_string();
+        let codemap = tcx.sess.codemap();
+        let (mut pre_string, mut post_string) = parse_attributes(attrs, &self.config, codemap);
+
+        // Without a contract, not panicking is checked under any input
+        if pre_string == "" && post_string == "" && no_panic(attrs, &self.config, codemap) {
+            pre_string = "true".to_string();
+            post_string = "true".to_string();
+        }
+
+        let invariant_clause = parse_clause(attrs, "invariant", &self.config, codemap);
+        let variant_clause = parse_clause(attrs, "variant", &self.config, codemap);
+
+        if attrs.iter().any(|attr| self.config.recognizes(attr, "condition")) {
+            println!("   note: `#[condition]` on {} is deprecated; use `#[requires]`, \
+                      `#[ensures]`, `#[ensures_err]`, `#[invariant]`, \
+                      `#[variant]` and `#[no_panic]`",
+                     name);
+        }
+
+        conflicts::check(&name, attrs, &self.config, codemap);
+
+        let secrets = parse_constant_time(attrs, &self.config, codemap);
+        let flows = parse_flows(attrs, &self.config, codemap);
+        let deterministic = attrs.iter().any(|attr| self.config.recognizes(attr, "deterministic"));
+        let reference = attrs.iter()
+            .find(|attr| self.config.recognizes(attr, "equiv_to"))
+            .map(|attr| {
+                     attr.value_str()
+                         .unwrap_or_else(|| error!("Expected `#[equiv_to = \"path\"]`"))
+                         .to_string()
+                 });
+        let stable_since = parse_contract_stable(attrs, &self.config, codemap);
+        let pledge = attrs.iter()
+            .find(|attr| self.config.recognizes(attr, "pledge"))
+            .map(|attr| {
+                     Clause {
+                         text: string_value(attr, "pledge"),
+                         span: attr.span,
+                     }
+                 });
+
+        let history = history_invariants(tcx, mir, &self.config);
+        let releases_lock = lock::releases(tcx, mir);
+        let harness = parse_harness(attrs, &self.config, codemap);
+        let check_casts = self.config.truncation != Lint::Allow && cast::has_lossy_casts(tcx, mir);
+
+        if (pre_string == "" || post_string == "") && variant_clause.is_none() &&
+           secrets.is_none() && flows.is_empty() && !deterministic && reference.is_none() &&
+           pledge.is_none() && history.is_empty() && !releases_lock && harness.is_none() &&
+           !check_casts && self.generators.is_empty() && !assertions::any(mir) {
+            return;
+        }
+
+        if self.config.diagnostics.is_some() {
+            diagnostics::locate(&name, tcx.hir.span(src.item_id()), codemap);
+        }
+
+        let expect_fail = attrs.iter().any(|attr| self.config.recognizes(attr, "expect_fail"));
+        let first = self.summaries.len();
+
+        let mut optimized_mir;
+        let mir = match self.config.stage {
+            Stage::Built => mir,
+            Stage::Optimized => {
+                optimized_mir = mir.clone();
+                optimize(tcx, src, &mut optimized_mir);
+                &optimized_mir
+            }
+        };
+
+        let assumed_calls = Rc::new(assume::marked_calls(tcx, src.item_id(), &self.config));
+        let debug_assertions = debug::mode(attrs, &self.config);
+        let data = MirData::new(tcx, mir, self.theories.clone(), self.config.clone())
+            .with_assumed_calls(assumed_calls.clone())
+            .with_debug_assertions(debug_assertions);
+        let data = if self.config.integers == Integers::Mathematical &&
+                      !integers::exact(tcx, attrs, mir, &self.config) {
+            data.with_mathematical_integers()
+        } else {
+            data
+        };
+        let (pre_clauses, post_clauses) = contract_clauses(attrs, &self.config, codemap);
+
+        for clause in pre_clauses.iter().chain(post_clauses.iter()) {
+            check_clause(clause, &data, tcx.sess);
+        }
+
+        for clause in invariant_clause.iter().chain(variant_clause.iter()) {
+            parse_clause_syntax(clause, tcx.sess);
+        }
+
+        let data = match invariant_clause {
+            Some(ref invariant) => {
+                let invariant = invariant::resolve(&name, &invariant.text, &data);
+                data.with_invariant(Rc::new(invariant))
+            }
+            None => data,
+        };
+
+        let mut pre_string_expression = Expression::BooleanLiteral(true);
+
+        if pre_string != "" {
+            pre_string_expression = walk_and_replace(parse_condition(pre_string.clone()), &data);
+            ast::ty_check(&pre_string_expression).unwrap_or_else(|e| error!("{}", e));
+        }
+
+        if post_string != "" {
+            let mut post_string_expression = parse_condition(post_string.clone());
+            post_string_expression = walk_and_replace(post_string_expression, &data);
+            ast::ty_check(&post_string_expression).unwrap_or_else(|e| error!("{}", e));
+
+            let weakest_precondition = data.weakest_precondition(&post_string_expression);
+
+            let verification_condition =
+                Expression::BinaryExpression(Rc::new(pre_string_expression.clone()),
+                                             ast::BinaryOperator::Implication,
+                                             Rc::new(weakest_precondition.clone()));
+
+            let status = self.discharge(&name, &verification_condition, &data);
+
+            if status == Status::Valid && data.truncated.get() {
+                println!("   note: loops were only unrolled to a bounded depth; add \
+                          `#[invariant = \"...\"]` for an unbounded proof");
+                interpolant::suggest(&name,
+                                     &pre_string_expression,
+                                     &post_string_expression,
+                                     &data);
+            }
+
+            if status == Status::Invalid {
+                checks::report(&name, &pre_string_expression, &data);
+                suggest_precondition(&pre_string_expression,
+                                     &post_string_expression,
+                                     &weakest_precondition,
+                                     &data);
+            }
+
+            self.diff_contract(&name, &pre_string_expression, &post_string_expression, &data);
+
+            if let Some(since) = stable_since {
+                if is_public(tcx, src.item_id()) {
+                    self.check_stability(&name,
+                                         since,
+                                         (&pre_string, &pre_string_expression),
+                                         (&post_string, &post_string_expression),
+                                         &data);
+                } else {
+                    println!("   note: `contract_stable` on private function {} has no effect",
+                             name);
+                }
+            }
+
+            if status == Status::Valid && self.config.manifest.is_some() &&
+               is_public(tcx, src.item_id()) {
+                self.verified.push(manifest::Entry {
+                                       name: name.clone(),
+                                       pre: pre_string.clone(),
+                                       post: post_string.clone(),
+                                       assumptions: ledger(&data),
+                                   });
+            }
+
+            if self.config.mutate && status == Status::Valid {
+                mutation_score(&name, &pre_string_expression, &post_string_expression, &data);
+            }
+
+            self.record(name.clone(), status, &pre_string, &post_string);
+        }
+
+        if let Some(ref variant) = variant_clause {
+            // A loop without an invariant is only known by its guard
+            let termination = data.with_composition(Composition::Single);
+            let termination = if termination.invariant.is_none() && invariant::loops(mir) > 0 {
+                let invariant = invariant::resolve(&name, "true", &termination);
+                termination.with_invariant(Rc::new(invariant))
+            } else {
+                termination
+            };
+            let resolved = variant::resolve(&name,
+                                            &variant.text,
+                                            tcx.hir.local_def_id(src.item_id()),
+                                            &termination);
+            let termination = termination.with_variant(Rc::new(resolved));
+
+            let weakest_precondition =
+                termination.weakest_precondition(&Expression::BooleanLiteral(true));
+            let verification_condition =
+                Expression::BinaryExpression(Rc::new(pre_string_expression.clone()),
+                                             ast::BinaryOperator::Implication,
+                                             Rc::new(weakest_precondition));
+
+            let label = format!("{} (termination)", name);
+            let status = self.discharge(&label, &verification_condition, &termination);
+            self.record(label, status, &pre_string, &variant.text);
+        }
+
+        if self.config.dead_branches {
+            report_dead_branches(&name, &pre_string_expression, &data);
+        }
+
+        if let Some(secrets) = secrets {
+            let public_inputs = self_composition::public_arguments(&data, &secrets);
+            let weakest_precondition = gen(0,
+                                           0,
+                                           &data.with_composition(Composition::Lockstep),
+                                           &Expression::BooleanLiteral(true));
+
+            let verification_condition =
+                Expression::BinaryExpression(Rc::new(self_composition::product_precondition(&pre_string_expression,
+                                                                                            &public_inputs)),
+                                             ast::BinaryOperator::Implication,
+                                             Rc::new(weakest_precondition));
+
+            let label = format!("{} (constant_time)", name);
+            let status = self.discharge(&label, &verification_condition, &data);
+            self.record(label, status, &pre_string, "");
+        }
+
+        for flow in flows {
+            let public_inputs = self_composition::public_arguments(&data, &flow.from);
+            let mut observation = Expression::BooleanLiteral(true);
+
+            for output in flow.to {
+                let output = Clause {
+                    text: output,
+                    span: flow.span,
+                };
+                let output_expression = resolve_clause(&output, &data, tcx.sess);
+
+                observation = Expression::BinaryExpression(Rc::new(observation),
+                                                           BinaryOperator::And,
+                                                           Rc::new(self_composition::agreement(&output_expression)));
+            }
+
+            let weakest_precondition = gen_composed(&data, &data, &observation);
+
+            let verification_condition =
+                Expression::BinaryExpression(Rc::new(self_composition::product_precondition(&pre_string_expression,
+                                                                                            &public_inputs)),
+                                             ast::BinaryOperator::Implication,
+                                             Rc::new(weakest_precondition));
+
+            let label = format!("{} (flows from {})", name, flow.from.join(", "));
+            let status = self.discharge(&label, &verification_condition, &data);
+            self.record(label, status, &pre_string, "");
+        }
+
+        if deterministic {
+            // Statics are free in each run, so agreeing on the arguments alone
+            // must be enough to agree on the result
+            let inputs = self_composition::public_arguments(&data, &[]);
+            let ret = walk_and_replace(Expression::VariableMapping("ret".to_string(),
+                                                                   Types::Unknown),
+                                       &data);
+            let weakest_precondition = gen_composed(&data,
+                                                    &data,
+                                                    &self_composition::agreement(&ret));
+
+            let verification_condition =
+                Expression::BinaryExpression(Rc::new(self_composition::product_precondition(&pre_string_expression,
+                                                                                            &inputs)),
+                                             ast::BinaryOperator::Implication,
+                                             Rc::new(weakest_precondition));
+
+            let label = format!("{} (deterministic)", name);
+            let status = self.discharge(&label, &verification_condition, &data);
+            self.record(label, status, &pre_string, "");
+        }
+
+        if let Some(ref reference) = reference {
+            let reference_id = find_function(tcx, &reference)
+                .unwrap_or_else(|| error!("Cannot find reference function `{}`", reference));
+            let reference_mir = tcx.item_mir(reference_id);
+            let reference_data = MirData::new(tcx,
+                                              &reference_mir,
+                                              self.theories.clone(),
+                                              self.config.clone());
+
+            let inputs = self_composition::argument_pairs(&data, &reference_data);
+            let ret = walk_and_replace(Expression::VariableMapping("ret".to_string(),
+                                                                   Types::Unknown),
+                                       &data);
+            let weakest_precondition = gen_composed(&data,
+                                                    &reference_data,
+                                                    &self_composition::agreement(&ret));
+
+            let mut precondition = pre_string_expression.clone();
+
+            for input in inputs {
+                precondition = Expression::BinaryExpression(Rc::new(precondition),
+                                                            BinaryOperator::And,
+                                                            Rc::new(input));
+            }
+
+            let verification_condition =
+                Expression::BinaryExpression(Rc::new(precondition),
+                                             ast::BinaryOperator::Implication,
+                                             Rc::new(weakest_precondition));
+
+            let label = format!("{} (equivalent to {})", name, reference);
+            let status = self.discharge(&label, &verification_condition, &data);
+            self.record(label, status, &pre_string, "");
+        }
+
+        if let Some(pledge) = pledge {
+            match mir.return_ty.sty {
+                TypeVariants::TyRef(_, ref referenced) if referenced.mutbl == hir::MutMutable => {}
+                _ => {
+                    span_error(tcx.sess,
+                               pledge.span,
+                               &format!("{} has a pledge but does not return `&mut`", name),
+                               "a pledge constrains the referent when the borrow ends")
+                }
+            }
+
+            let ret = walk_and_replace(Expression::VariableMapping("ret".to_string(),
+                                                                   Types::Unknown),
+                                       &data);
+            let prophecy = self_composition::rename_with(&ret, pledge::FINAL_SUFFIX);
+            let place = data.weakest_precondition(&ret);
+
+            let pledge_expression = resolve_clause(&pledge, &data, tcx.sess);
+
+            let verification_condition =
+                Expression::BinaryExpression(Rc::new(pre_string_expression.clone()),
+                                             ast::BinaryOperator::Implication,
+                                             Rc::new(pledge::obligation(&pledge_expression,
+                                                                        &place,
+                                                                        &prophecy)));
+
+            let label = format!("{} (pledge)", name);
+            let status = self.discharge(&label, &verification_condition, &data);
+            self.record(label, status, &pre_string, &pledge.text);
+        }
+
+        for invariant in history {
+            let invariant_expression = resolve_clause(&invariant, &data, tcx.sess);
+
+            let verification_condition =
+                Expression::BinaryExpression(Rc::new(pre_string_expression.clone()),
+                                             ast::BinaryOperator::Implication,
+                                             Rc::new(data.weakest_precondition(&invariant_expression)));
+
+            let label = format!("{} (history invariant `{}`)", name, invariant.text);
+            let status = self.discharge(&label, &verification_condition, &data);
+            self.record(label, status, &pre_string, &invariant.text);
+        }
+
+        // A harness passes when no assertion in it, or in the code it calls,
+        // can fail for inputs satisfying its assumption
+        if let Some(assumption) = harness {
+            let harness_data = MirData::new(tcx, mir, self.theories.clone(), self.config.clone())
+                .with_local_inlining()
+                .with_assumed_calls(assumed_calls.clone())
+                .with_debug_assertions(debug_assertions);
+            let assumption_expression = if assumption.text == "" {
+                Expression::BooleanLiteral(true)
+            } else {
+                resolve_clause(&assumption, &harness_data, tcx.sess)
+            };
+
+            let verification_condition =
+                Expression::BinaryExpression(Rc::new(assumption_expression),
+                                             ast::BinaryOperator::Implication,
+                                             Rc::new(harness_data.weakest_precondition(&Expression::BooleanLiteral(true))));
+
+            let label = format!("{} (harness)", name);
+            let status = self.discharge(&label, &verification_condition, &harness_data);
+            self.record(label, status, &assumption.text, "");
+        }
+
+        // With a postcondition, restoring lock invariants is part of its proof
+        if post_string == "" && releases_lock {
+            let verification_condition =
+                Expression::BinaryExpression(Rc::new(pre_string_expression.clone()),
+                                             ast::BinaryOperator::Implication,
+                                             Rc::new(data.weakest_precondition(&Expression::BooleanLiteral(true))));
+
+            let label = format!("{} (lock invariants)", name);
+            let status = self.discharge(&label, &verification_condition, &data);
+            self.record(label, status, &pre_string, "");
+        }
+
+        if check_casts {
+            let cast_data = MirData::new(tcx, mir, self.theories.clone(), self.config.clone())
+                .with_cast_checks()
+                .with_assumed_calls(assumed_calls.clone())
+                .with_debug_assertions(debug_assertions);
+            let verification_condition =
+                Expression::BinaryExpression(Rc::new(pre_string_expression.clone()),
+                                             ast::BinaryOperator::Implication,
+                                             Rc::new(cast_data.weakest_precondition(&Expression::BooleanLiteral(true))));
+
+            let label = format!("{} (truncating casts)", name);
+            let status = self.discharge(&label, &verification_condition, &cast_data);
+
+            // Warnings are shown, but never fail the function
+            if self.config.truncation == Lint::Deny {
+                self.record(label, status, &pre_string, "");
+            } else if status == Status::Invalid {
+                println!("   warning: a cast may truncate (`truncation = \"warn\"`)");
+            }
+        }
+
+        let function_name = &name;
+        let obligations = self.generators
+            .iter()
+            .flat_map(|generator| {
+                generator.obligations(&data, &pre_string_expression)
+                    .into_iter()
+                    .map(move |(label, obligation)| {
+                             (format!("{} ({}: {})", function_name, generator.name(), label),
+                              obligation)
+                         })
+            })
+            .collect::<Vec<_>>();
+
+        for (label, obligation) in obligations {
+            let status = self.discharge(&label, &obligation, &data);
+            self.record(label, status, &pre_string, "");
+        }
+
+        for (label, obligation) in assertions::obligations(&pre_string_expression, &data) {
+            let label = format!("{} ({})", name, label);
+            let status = self.discharge(&label, &obligation, &data);
+            self.record(label, status, &pre_string, "");
+        }
+
+        if expect_fail {
+            self.expect_failure(&name, first, &pre_string, &post_string);
+        }
+    }
+
+    // The checks of a function marked `#[expect_fail]` pass together when
+    // one of them is refuted, and fail when all of them are proved
+    fn expect_failure(&mut self, name: &str, first: usize, pre: &str, post: &str) {
+        let checks = self.summaries.split_off(first);
+        let refuted = checks.iter().any(|summary| summary.status == Status::Invalid);
+        let label = format!("{} (expect_fail)", name);
+
+        let status = if refuted {
+            println!("[EXPECTED FAILURE] -- {}", name);
+            Status::Valid
+        } else if checks.iter().all(|summary| summary.status == Status::Valid) {
+            println!("!! [UNEXPECTED PASS] -- {}: marked `#[expect_fail]`, but every check \
+                      was proved",
+                     name);
+            Status::Invalid
+        } else {
+            Status::Error
+        };
+
+        self.costs.insert(label.clone(), checks.iter().map(|summary| summary.cost).sum());
+        self.record(label, status, pre, post);
+    }
+}
+
+impl Drop for StanleyMir {
+    fn drop(&mut self) {
+        // The result line is printed even without obligations, so a run
+        // that never reached the plugin can be told apart
+        report::print(&self.summaries, milliseconds(self.started.elapsed()));
+
+        if !self.ledger.is_empty() {
+            println!("Assumption ledger: the proofs of this run rely on");
+
+            for (assumption, labels) in &self.ledger {
+                println!("   {} ({} obligations)", assumption, labels.len());
+            }
+        }
+
+        if self.skipped > 0 {
+            println!("!! {} obligations skipped: the budget of {} seconds was spent",
+                     self.skipped,
+                     self.config.budget.unwrap());
+        }
+
+        if self.filtered > 0 {
+            println!("   note: {} obligations skipped: their identifiers do not contain `{}`",
+                     self.filtered,
+                     self.config.filter.as_ref().unwrap());
+        }
+
+        for label in self.previous.keys() {
+            if !self.summaries.iter().any(|summary| summary.label == *label) {
+                println!("!! [REMOVED] -- {}", label);
+            }
+        }
+
+        if let Some(ref path) = self.config.summary {
+            summary::save(path, &self.producer, &self.summaries);
+
+            let journal = summary::journal(path);
+            if Path::new(&journal).exists() {
+                fs::remove_file(&journal)
+                    .unwrap_or_else(|e| error!("Cannot remove `{}`: {}", journal, e));
+            }
+        }
+
+        if let Some(ref path) = self.config.diagnostics {
+            diagnostics::save(path, &self.summaries);
+        }
+
+        if let Some(ref path) = self.config.manifest {
+            let toolchain = format!("rustc {} ({} {})",
+                                    rustc_driver::release_str().unwrap_or("unknown"),
+                                    rustc_driver::commit_hash_str().unwrap_or("unknown"),
+                                    rustc_driver::commit_date_str().unwrap_or("unknown"));
+
+            self.verified.sort_by(|a, b| a.name.cmp(&b.name));
+            manifest::save(path,
+                           &self.verified,
+                           &self.ledger,
+                           &toolchain,
+                           &self.config.solver_version());
+        }
+
+        for name in self.stable.keys() {
+            if !self.stable_seen.contains(name) {
+                println!("!! [SEMVER] -- {}: stable contract removed (breaking change)", name);
+            }
+        }
+
+        if let Some(ref path) = self.config.contracts {
+            let mut stable = self.stable.values().cloned().collect::<Vec<_>>();
+            stable.sort_by(|a, b| a.name.cmp(&b.name));
+            summary::save_stable(path, &stable);
+        }
+
+        explain::finish(&self.config);
+
+        if let Some(mode) = self.config.fmt {
+            formatter::finish(mode);
+        }
+
+        // Last, as new failures end the build
+        if let Some(ref path) = self.config.baseline {
+            if self.config.update_baseline {
+                baseline::save(path, &self.summaries);
+            } else {
+                baseline::check(path, &self.summaries);
+            }
+        }
+    }
+}
+
+impl<'tcx> Pass for StanleyMir {}
+
+impl<'tcx> MirPass<'tcx> for StanleyMir {
+    fn run_pass<'a>(&mut self, tcx: TyCtxt<'a, 'tcx, 'tcx>, src: MirSource, mir: &mut Mir<'tcx>) {
+        let item_id = src.item_id();
+        let def_id = tcx.hir.local_def_id(item_id);
+        let name = tcx.item_path_str(def_id);
+        let attrs = tcx.hir.attrs(item_id);
+
+        if self.config.fmt.is_some() {
+            formatter::function(attrs, &self.config, tcx.sess.codemap());
+        }
+
+        if self.config.monomorphize {
+            self.verify_instantiations(tcx, mir);
+
+            // Generic bodies are only verified through their instantiations
+            if !tcx.item_generics(def_id).types.is_empty() {
+                return;
+            }
+        }
+
+        self.verify(tcx, src, &name, attrs, mir);
+    }
+}
+
+// Whether a new contract strengthens the old precondition or weakens the
+// old postcondition, which would break callers relying on the old one
+fn contract_changes(old_pre: &str,
+                    old_post: &str,
+                    pre: &Expression,
+                    post: &Expression,
+                    data: &MirData)
+                    -> (bool, bool) {
+    let old_pre = if old_pre == "" {
+        Expression::BooleanLiteral(true)
+    } else {
+        walk_and_replace(parse_condition(old_pre.to_string()), data)
+    };
+    let old_post = if old_post == "" {
+        Expression::BooleanLiteral(true)
+    } else {
+        walk_and_replace(parse_condition(old_post.to_string()), data)
+    };
+
+    let pre_weakened = Expression::BinaryExpression(Rc::new(old_pre.clone()),
+                                                    BinaryOperator::Implication,
+                                                    Rc::new(pre.clone()));
+
+    let post_strengthened =
+        Expression::BinaryExpression(Rc::new(Expression::BinaryExpression(Rc::new(old_pre),
+                                                                          BinaryOperator::And,
+                                                                          Rc::new(post.clone()))),
+                                     BinaryOperator::Implication,
+                                     Rc::new(old_post));
+
+    (!is_valid(&pre_weakened, data), !is_valid(&post_strengthened, data))
+}
+
+// The verification condition as it is handed to the solver, with
+// permutations, uninterpreted functions and quantifiers eliminated
+fn prepare(verification_condition: &Expression, data: &MirData) -> Expression {
+    let verification_condition = fixed_lengths(data)
+        .into_iter()
+        .chain(valid_chars(data))
+        .fold(verification_condition.clone(), |condition, length| {
+            Expression::BinaryExpression(Rc::new(length),
+                                         BinaryOperator::Implication,
+                                         Rc::new(condition))
+        });
+    let closed_condition = builtins::eliminate_permutations(&verification_condition);
+    let closed_condition =
+        quantifier::eliminate(&theory::eliminate(&closed_condition, &data.theories));
+    let simplified = ast::simplify_expression(&closed_condition);
+    let solver = &data.config.solver;
+    let proved = midpoint::lemmas(&simplified, solver)
+        .into_iter()
+        .chain(lemmas::lemmas(&simplified, solver));
+
+    proved.fold(simplified, |condition, lemma| {
+        Expression::BinaryExpression(Rc::new(lemma),
+                                     BinaryOperator::Implication,
+                                     Rc::new(condition))
+    })
+}
+
+fn array_length(ty: Ty) -> Option<usize> {
+    match referenced(ty).sty {
+        TypeVariants::TyArray(_, length) => Some(length),
+        _ => None,
+    }
+}
+
+// The lengths of the arguments and result of fixed size array type `[T; N]`
+fn fixed_lengths(data: &MirData) -> Vec<Expression> {
+    let arguments = data.mir
+        .args_iter()
+        .map(|arg| (local_name(arg, data), data.mir.local_decls[arg].ty));
+
+    arguments.chain(Some(("ret".to_string(), data.mir.return_ty)))
+        .filter_map(|(name, ty)| match (ast::element_type(ty), array_length(ty)) {
+            (Some(element), Some(length)) => {
+                let array = Expression::Array(name, element);
+                Some(Expression::BinaryExpression(Rc::new(Expression::Length(Rc::new(array))),
+                                                  BinaryOperator::Equal,
+                                                  Rc::new(Expression::BitVector(length as i64,
+                                                                                Types::Usize))))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+// Arguments of type `char` hold Unicode scalar values, outside the
+// surrogate range
+fn valid_chars(data: &MirData) -> Vec<Expression> {
+    let compare = |c: &Expression, op, value| {
+        Rc::new(Expression::BinaryExpression(Rc::new(c.clone()),
+                                             op,
+                                             Rc::new(Expression::BitVector(value, Types::U32))))
+    };
+
+    data.mir
+        .args_iter()
+        .filter(|&arg| match data.mir.local_decls[arg].ty.sty {
+                    TypeVariants::TyChar => true,
+                    _ => false,
+                })
+        .map(|arg| {
+            let c = Expression::VariableMapping(local_name(arg, data), Types::U32);
+            let at_least = compare(&c, BinaryOperator::GreaterThanOrEqual, 0);
+            let at_most = compare(&c, BinaryOperator::LessThanOrEqual, 0x10ffff);
+            let below = compare(&c, BinaryOperator::LessThan, 0xd800);
+            let above = compare(&c, BinaryOperator::GreaterThan, 0xdfff);
+            let in_range = Expression::BinaryExpression(at_least, BinaryOperator::And, at_most);
+            let not_surrogate = Expression::BinaryExpression(below, BinaryOperator::Or, above);
+
+            Expression::BinaryExpression(Rc::new(in_range),
+                                         BinaryOperator::And,
+                                         Rc::new(not_surrogate))
+        })
+        .collect()
+}
+
+// `c as u32` keeps the scalar value of a `char`
+fn is_scalar_value_cast(operand: &Operand, target: Ty, data: &MirData) -> bool {
+    match (&operand.ty(data.mir, data.tcx).sty, &target.sty) {
+        (&TypeVariants::TyChar, &TypeVariants::TyUint(UintTy::U32)) => true,
+        _ => false,
+    }
+}
+
+// What a proof relying on `data` takes for granted, for the ledger of the
+// run
+fn ledger(data: &MirData) -> Vec<String> {
+    let mut ledger = assumptions(data);
+
+    if data.truncated.get() {
+        ledger.push("loops run a bounded number of iterations".to_string());
+    }
+
+    ledger
+}
+
+fn solve_prepared(condition: &Expression, solver: &Solver) -> SMTRes {
+    // The encoding above lacks the theory of floating point, which only the
+    // SMT-LIB text has
+    if ast::any_type(condition, &ast::is_float) {
+        let mut script = Vec::new();
+        smtlib::write(&mut script, "floating point", condition)
+            .unwrap_or_else(|e| error!("Cannot encode a condition: {}", e));
+
+        return match parallel::run(&script, solver) {
+            Ok(ref output) if output.starts_with("unsat") => SMTRes::Unsat(output.clone(), None),
+            Ok(ref output) if output.starts_with("sat") => {
+                SMTRes::Sat(output.clone(), Some(output.clone()))
+            }
+            Ok(output) | Err(output) => SMTRes::Error(output, None),
+        };
+    }
+
+    let mut process = parallel::Process::new(solver);
+    let mut encoding = SMTLib2::new(Some(QF_AUFBV));
+    let vcon = encoding.expr2smtlib(condition);
+    let _ = encoding.assert(core::OpCodes::Not, &[vcon]);
+    let (_, check) = encoding.solve(&mut process, false);
+
+    check
+}
+
+// What a proof relying on `data` takes for granted
+fn assumptions(data: &MirData) -> Vec<String> {
+    let mut assumptions = Vec::new();
+
+    if data.approximated_atomics.get() {
+        assumptions.push("atomic operations are sequentially consistent".to_string());
+    }
+
+    if data.unfinished.get() {
+        assumptions.push("code marked unimplemented is correct".to_string());
+    }
+
+    assumptions.extend(data.assumptions.borrow().iter().cloned());
+    assumptions
+}
+
+fn solve(verification_condition: &Expression, data: &MirData) -> SMTRes {
+    solve_prepared(&prepare(verification_condition, data), &data.config.solver)
+}
+
+fn is_valid(verification_condition: &Expression, data: &MirData) -> bool {
+    match solve(verification_condition, data) {
+        SMTRes::Unsat(..) => true,
+        _ => false,
+    }
+}
+
+// Verification must not change the code being compiled, so the standard
+// optimizations are applied to a copy of the body
+fn optimize<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>, src: MirSource, mir: &mut Mir<'tcx>) {
+    SimplifyCfg::new("stanley-initial").run_pass(tcx, src, mir);
+    SimplifyBranches::new("stanley").run_pass(tcx, src, mir);
+    InstCombine::new().run_pass(tcx, src, mir);
+    Deaggregator.run_pass(tcx, src, mir);
+    CopyPropagation.run_pass(tcx, src, mir);
+    SimplifyCfg::new("stanley-final").run_pass(tcx, src, mir);
+    SimplifyLocals.run_pass(tcx, src, mir);
+}
+
+// A model value as the type of its variable reads it: the bits of that
+// type, in two's complement for signed ones, or all the solver gives when the
+// type is not known. `None` when the solver gave something unreadable
+fn model_value(hex: &str, ty: Option<Types>) -> Option<String> {
+    let bits = match u128::from_str_radix(hex, 16) {
+        Ok(bits) => bits,
+        Err(_) => return None,
+    };
+    let width = match ty {
+        Some(Types::Bool) | Some(Types::Void) | Some(Types::Unknown) | None => hex.len() * 4,
+        Some(ty) if ast::is_float(ty) => hex.len() * 4,
+        Some(ty) => bitvector_size(ty),
+    };
+
+    match ty {
+        Some(Types::Bool) => Some((bits != 0).to_string()),
+        Some(ty) if ast::is_float(ty) => Some(ast::float_value(bits as u64, ty).to_string()),
+        Some(ty) if ast::is_signed(ty) && width < 128 && bits >> (width - 1) & 1 == 1 => {
+            Some((bits as i128 - (1 << width)).to_string())
+        }
+        Some(ty) if ast::is_signed(ty) => Some((bits as i128).to_string()),
+        _ => Some(bits.to_string()),
+    }
+}
+
+fn discharge(name: &str, verification_condition: &Expression, data: &MirData) -> Status {
+    if data.unfinished.get() && data.config.unfinished == Unfinished::Fail {
+        println!("!! [INVALID] -- {} (reaches unimplemented code)", name);
+
+        if explain::matches(name, &data.config) {
+            explain::context(name, &prepare(verification_condition, data), data);
+        }
+
+        return Status::Invalid;
+    }
+
+    let condition = prepare(verification_condition, data);
+
+    if explain::matches(name, &data.config) {
+        explain::context(name, &condition, data);
+    }
+
+    if let Some(ref directory) = data.config.smt {
+        smtlib::export(directory, name, &condition);
+    }
+
+    if data.config.emits(Emit::Smt) {
+        let mut script = Vec::new();
+        smtlib::write(&mut script, name, &condition)
+            .unwrap_or_else(|e| error!("Cannot encode `{}`: {}", name, e));
+        print!("{}", String::from_utf8_lossy(&script));
+    }
+
+    // When several solvers may run, the conjuncts are proved apart, and
+    // conditions on floats are always solved as SMT-LIB text
+    let floating = ast::any_type(&condition, &ast::is_float);
+    let obligations = if data.config.jobs > 1 {
+        parallel::obligations(&condition)
+    } else if floating {
+        vec![condition.clone()]
+    } else {
+        Vec::new()
+    };
+    let mathematical = data.mathematical_integers && integers::expressible(&condition);
+    let outcome = if mathematical {
+        let assumption = "arithmetic does not overflow (mathematical integers)".to_string();
+
+        if !data.assumptions.borrow().contains(&assumption) {
+            data.assumptions.borrow_mut().push(assumption);
+        }
+
+        integers::solve(name, &condition, &data.config.solver)
+    } else if obligations.len() > 1 || floating {
+        parallel::solve(name, &obligations, &data.config.solver, data.config.jobs)
+    } else {
+        match solve_prepared(&condition, &data.config.solver) {
+            SMTRes::Sat(_, ref model) => {
+                Outcome::Invalid(parallel::model(model.as_ref().map_or("", |model| &model[..])))
+            }
+            SMTRes::Unsat(..) => Outcome::Valid,
+            SMTRes::Error(ref error, _) => Outcome::Error(error.to_string()),
+        }
+    };
+
+    match outcome {
+        Outcome::Invalid(model) => {
+            println!("!! [INVALID] -- {}", name);
+            let mut values = Vec::new();
+            let mut types = BTreeMap::new();
+            eval::variables(&condition, &mut types);
+
+            for (var, hex) in model {
+                let value = model_value(&hex, types.get(&var).cloned())
+                    .unwrap_or_else(|| "?".to_string());
+
+                match source_name(&var, data) {
+                    Some(source) => {
+                        println!("   {:7} = {:>10} (0x{})", source, value, hex);
+                        values.push((source, value));
+                    }
+                    // The whole model shows the temporaries too, by the
+                    // names the conditions give them
+                    None if data.config.emits(Emit::Model) => {
+                        println!("   {:7} = {:>10} (0x{})", var, value, hex)
+                    }
+                    None => {}
+                }
+            }
+
+            explain::counterexample(name, values.clone());
+
+            if data.config.diagnostics.is_some() {
+                diagnostics::counterexample(name, values);
+            }
+
+            Status::Invalid
+        }
+        Outcome::Valid => {
+            // Wrapping evaluation refutes proofs relying on the absence of
+            // overflow
+            let differential = if mathematical { None } else { data.config.differential };
+
+            if let Some(samples) = differential {
+                if let Some(input) = eval::counterexample(&condition, samples) {
+                    println!("!! [UNSOUND] -- {} (proved, but false for this input)", name);
+
+                    for (var, value) in input {
+                        if let Some(name) = source_name(&var, data) {
+                            println!("   {:7} = {:10?}", name, value);
+                        }
+                    }
+
+                    return Status::Error;
+                }
+            }
 
-struct StanleyMir;
+            if let Some(ref directory) = data.config.proofs {
+                proof::archive(directory, name, &condition, &data.config);
+            }
+
+            println!("[VALID] -- {}", name);
+
+            for assumption in assumptions(data) {
+                println!("   assumes: {}", assumption);
+            }
 
-pub struct MirData<'tcx> {
-    block_data: Vec<&'tcx BasicBlockData<'tcx>>,
-    mir: &'tcx Mir<'tcx>,
+            Status::Valid
+        }
+        Outcome::Error(error) => {
+            println!("[ERROR]\n{}\n", error);
+            Status::Error
+        }
+    }
 }
 
-impl<'tcx> Pass for StanleyMir {}
+// The name a variable of a condition has in the source, keeping suffixes
+// like `$old` and field paths; `None` for compiler temporaries and the
+// locals of inlined callees, which mean nothing to the reader
+fn source_name(name: &str, data: &MirData) -> Option<String> {
+    let end = name.find(|c| c == '$' || c == '.').unwrap_or(name.len());
+    let (base, rest) = name.split_at(end);
 
-impl<'tcx> MirPass<'tcx> for StanleyMir {
-    fn run_pass<'a>(&mut self, tcx: TyCtxt<'a, 'tcx, 'tcx>, src: MirSource, mir: &mut Mir<'tcx>) {
-        let item_id = src.item_id();
-        let def_id = tcx.hir.local_def_id(item_id);
-        let name = tcx.item_path_str(def_id);
-        let attrs = tcx.hir.attrs(item_id);
+    let inlined = rest.split('$').any(|suffix| {
+        suffix.starts_with('i') && suffix[1..].parse::<usize>().is_ok()
+    });
+
+    if inlined && data.source_names.contains_key(base) {
+        return None;
+    }
 
-        let (pre_string, post_string) = parse_attributes(attrs);
+    match data.source_names.get(base) {
+        Some(&Some(ref source)) => Some(format!("{}{}", source, rest)),
+        Some(&None) => None,
+        None => Some(name.to_string()),
+    }
+}
+
+// A branch target no run satisfying the precondition reaches is dead code,
+// or a sign the precondition is stronger than intended
+fn report_dead_branches(name: &str, pre: &Expression, data: &MirData) {
+    let mut checked = HashSet::new();
+
+    for block in &data.block_data {
+        let targets = match block.terminator().kind {
+            TerminatorKind::SwitchInt { ref targets, .. } => targets,
+            _ => continue,
+        };
+        let span = block.terminator().source_info.span;
+
+        for (i, target) in targets.iter().enumerate() {
+            if !checked.insert(target.index()) {
+                continue;
+            }
+
+            let mut probe = data.with_composition(Composition::Single);
+            probe.probe = Some(target.index());
+            let reached = probe.weakest_precondition(&Expression::BooleanLiteral(true));
+            let verification_condition = Expression::BinaryExpression(Rc::new(pre.clone()),
+                                                                      BinaryOperator::Implication,
+                                                                      Rc::new(reached));
+
+            // Cut off unrolling may hide the paths reaching it
+            if is_valid(&verification_condition, &probe) && !probe.truncated.get() {
+                let branch = match (targets.len(), i) {
+                    (2, 0) => "`false` branch".to_string(),
+                    (2, _) => "`true` branch".to_string(),
+                    (_, i) => format!("branch {}", i),
+                };
+
+                println!("!! [DEAD] -- {}: the {} at {} is never taken when the precondition \
+                          holds",
+                         name,
+                         branch,
+                         data.tcx.sess.codemap().span_to_string(span));
+            }
+        }
+    }
+}
 
-        if pre_string == "" || post_string == "" {
+fn suggest_precondition(pre: &Expression,
+                        post: &Expression,
+                        weakest_precondition: &Expression,
+                        data: &MirData) {
+    // Comparisons are only formed between arguments holding a single value
+    let arguments = data.mir
+        .args_iter()
+        .map(|arg| &data.mir.local_decls[arg])
+        .filter(|decl| ast::type_to_enum(decl.ty) != Types::Unknown)
+        .map(|decl| {
+                 Expression::VariableMapping(decl.name.unwrap().as_str().to_string(),
+                                             ast::type_to_enum(decl.ty))
+             })
+        .collect::<Vec<_>>();
+
+    for candidate in abduction::candidates(&arguments, &[pre, post]) {
+        let strengthened = Expression::BinaryExpression(Rc::new(pre.clone()),
+                                                        BinaryOperator::And,
+                                                        Rc::new(candidate.clone()));
+        let verification_condition =
+            Expression::BinaryExpression(Rc::new(strengthened.clone()),
+                                         BinaryOperator::Implication,
+                                         Rc::new(weakest_precondition.clone()));
+        let vacuous = Expression::UnaryExpression(UnaryOperator::Not, Rc::new(strengthened));
+
+        if is_valid(&verification_condition, data) && !is_valid(&vacuous, data) {
+            println!("   this would verify if you add `pre: {}`", candidate);
             return;
         }
+    }
+}
 
-        let mut pre_string_expression = parse_condition(pre_string);
-        let mut post_string_expression = parse_condition(post_string);
+fn mutation_score(name: &str, pre: &Expression, post: &Expression, data: &MirData) {
+    let mutants = mutation::mutants(post);
+    let mut survivors = Vec::new();
 
-        let mut data = MirData {
-            block_data: Vec::new(),
-            mir: mir,
-        };
+    for mutant in &mutants {
+        let verification_condition =
+            Expression::BinaryExpression(Rc::new(pre.clone()),
+                                         BinaryOperator::Implication,
+                                         Rc::new(data.weakest_precondition(mutant)));
 
-        for block in mir.basic_blocks() {
-            data.block_data.push(block);
+        if is_valid(&verification_condition, data) {
+            survivors.push(mutant);
         }
+    }
 
-        pre_string_expression = walk_and_replace(pre_string_expression, &data);
-        post_string_expression = walk_and_replace(post_string_expression, &data);
+    println!("   [MUTATION] -- {}: {}/{} mutants killed",
+             name,
+             mutants.len() - survivors.len(),
+             mutants.len());
 
-        ast::ty_check(&pre_string_expression).unwrap_or_else(|e| error!("{}", e));
-        ast::ty_check(&post_string_expression).unwrap_or_else(|e| error!("{}", e));
+    for survivor in survivors {
+        println!("   survived: {:?}", survivor);
+    }
+}
 
-        let weakest_precondition = gen(0, 0, &data, &post_string_expression);
+fn gen_composed(first: &MirData, second: &MirData, post_expression: &Expression) -> Expression {
+    let second_run = gen(0,
+                         0,
+                         &second.with_composition(Composition::SecondRun),
+                         post_expression);
 
-        let verification_condition = Expression::BinaryExpression(Box::new(pre_string_expression),
-                                                                  ast::BinaryOperator::Implication,
-                                                                  Box::new(weakest_precondition));
+    gen(0, 0, &first.with_composition(Composition::FirstRun), &second_run)
+}
 
-        let mut z3: z3::Z3 = Default::default();
-        let mut solver = SMTLib2::new(Some(QF_AUFBV));
-        let simplified_condition = ast::simplify_expression(&verification_condition);
-        let vcon = solver.expr2smtlib(&simplified_condition);
-        let _ = solver.assert(core::OpCodes::Not, &[vcon]);
-        let (_, check) = solver.solve(&mut z3, false);
+fn find_function(tcx: TyCtxt, path: &str) -> Option<DefId> {
+    tcx.hir
+        .krate()
+        .bodies
+        .keys()
+        .map(|body_id| tcx.hir.body_owner_def_id(*body_id))
+        .find(|def_id| tcx.item_path_str(*def_id) == path)
+}
 
-        match check {
-            SMTRes::Sat(_, ref model) => {
-                let re = Regex::new(r".+(\(define-fun\s+([a-zA-Z0-9]+).*\s+#x([0-9a-f]+)\))+")
-                    .unwrap();
-                let text = model.clone().unwrap();
+// Every path to a `return` is generated separately, so blocks where paths
+// join are generated once per path reaching them
+fn gen(index: usize, depth: usize, data: &MirData, post_expression: &Expression) -> Expression {
+    if data.probe == Some(index) && !data.probe_check {
+        return Expression::BooleanLiteral(false);
+    }
 
-                println!("!! [INVALID] -- {}", name);
-                /*println!("{:?}", verification_condition);
-                println!("{:?}", simplified_condition);*/
+    // A run that never terminates satisfies any postcondition
+    if loops_forever(index, data) {
+        return Expression::BooleanLiteral(true);
+    }
 
-                for cap in re.captures_iter(&text) {
-                    println!("   {:7} = {:10?} (0x{})",
-                             &cap[2],
-                             i64::from_str_radix(&cap[3], 16).unwrap(),
-                             &cap[3]);
+    // Reaching the header of a loop with an invariant requires the
+    // invariant; the loop itself is proved separately from there. Coming
+    // back to it also requires a decreased variant when termination is
+    // checked
+    if let Some(ref invariant) = data.invariant {
+        if invariant.header == index {
+            if let Some(ref variant) = data.variant {
+                if data.path.borrow().contains(&index) {
+                    return variant::back_edge(&invariant.condition, variant);
                 }
             }
-            SMTRes::Unsat(..) => println!("[VALID] -- {}", name),
-            SMTRes::Error(ref error, _) => println!("[ERROR]\n{}\n", error),
+
+            return invariant.condition.clone();
         }
     }
+
+    data.path.borrow_mut().push(index);
+    let wp = gen_block(index, depth, data, post_expression);
+    data.path.borrow_mut().pop();
+    wp
 }
 
-fn gen(index: usize, depth: usize, data: &MirData, post_expression: &Expression) -> Expression {
+// Whether the path came back to `index` without passing a branch, so no run
+// ever leaves the loop
+fn loops_forever(index: usize, data: &MirData) -> bool {
+    let path = data.path.borrow();
+
+    match path.iter().rposition(|&block| block == index) {
+        Some(start) => {
+            path[start..].iter().all(|&block| match data.block_data[block].terminator().kind {
+                TerminatorKind::SwitchInt { .. } => false,
+                _ => true,
+            })
+        }
+        None => false,
+    }
+}
+
+// The target of the value the switched integer or discriminant equals, and
+// the last target when it equals none of them
+fn gen_cases(discriminant: &Expression,
+             values: &[ConstInt],
+             targets: &[BasicBlock],
+             depth: usize,
+             data: &MirData,
+             post_expression: &Expression)
+             -> Expression {
+    let otherwise = gen(targets[values.len()].index(), depth + 1, data, post_expression);
+
+    values.iter().zip(targets).rev().fold(otherwise, |wp, (value, target)| {
+        let condition = Expression::BinaryExpression(Rc::new(discriminant.clone()),
+                                                     BinaryOperator::Equal,
+                                                     Rc::new(gen_integral(value)));
+        let condition = data.composition.rename(&condition);
+        let not_condition = Expression::UnaryExpression(UnaryOperator::Not,
+                                                        Rc::new(condition.clone()));
+        let taken = gen(target.index(), depth + 1, data, post_expression);
+        let taken = Expression::BinaryExpression(Rc::new(condition.clone()),
+                                                 BinaryOperator::Implication,
+                                                 Rc::new(taken));
+        let skipped = Expression::BinaryExpression(Rc::new(not_condition),
+                                                   BinaryOperator::Implication,
+                                                   Rc::new(wp));
+        let wp = Expression::BinaryExpression(Rc::new(taken),
+                                              BinaryOperator::And,
+                                              Rc::new(skipped));
+
+        if data.composition == Composition::Lockstep {
+            Expression::BinaryExpression(Rc::new(self_composition::agreement(&condition)),
+                                         BinaryOperator::And,
+                                         Rc::new(wp))
+        } else {
+            wp
+        }
+    })
+}
+
+fn gen_block(index: usize,
+             depth: usize,
+             data: &MirData,
+             post_expression: &Expression)
+             -> Expression {
     let mut wp;
+    let terminator = data.block_data[index].terminator.clone().unwrap();
+    let span = terminator.source_info.span;
+
+    match terminator.kind {
+        TerminatorKind::Assert { ref cond, expected, target, ref msg, .. } => {
+            let mut check = data.composition.rename(&gen_expression(cond, data));
+
+            if !expected {
+                check = Expression::UnaryExpression(UnaryOperator::Not, Rc::new(check));
+            }
 
-    match data.block_data[index].terminator.clone().unwrap().kind {
-        TerminatorKind::Assert { target, .. } |
+            // The check probed for is the only failure
+            if data.probe == Some(index) {
+                wp = check;
+            } else {
+                wp = gen(target.index(), depth, data, post_expression);
+            }
+
+            // Indexing out of bounds, overflowing arithmetic and division by
+            // zero panic, which fails a single run like any other panic.
+            // Probes only follow the runs that do not panic there
+            if !data.composition.is_relational() && data.probe != Some(index) {
+                let op = if data.probe.is_some() {
+                    BinaryOperator::Implication
+                } else {
+                    BinaryOperator::And
+                };
+
+                wp = Expression::BinaryExpression(Rc::new(check), op, Rc::new(wp));
+            }
+
+            if data.composition == Composition::Lockstep {
+                if let AssertMessage::BoundsCheck { ref index, .. } = *msg {
+                    wp = Expression::BinaryExpression(Rc::new(self_composition::agreement(&gen_expression(index, data))),
+                                                      BinaryOperator::And,
+                                                      Rc::new(wp));
+                }
+            }
+        }
         TerminatorKind::Goto { target } => {
             wp = gen(target.index(), depth, data, post_expression);
         }
         TerminatorKind::Return => {
             return post_expression.clone();
         }
-        TerminatorKind::Call { func, .. } => {
+        TerminatorKind::Call { func, args, destination, .. } => {
             match func {
-                Operand::Constant(ref c) if format!("{:?}", c.literal).contains("begin_panic") => {
-                    return Expression::BooleanLiteral(false)
+                _ if is_panic(&func) => {
+                    if let Some(assumption) = assertions::assumption(index, data) {
+                        if !data.assumptions.borrow().contains(&assumption) {
+                            data.assumptions.borrow_mut().push(assumption);
+                        }
+
+                        return Expression::BooleanLiteral(true);
+                    }
+
+                    if data.probe.is_some() || debug::is_excused(index, data) ||
+                       assertions::is_assumed(index, data) {
+                        return Expression::BooleanLiteral(true);
+                    }
+
+                    if panic_message(&args).map_or(false, |message| message == UNIMPLEMENTED) {
+                        data.unfinished.set(true);
+
+                        if data.config.unfinished == Unfinished::Trust {
+                            return Expression::BooleanLiteral(true);
+                        }
+                    }
+
+                    // Unlike other diverging calls, panicking fails a single run,
+                    // as failed assertions panic, so `unreachable!()` is proved
+                    // unreachable under the precondition. A panicking run is never a
+                    // witness against a relational property, and lockstep runs
+                    // only reach it through branches already checked to agree
+                    return Expression::BooleanLiteral(data.composition.is_relational());
+                }
+                _ => {
+                    let def_id = function_def_id(&func).or_else(|| pointer_target(&func, data.mir));
+
+                    match (def_id, destination) {
+                        (Some(def_id), Some((lvalue, target))) => {
+                            let after = gen(target.index(), depth, data, post_expression);
+
+                            let recursive = data.variant
+                                .as_ref()
+                                .map_or(false, |variant| variant.function == def_id);
+
+                            if recursive {
+                                wp = variant::recursive_call(&after,
+                                                             data.variant.as_ref().unwrap(),
+                                                             &args,
+                                                             lvalue,
+                                                             index,
+                                                             depth,
+                                                             data);
+                            } else if atomic::is_atomic_call(data, &args) {
+                                wp = atomic::access(&after,
+                                                    def_id,
+                                                    &args,
+                                                    lvalue,
+                                                    index,
+                                                    depth,
+                                                    data);
+                            } else if let Some(call) = lock::call_kind(data, def_id, &args) {
+                                wp = match call {
+                                    lock::Call::Lock => {
+                                        lock::acquire(&after, &lvalue, index, depth, data)
+                                    }
+                                    lock::Call::Guard => after,
+                                };
+                            } else if thread::is_thread_call(data, def_id, &args) {
+                                wp = thread::call(&after,
+                                                  def_id,
+                                                  &args,
+                                                  lvalue,
+                                                  index,
+                                                  depth,
+                                                  data);
+                            } else if uninit::is_uninitialized_call(data, def_id) {
+                                wp = uninit::uninitialized(&after, lvalue, index, depth, data);
+                            } else if let Some(method) = option::method(data, def_id, &args) {
+                                wp = option::call(&after, method, &args, lvalue, data);
+                            } else if let Some(method) =
+                                result::method(data, def_id, &args, &lvalue, target) {
+                                wp = result::call(&after,
+                                                  method,
+                                                  &args,
+                                                  lvalue,
+                                                  index,
+                                                  depth,
+                                                  data);
+                            } else if let Some(method) = numeric::method(data, def_id, &args) {
+                                wp = numeric::call(&after,
+                                                   method,
+                                                   &args,
+                                                   lvalue,
+                                                   index,
+                                                   depth,
+                                                   data);
+                            } else if let Some(method) = ordering::method(data, def_id, &args) {
+                                wp = ordering::call(&after,
+                                                    method,
+                                                    &args,
+                                                    lvalue,
+                                                    index,
+                                                    depth,
+                                                    data);
+                            } else if let Some(op) = primitive_operator(data.tcx, &func) {
+                                let var = data.composition.rename(&gen_lvalue(lvalue, data));
+                                let expression =
+                                    Expression::BinaryExpression(Rc::new(gen_expression(&args[0], data)),
+                                                                 op,
+                                                                 Rc::new(gen_expression(&args[1], data)));
+                                wp = substitute_variable_with_expression(&after,
+                                                                         &var,
+                                                                         &data.composition
+                                                                              .rename(&expression));
+                            } else if let Some(callee) = contract::of(def_id, data) {
+                                wp = contract::call(&after,
+                                                    def_id,
+                                                    callee,
+                                                    &args,
+                                                    lvalue,
+                                                    index,
+                                                    depth,
+                                                    span,
+                                                    data);
+                            } else if data.tcx.is_const_fn(def_id) ||
+                                      (data.inline_local && def_id.is_local()) {
+                                wp = gen_inlined_call(def_id, &args, lvalue, after, span, data);
+                            } else {
+                                let callee = data.tcx.item_path_str(def_id);
+                                span_error(data.tcx.sess,
+                                           span,
+                                           &format!("cannot verify the call of `{}`", callee),
+                                           "give the callee a contract; without one, only a \
+                                            `const fn`, or a function of the crate called in a \
+                                            `#[harness]`, is inlined")
+                            }
+                        }
+                        // Calls that never return, like `process::exit` or
+                        // functions returning `!`, end every run through them
+                        (_, None) => return Expression::BooleanLiteral(true),
+                        (None, Some(_)) => {
+                            span_error(data.tcx.sess,
+                                       span,
+                                       "cannot verify a call through a function pointer or \
+                                        closure",
+                                       "only calls naming the function called, or through a \
+                                        local pointer only ever given one function, are \
+                                        supported")
+                        }
+                    }
                 }
-                _ => unimplemented!(),
             }
         }
-        TerminatorKind::SwitchInt { discr, targets, .. } => {
-            if depth > 199 {
+        TerminatorKind::SwitchInt { discr, values, targets, .. } => {
+            // Only earlier visits of this block on the current path are loop
+            // iterations; nested branches deepen `depth` without looping
+            let iterations = data.path
+                .borrow()
+                .iter()
+                .filter(|&&block| block == index)
+                .count() - 1;
+
+            if iterations > 199 {
+                data.truncated.set(true);
                 return Expression::BooleanLiteral(true);
             }
 
@@ -166,7 +2044,9 @@ fn gen(index: usize, depth: usize, data: &MirData, post_expression: &Expression)
                                 if let Operand::Constant(ref constant) = *rval {
                                     if let Literal::Value { ref value } = constant.literal {
                                         if let ConstVal::Integral(ref integral_value) = *value {
-                                            if depth > integral_value.to_u32().unwrap() as usize {
+                                            if iterations >
+                                               integral_value.to_u32().unwrap() as usize {
+                                                data.truncated.set(true);
                                                 return Expression::BooleanLiteral(true);
                                             }
                                         }
@@ -179,9 +2059,6 @@ fn gen(index: usize, depth: usize, data: &MirData, post_expression: &Expression)
                 }
             }
 
-            let wp_if = gen(targets[1].index(), depth + 1, data, post_expression);
-            let wp_else = gen(targets[0].index(), depth + 1, data, post_expression);
-
             let condition = match discr {
                 Operand::Constant(ref constant) => {
                     match constant.literal {
@@ -197,66 +2074,515 @@ fn gen(index: usize, depth: usize, data: &MirData, post_expression: &Expression)
                 Operand::Consume(c) => gen_lvalue(c, data),
             };
 
-            let not_condition = Expression::UnaryExpression(UnaryOperator::Not,
-                                                            Box::new(condition.clone()));
+            // Integers and the discriminants of enums switch between as many
+            // targets as there are patterns, and the guard of an arm is a
+            // boolean switch of its own
+            if ast::determine_evaluation_type(&condition) != Types::Bool {
+                wp = gen_cases(&condition, &values, &targets, depth, data, post_expression);
+            } else {
+                // Switches list the value of the first target, which is `false`
+                // for booleans but the discriminant of `Some` for `if let Some(..)`
+                let (taken, otherwise) = if values.len() == 1 && values[0].to_u64() == Some(1) {
+                    (targets[0], targets[1])
+                } else {
+                    (targets[1], targets[0])
+                };
+
+                let mut wp_if = gen(taken.index(), depth + 1, data, post_expression);
+                let mut wp_else = gen(otherwise.index(), depth + 1, data, post_expression);
+
+                // A debug assertion that is ignored never fails
+                if data.debug_assertions == DebugAssertions::Ignore {
+                    if debug::is_debug_assertion(taken.index(), data) {
+                        wp_if = wp_else.clone();
+                    } else if debug::is_debug_assertion(otherwise.index(), data) {
+                        wp_else = wp_if.clone();
+                    }
+                }
+
+                let condition = data.composition.rename(&condition);
+
+                let not_condition = Expression::UnaryExpression(UnaryOperator::Not,
+                                                                Rc::new(condition.clone()));
 
-            wp = Expression::BinaryExpression(Box::new(Expression::BinaryExpression(Box::new(condition), BinaryOperator::Implication, Box::new(wp_if))),
-                                              ast::BinaryOperator::And,
-                                              Box::new(Expression::BinaryExpression(Box::new(not_condition), BinaryOperator::Implication, Box::new(wp_else))));
+                wp = Expression::BinaryExpression(Rc::new(Expression::BinaryExpression(Rc::new(condition.clone()), BinaryOperator::Implication, Rc::new(wp_if))),
+                                                  ast::BinaryOperator::And,
+                                                  Rc::new(Expression::BinaryExpression(Rc::new(not_condition), BinaryOperator::Implication, Rc::new(wp_else))));
+
+                if data.composition == Composition::Lockstep {
+                    let agreement = self_composition::agreement(&condition);
+                    wp = Expression::BinaryExpression(Rc::new(agreement),
+                                                      BinaryOperator::And,
+                                                      Rc::new(wp));
+                }
+            }
+        }
+        TerminatorKind::Drop { location, target, .. } => {
+            wp = gen(target.index(), depth, data, post_expression);
+
+            if let Lvalue::Local(local) = location {
+                if lock::is_guard(data.tcx, data.mir.local_decls[local].ty) {
+                    wp = lock::release(&wp, local, index, depth, data);
+                }
+            }
         }
-        TerminatorKind::DropAndReplace { .. } |
-        TerminatorKind::Drop { .. } |
+        // No run gets here, whatever the postcondition; cleanup blocks are
+        // only reached by unwinding, which is never followed
         TerminatorKind::Unreachable |
-        TerminatorKind::Resume => unimplemented!(),
+        TerminatorKind::Resume => return Expression::BooleanLiteral(true),
+        // Dropping the old value, then assigning the new one
+        TerminatorKind::DropAndReplace { location, value, target, .. } => {
+            let assign = Statement {
+                source_info: terminator.source_info,
+                kind: StatementKind::Assign(location.clone(), Rvalue::Use(value)),
+            };
+            wp = gen_statement(gen(target.index(), depth, data, post_expression), &assign, data);
+
+            if let Lvalue::Local(local) = location {
+                if lock::is_guard(data.tcx, data.mir.local_decls[local].ty) {
+                    wp = lock::release(&wp, local, index, depth, data);
+                }
+            }
+        }
     }
 
     let mut stmts = data.block_data[index].statements.clone();
     stmts.reverse();
 
     for stmt in stmts {
-        wp = gen_stmt(wp, stmt, data);
+        wp = gen_statement(wp, &stmt, data);
+    }
+
+    wp
+}
+
+// A statement with the options, results, enums and structs it builds or
+// copies, and the reads of uninitialized memory it makes
+fn gen_statement(mut wp: Expression, stmt: &Statement, data: &MirData) -> Expression {
+    wp = gen_stmt(wp, stmt.clone(), data);
+    wp = uninit::statement(wp, stmt, data);
+    wp = option::statement(wp, stmt, data);
+    wp = result::statement(wp, stmt, data);
+    wp = enums::statement(wp, stmt, data);
+    structs::statement(wp, stmt, data)
+}
+
+// The message `unimplemented!()` panics with
+const UNIMPLEMENTED: &'static str = "not yet implemented";
+
+// Calls to `begin_panic` and `begin_panic_fmt`, which `panic!`, `assert!`
+// and `assert_eq!` expand to
+fn is_panic(func: &Operand) -> bool {
+    match *func {
+        Operand::Constant(ref c) => format!("{:?}", c.literal).contains("begin_panic"),
+        _ => false,
+    }
+}
+
+// The message of a panic with a literal one, as `panic!`, `unreachable!()`
+// and `unimplemented!()` have
+fn panic_message(args: &[Operand]) -> Option<String> {
+    if let Some(&Operand::Constant(ref c)) = args.first() {
+        if let Literal::Value { value: ConstVal::Str(ref message) } = c.literal {
+            return Some(message.to_string());
+        }
+    }
+
+    None
+}
+
+fn function_def_id(func: &Operand) -> Option<DefId> {
+    if let Operand::Constant(ref c) = *func {
+        if let Literal::Value { ref value } = c.literal {
+            if let ConstVal::Function(def_id, _) = *value {
+                return Some(def_id);
+            }
+        }
+    }
+
+    None
+}
+
+// The function a pointer called in the body points to, when the local
+// holding it is only ever given that function, directly or through copies
+fn pointer_target(func: &Operand, mir: &Mir) -> Option<DefId> {
+    let mut local = match *func {
+        Operand::Consume(Lvalue::Local(local)) => local,
+        _ => return None,
+    };
+
+    for _ in 0..mir.local_decls.len() {
+        let returned = mir.basic_blocks().iter().any(|block| match block.terminator().kind {
+            TerminatorKind::Call { destination: Some((Lvalue::Local(dest), _)), .. } => {
+                dest == local
+            }
+            _ => false,
+        });
+        let mut sources = mir.basic_blocks()
+            .iter()
+            .flat_map(|block| block.statements.iter())
+            .filter_map(|stmt| match stmt.kind {
+                            StatementKind::Assign(Lvalue::Local(dest), ref rvalue) => {
+                                if dest == local { Some(rvalue) } else { None }
+                            }
+                            _ => None,
+                        });
+
+        let source = match (sources.next(), sources.next()) {
+            (Some(source), None) if !returned => source,
+            _ => return None,
+        };
+
+        match *source {
+            Rvalue::Cast(CastKind::ReifyFnPointer, ref operand, _) => {
+                return function_def_id(operand)
+            }
+            Rvalue::Use(Operand::Consume(Lvalue::Local(from))) => local = from,
+            _ => return None,
+        }
+    }
+
+    None
+}
+
+// Once a generic body is instantiated, operator traits on primitive types
+// are just the corresponding MIR operations
+fn primitive_operator(tcx: TyCtxt, func: &Operand) -> Option<BinaryOperator> {
+    let (def_id, substs) = match *func {
+        Operand::Constant(ref c) => {
+            match c.literal {
+                Literal::Value { value: ConstVal::Function(def_id, substs) } => (def_id, substs),
+                _ => return None,
+            }
+        }
+        _ => return None,
+    };
+
+    match substs.types().next() {
+        Some(ty) if ty.is_integral() || ty.is_bool() => {}
+        _ => return None,
+    }
+
+    let trait_id = match tcx.trait_of_item(def_id) {
+        Some(trait_id) => trait_id,
+        None => return None,
+    };
+    let lang_items = &tcx.lang_items;
+    let method = tcx.item_name(def_id).to_string();
+
+    let op = if Some(trait_id) == lang_items.add_trait() {
+        BinaryOperator::Addition
+    } else if Some(trait_id) == lang_items.sub_trait() {
+        BinaryOperator::Subtraction
+    } else if Some(trait_id) == lang_items.mul_trait() {
+        BinaryOperator::Multiplication
+    } else if Some(trait_id) == lang_items.div_trait() {
+        BinaryOperator::Division
+    } else if Some(trait_id) == lang_items.rem_trait() {
+        BinaryOperator::Modulo
+    } else if Some(trait_id) == lang_items.bitand_trait() {
+        BinaryOperator::BitwiseAnd
+    } else if Some(trait_id) == lang_items.bitor_trait() {
+        BinaryOperator::BitwiseOr
+    } else if Some(trait_id) == lang_items.bitxor_trait() {
+        BinaryOperator::BitwiseXor
+    } else if Some(trait_id) == lang_items.shl_trait() {
+        BinaryOperator::BitwiseLeftShift
+    } else if Some(trait_id) == lang_items.shr_trait() {
+        BinaryOperator::BitwiseRightShift
+    } else if Some(trait_id) == lang_items.eq_trait() {
+        match method.as_ref() {
+            "eq" => BinaryOperator::Equal,
+            "ne" => BinaryOperator::NotEqual,
+            _ => return None,
+        }
+    } else if Some(trait_id) == lang_items.ord_trait() {
+        match method.as_ref() {
+            "lt" => BinaryOperator::LessThan,
+            "le" => BinaryOperator::LessThanOrEqual,
+            "gt" => BinaryOperator::GreaterThan,
+            "ge" => BinaryOperator::GreaterThanOrEqual,
+            _ => return None,
+        }
+    } else {
+        return None;
+    };
+
+    Some(op)
+}
+
+// The callee's body is generated in its own namespace with the result bound
+// to the call's destination, then its parameters are replaced by the actual
+// arguments, so constant arguments fold away during simplification
+fn gen_inlined_call(def_id: DefId,
+                    args: &[Operand],
+                    destination: Lvalue,
+                    wp: Expression,
+                    span: Span,
+                    data: &MirData)
+                    -> Expression {
+    let level = match data.composition {
+        Composition::Single => 1,
+        Composition::Inlined(level) => level + 1,
+        _ => error!("Calls are not supported when checking relational properties"),
+    };
+
+    let callee_mir = data.tcx.item_mir(def_id);
+    if level > 16 {
+        error!("Cannot inline `{}`: calls nest more than 16 deep",
+               data.tcx.item_path_str(def_id));
+    }
+
+    let mut callee = MirData::new(data.tcx,
+                                  &callee_mir,
+                                  data.theories.clone(),
+                                  data.config.clone())
+        .with_composition(Composition::Inlined(level));
+    callee.inline_local = data.inline_local;
+    callee.debug_assertions = debug::mode(&data.tcx.get_attrs(def_id), &data.config);
+
+    if let Some(id) = data.tcx.hir.as_local_node_id(def_id) {
+        callee.assumed_calls = Rc::new(assume::marked_calls(data.tcx, id, &data.config));
+    }
+
+    let callee_ret = Expression::VariableMapping("ret".to_string(),
+                                                 ast::type_to_enum(callee.mir.return_ty));
+    let dest = data.composition.rename(&gen_lvalue(destination, data));
+    let wp = substitute_variable_with_expression(&wp,
+                                                 &dest,
+                                                 &callee.composition.rename(&callee_ret));
+
+    let mut wp = gen(0, 0, &callee, &wp);
+    data.assumptions.borrow_mut().extend(callee.assumptions.borrow().iter().cloned());
+
+    if assume::is_marked(span, &data.assumed_calls) {
+        let codemap = data.tcx.sess.codemap();
+        let (pre, _) = parse_attributes(&data.tcx.get_attrs(def_id), &data.config, codemap);
+
+        if pre != "" {
+            let pre = callee.composition.rename(&callee.resolve_condition(&pre));
+            wp = Expression::BinaryExpression(Rc::new(pre),
+                                              BinaryOperator::Implication,
+                                              Rc::new(wp));
+            data.assumptions.borrow_mut().push(format!("precondition of `{}` at {}",
+                                                       data.tcx.item_path_str(def_id),
+                                                       codemap.span_to_string(span)));
+        }
+    }
+
+    for (param, arg) in callee.mir.args_iter().zip(args.iter()) {
+        let decl = &callee.mir.local_decls[param];
+        let param_var = Expression::VariableMapping(decl.name.unwrap().as_str().to_string(),
+                                                    ast::type_to_enum(decl.ty));
+        wp = substitute_variable_with_expression(&wp,
+                                                 &callee.composition.rename(&param_var),
+                                                 &data.composition.rename(&gen_expression(arg,
+                                                                                          data)));
     }
 
     wp
 }
 
-fn gen_lvalue(lvalue: Lvalue, data: &MirData) -> Expression {
+fn local_name(index: Local, data: &MirData) -> String {
+    match data.mir.local_kind(index) {
+        LocalKind::Arg => data.mir.local_decls[index].name.unwrap().as_str().to_string(),
+        LocalKind::Temp => gen_name!("tmp", index),
+        LocalKind::Var => gen_name!("var", index),
+        LocalKind::ReturnPointer => "ret".to_string(),
+    }
+}
+
+// The type of a local, which for a reference is that of the value it
+// points to
+fn local_type(ty: Ty) -> Types {
+    if ty.is_nil() {
+        Types::Void
+    } else {
+        ast::type_to_enum(ty)
+    }
+}
+
+// References and boxes are modelled by the value they point to, however
+// many of them lead to it
+fn referenced(ty: Ty) -> Ty {
+    match ty.sty {
+        TypeVariants::TyRef(_, ref pointee) => referenced(pointee.ty),
+        _ if ty.is_box() => referenced(ty.boxed_ty()),
+        _ => ty,
+    }
+}
+
+fn struct_field_name(ty: Ty, field: Field) -> Option<String> {
+    match referenced(ty).sty {
+        TypeVariants::TyAdt(adt, _) if adt.is_struct() => {
+            Some(adt.struct_variant().fields[field.index()].name.as_str().to_string())
+        }
+        _ => None,
+    }
+}
+
+// Struct fields are variables named by their path from a local, e.g.
+// `self.count`, together with their type
+fn field_path<'a, 'tcx>(lvalue: &Lvalue<'tcx>,
+                        data: &MirData<'a, 'tcx>)
+                        -> Option<(String, Ty<'tcx>)> {
+    match *lvalue {
+        Lvalue::Local(index) => Some((local_name(index, data), data.mir.local_decls[index].ty)),
+        Lvalue::Projection(ref pro) => {
+            if let (&ProjectionElem::Deref, &Lvalue::Local(local)) = (&pro.elem, &pro.base) {
+                if let Some(place) = borrows::target(local, data) {
+                    return field_path(&place, data);
+                }
+            }
+
+            let (path, ty) = match field_path(&pro.base, data) {
+                Some(base) => base,
+                None => return None,
+            };
+
+            match pro.elem {
+                ProjectionElem::Deref => Some((path, referenced(ty))),
+                // The payload of `Some` is the option itself
+                ProjectionElem::Downcast(adt, _) if ast::is_option_adt(adt) => Some((path, ty)),
+                ProjectionElem::Field(_, field_ty) if ast::is_option(ty) => Some((path, field_ty)),
+                // So is the payload of `Ok`, while that of `Err` is apart
+                ProjectionElem::Downcast(adt, 0) if ast::is_result_adt(adt) => Some((path, ty)),
+                ProjectionElem::Downcast(adt, _) if ast::is_result_adt(adt) => {
+                    Some((format!("{}$err", path), ty))
+                }
+                ProjectionElem::Field(_, field_ty) if ast::is_result(ty) => Some((path, field_ty)),
+                // The fields of other enums are named by their variant
+                ProjectionElem::Downcast(_, variant) if enums::is_enum(ty) => {
+                    Some((enums::variant_path(&path, ty, variant), ty))
+                }
+                ProjectionElem::Field(field, field_ty) if enums::is_enum(ty) => {
+                    enums::downcast(&pro.base)
+                        .and_then(|variant| enums::field_name(ty, variant, field))
+                        .map(|name| (format!("{}.{}", path, name), field_ty))
+                }
+                ProjectionElem::Field(field, field_ty) if structs::is_tuple(ty) => {
+                    Some((format!("{}.{}", path, field.index()), field_ty))
+                }
+                ProjectionElem::Field(field, field_ty) => {
+                    struct_field_name(ty, field).map(|name| (format!("{}.{}", path, name), field_ty))
+                }
+                _ => None,
+            }
+        }
+        Lvalue::Static(_) => None,
+    }
+}
+
+// The type of a field path written in a specification, from an argument
+// or `ret` through any number of fields
+fn field_type<'a, 'tcx>(path: &str, data: &MirData<'a, 'tcx>) -> Option<Ty<'tcx>> {
+    let mut segments = path.split('.');
+    let root = segments.next().unwrap();
+    let mut ty = if root == "ret" {
+        data.mir.return_ty
+    } else {
+        match data.mir
+                  .args_iter()
+                  .find(|arg| {
+                            data.mir.local_decls[*arg].name.map_or(false, |n| &*n.as_str() == root)
+                        }) {
+            Some(arg) => data.mir.local_decls[arg].ty,
+            None => return None,
+        }
+    };
+
+    for segment in segments {
+        ty = match referenced(ty).sty {
+            TypeVariants::TyAdt(adt, substs) if adt.is_struct() => {
+                match adt.struct_variant().fields.iter().find(|f| &*f.name.as_str() == segment) {
+                    Some(field) => field.ty(data.tcx, substs),
+                    None => return None,
+                }
+            }
+            TypeVariants::TyTuple(elements, _) => {
+                match segment.parse::<usize>().ok().and_then(|index| elements.get(index)) {
+                    Some(&element) => element,
+                    None => return None,
+                }
+            }
+            _ => return None,
+        };
+    }
+
+    Some(ty)
+}
+
+fn gen_lvalue<'a, 'tcx>(lvalue: Lvalue<'tcx>, data: &MirData<'a, 'tcx>) -> Expression {
     match lvalue {
         Lvalue::Local(index) => {
+            let decl = &data.mir.local_decls[index];
+
+            if let Some(element) = ast::element_type(decl.ty) {
+                let name = match data.mir.local_kind(index) {
+                    LocalKind::Arg => decl.name.unwrap().as_str().to_string(),
+                    LocalKind::Temp => gen_name!("tmp", index),
+                    LocalKind::Var => gen_name!("var", index),
+                    LocalKind::ReturnPointer => "ret".to_string(),
+                };
+
+                return Expression::Array(name, element);
+            }
+
+            if ast::is_option(decl.ty) || ast::is_result(decl.ty) || enums::is_enum(decl.ty) ||
+               structs::is_struct(decl.ty) || structs::is_tuple(decl.ty) {
+                return Expression::VariableMapping(local_name(index, data),
+                                                   ast::type_to_enum(decl.ty));
+            }
+
             match data.mir.local_kind(index) {
                 LocalKind::Arg => {
-                    Expression::VariableMapping(data.mir.local_decls[index]
-                                                    .name
-                                                    .unwrap()
-                                                    .as_str()
-                                                    .to_string(),
-                                                ast::string_to_type(data.mir.local_decls[index]
-                                                                        .ty
-                                                                        .to_string()))
+                    Expression::VariableMapping(decl.name.unwrap().as_str().to_string(),
+                                                ast::type_to_enum(decl.ty))
                 }
                 LocalKind::Temp => {
-                    let mut ty = data.mir.local_decls[index].ty.to_string();
-
-                    if let TypeVariants::TyTuple(s, _) = data.mir.local_decls[index].ty.sty {
-                        if s.len() > 0 {
-                            ty = s[0].to_string();
-                        }
-                    }
-                    Expression::VariableMapping(gen_name!("tmp", index), ast::string_to_type(ty))
+                    Expression::VariableMapping(gen_name!("tmp", index), local_type(decl.ty))
                 }
                 LocalKind::Var => {
-                    Expression::VariableMapping(gen_name!("var", index),
-                                                ast::string_to_type(data.mir.local_decls[index]
-                                                                        .ty
-                                                                        .to_string()))
+                    Expression::VariableMapping(gen_name!("var", index), local_type(decl.ty))
                 }
                 LocalKind::ReturnPointer => {
                     Expression::VariableMapping("ret".to_string(),
                                                 ast::type_to_enum(data.mir.return_ty))
                 }
             }
-        }
-        Lvalue::Projection(pro) => {
+        }
+        Lvalue::Projection(pro) => {
+            match pro.elem {
+                // References are modelled by the value they point to, except
+                // those into data guarded by a lock
+                ProjectionElem::Deref => {
+                    if let Lvalue::Local(local) = pro.base {
+                        if let Some((guarded, _)) = lock::guarded(local, data) {
+                            return guarded;
+                        }
+
+                        if let Some(place) = borrows::target(local, data) {
+                            return gen_lvalue(place, data);
+                        }
+                    }
+
+                    return gen_lvalue(pro.base.clone(), data);
+                }
+                ProjectionElem::Index(ref index) => {
+                    return arrays::element(&pro.base, gen_expression(index, data), data);
+                }
+                ProjectionElem::Field(..) => {
+                    if let Some((path, ty)) = field_path(&Lvalue::Projection(pro.clone()), data) {
+                        if let Some(element) = ast::element_type(ty) {
+                            return Expression::Array(path, element);
+                        }
+
+                        return Expression::VariableMapping(path, ast::type_to_enum(ty));
+                    }
+                }
+                _ => {}
+            }
+
             let lvalue_name;
             let lvalue_type_string;
 
@@ -305,11 +2631,16 @@ fn gen_lvalue(lvalue: Lvalue, data: &MirData) -> Expression {
 
             Expression::VariableMapping(lvalue_name, ast::string_to_type(lvalue_type_string))
         }
-        Lvalue::Static(_) => unimplemented!(),
+        Lvalue::Static(ref stat) => {
+            Expression::VariableMapping(format!("static{}_{}",
+                                                stat.def_id.krate.as_u32(),
+                                                stat.def_id.index.as_u32()),
+                                        ast::type_to_enum(stat.ty))
+        }
     }
 }
 
-fn gen_stmt(wp: Expression, stmt: Statement, data: &MirData) -> Expression {
+fn gen_stmt(mut wp: Expression, stmt: Statement, data: &MirData) -> Expression {
     let lvalue: Lvalue;
     let rvalue: Rvalue;
 
@@ -331,7 +2662,7 @@ fn gen_stmt(wp: Expression, stmt: Statement, data: &MirData) -> Expression {
             let rvalue2 = gen_expression(rval, data);
 
             expression =
-                Expression::BinaryExpression(Box::new(lvalue2),
+                Expression::BinaryExpression(Rc::new(lvalue2),
                                              (match *binop {
                                                   BinOp::Add => BinaryOperator::Addition,
                                                   BinOp::Sub => BinaryOperator::Subtraction,
@@ -350,79 +2681,323 @@ fn gen_stmt(wp: Expression, stmt: Statement, data: &MirData) -> Expression {
                                                   BinOp::Eq => BinaryOperator::Equal,
                                                   BinOp::Ne => BinaryOperator::NotEqual,
                                               }),
-                                             Box::new(rvalue2));
+                                             Rc::new(rvalue2));
         }
         Rvalue::UnaryOp(ref unop, ref val) => {
             expression = Expression::UnaryExpression(match *unop {
                                                          UnOp::Not => UnaryOperator::Not,
                                                          UnOp::Neg => UnaryOperator::Negation,
                                                      },
-                                                     Box::new(gen_expression(val, data)));
+                                                     Rc::new(gen_expression(val, data)));
         }
         Rvalue::Aggregate(ref ag_kind, ref vec_operand) => {
             match *ag_kind {
-                AggregateKind::Tuple => {
-                    for operand in vec_operand.iter() {
-                        expression =
-                            Expression::VariableMapping(format!("{:?}", operand),
-                                                        ast::string_to_type(match operand.clone() {
-                                                                                Operand::Constant(ref constant) => constant.ty.to_string(),
-                                                                                Operand::Consume(ref lvalue) => {
-                                                                                    match *lvalue {
-                                                                                        Lvalue::Local(ref variable) => {
-                                                                                            match data.mir.local_kind(*variable) {
-                                                                                                LocalKind::Arg | LocalKind::Temp | LocalKind::Var => data.mir.local_decls[*variable].ty.to_string(),
-                                                                                                _ => unimplemented!(),
-                                                                                            }
-                                                                                        }
-                                                                                        Lvalue::Static(_) |
-                                                                                        Lvalue::Projection(_) => unimplemented!(),
-                                                                                    }
-                                                                                }
-                                                                            }));
-                    }
-                }
-                _ => error!("Unsupported aggregate: only tuples are supported"),
+                // The tag is set by `option::statement`
+                AggregateKind::Adt(adt, variant, _, _) if ast::is_option_adt(adt) => {
+                    expression = if variant == 1 {
+                        gen_expression(&vec_operand[0], data)
+                    } else {
+                        var.clone()
+                    };
+                }
+                // The tag and the `Err` payload are set by `result::statement`
+                AggregateKind::Adt(adt, variant, _, _) if ast::is_result_adt(adt) => {
+                    expression = if variant == 0 {
+                        gen_expression(&vec_operand[0], data)
+                    } else {
+                        var.clone()
+                    };
+                }
+                // The discriminant and the fields are set by `enums::statement`
+                AggregateKind::Adt(adt, ..) if adt.is_enum() => expression = var.clone(),
+                // So are the elements of a tuple and the fields of a struct, by
+                // `structs::statement`
+                AggregateKind::Tuple => expression = var.clone(),
+                AggregateKind::Adt(adt, ..) if adt.is_struct() => expression = var.clone(),
+                AggregateKind::Array(_) => expression = arrays::build(&var, &rvalue, data),
+                _ => error!("Unsupported aggregate: only tuples, structs, arrays and enums are \
+                             supported"),
             }
         }
         Rvalue::Use(ref operand) => {
             expression = gen_expression(operand, data);
         }
-        Rvalue::Cast(..) | Rvalue::Ref(..) => {
+        Rvalue::Ref(_, _, ref lvalue) => {
+            // References are modelled by the value they point to
+            expression = gen_lvalue(lvalue.clone(), data);
+        }
+        Rvalue::Cast(CastKind::Unsize, ref operand, _) => {
+            // An array coerced to a slice keeps its contents
+            expression = gen_expression(operand, data);
+        }
+        Rvalue::Cast(_, ref operand, target) if is_scalar_value_cast(operand, target, data) => {
+            expression = gen_expression(operand, data);
+        }
+        Rvalue::Cast(..) => {
             expression = var.clone();
+
+            if data.check_casts {
+                if let Some(fits) = cast::obligation(&rvalue, data) {
+                    wp = Expression::BinaryExpression(Rc::new(data.composition.rename(&fits)),
+                                                      BinaryOperator::And,
+                                                      Rc::new(wp));
+                }
+            }
+        }
+        Rvalue::Len(ref lvalue) => {
+            // Bounds checks on fixed size arrays fold to constants
+            expression = match array_length(lvalue.ty(data.mir, data.tcx).to_ty(data.tcx)) {
+                Some(length) => Expression::BitVector(length as i64, Types::Usize),
+                None => Expression::Length(Rc::new(gen_lvalue(lvalue.clone(), data))),
+            };
         }
+        // Switching on the discriminant of an option is switching on its tag
+        Rvalue::Discriminant(ref lvalue) if option::holds_option(lvalue, data) => {
+            expression = option::tag(&gen_lvalue(lvalue.clone(), data));
+        }
+        // and on that of a result on whether it is `Err`, its variant 1
+        Rvalue::Discriminant(ref lvalue) if result::holds_result(lvalue, data) => {
+            let result = gen_lvalue(lvalue.clone(), data);
+            expression = Expression::UnaryExpression(UnaryOperator::Not,
+                                                     Rc::new(result::tag(&result)));
+        }
+        Rvalue::Discriminant(ref lvalue) if enums::holds_enum(lvalue, data) => {
+            expression = enums::read_discriminant(lvalue, data);
+        }
+        Rvalue::Repeat(..) => expression = arrays::build(&var, &rvalue, data),
         Rvalue::Box(..) |
-        Rvalue::Len(..) |
-        Rvalue::Repeat(..) |
         Rvalue::Discriminant(..) => unimplemented!(),
     };
 
-    substitute_variable_with_expression(&wp, &var, &expression)
+    // A checked operation gives a pair of its result and whether it
+    // overflowed
+    let var = match (rvalue, var) {
+        (Rvalue::CheckedBinaryOp(binop, ref lval, ref rval),
+         Expression::VariableMapping(name, _)) => {
+            let overflowed = Expression::VariableMapping(format!("{}.1", name), Types::Bool);
+            let (a, b) = (gen_expression(lval, data), gen_expression(rval, data));
+            wp = assign(&wp, &overflowed, &arithmetic::flag(binop, &a, &b), data);
+
+            Expression::VariableMapping(format!("{}.0", name),
+                                        ast::determine_evaluation_type(&expression))
+        }
+        (_, var) => var,
+    };
+
+    // Writing an element replaces the whole array by an updated one
+    let (var, expression) = match var {
+        Expression::Select(array, index) => {
+            let store = Expression::Store(array.clone(), index, Rc::new(expression));
+            ((*array).clone(), store)
+        }
+        var => (var, expression),
+    };
+
+    assign(&wp, &var, &expression, data)
+}
+
+// The weakest precondition of `var = expression` in every run
+fn assign(wp: &Expression,
+          var: &Expression,
+          expression: &Expression,
+          data: &MirData)
+          -> Expression {
+    match data.composition {
+        Composition::Lockstep => {
+            let wp = substitute_variable_with_expression(wp, var, expression);
+            substitute_variable_with_expression(&wp,
+                                                &self_composition::rename(var),
+                                                &self_composition::rename(expression))
+        }
+        composition => {
+            substitute_variable_with_expression(wp,
+                                                &composition.rename(var),
+                                                &composition.rename(expression))
+        }
+    }
 }
 
 fn substitute_variable_with_expression(source_expression: &Expression,
                                        target: &Expression,
                                        replacement: &Expression)
                                        -> Expression {
-    match *source_expression {
-        Expression::BinaryExpression(ref left, ref op, ref right) => {
-            let new_left = Box::new(substitute_variable_with_expression(left, target, replacement));
-            let new_right =
-                Box::new(substitute_variable_with_expression(right, target, replacement));
-            Expression::BinaryExpression(new_left, *op, new_right)
+    substitute(source_expression, target, replacement)
+        .unwrap_or_else(|| source_expression.clone())
+}
+
+fn substitute_shared(expression: &Rc<Expression>,
+                     target: &Expression,
+                     replacement: &Expression,
+                     changed: &mut bool)
+                     -> Rc<Expression> {
+    match substitute(expression, target, replacement) {
+        Some(substituted) => {
+            *changed = true;
+            Rc::new(substituted)
+        }
+        None => expression.clone(),
+    }
+}
+
+// `None` when `target` does not occur, so that the subformulas a
+// substitution leaves alone, like the common postcondition of joining
+// branches, stay shared instead of being copied
+fn substitute(source_expression: &Expression,
+              target: &Expression,
+              replacement: &Expression)
+              -> Option<Expression> {
+    let mut changed = false;
+
+    let substituted = match *source_expression {
+        Expression::BinaryExpression(ref left, op, ref right) => {
+            let left = substitute_shared(left, target, replacement, &mut changed);
+            let right = substitute_shared(right, target, replacement, &mut changed);
+            Expression::BinaryExpression(left, op, right)
         }
         Expression::UnaryExpression(ref op, ref expr) => {
-            Expression::UnaryExpression((*op).clone(),
-                                        Box::new(substitute_variable_with_expression(expr,
-                                                                                     target,
-                                                                                     replacement)))
+            Expression::UnaryExpression(op.clone(),
+                                        substitute_shared(expr, target, replacement, &mut changed))
+        }
+        Expression::FunctionCall(ref name, ref args, ty) => {
+            let args = args.iter()
+                .map(|arg| match substitute(arg, target, replacement) {
+                         Some(substituted) => {
+                             changed = true;
+                             substituted
+                         }
+                         None => arg.clone(),
+                     })
+                .collect();
+            Expression::FunctionCall(name.clone(), args, ty)
+        }
+        Expression::Select(ref array, ref index) => {
+            let array = substitute_shared(array, target, replacement, &mut changed);
+            let index = substitute_shared(index, target, replacement, &mut changed);
+            Expression::Select(array, index)
+        }
+        Expression::Store(ref array, ref index, ref value) => {
+            let array = substitute_shared(array, target, replacement, &mut changed);
+            let index = substitute_shared(index, target, replacement, &mut changed);
+            let value = substitute_shared(value, target, replacement, &mut changed);
+            Expression::Store(array, index, value)
+        }
+        Expression::Forall(ref var, ty, ref body) => {
+            Expression::Forall(var.clone(),
+                               ty,
+                               substitute_shared(body, target, replacement, &mut changed))
+        }
+        Expression::Length(ref array) => {
+            Expression::Length(substitute_shared(array, target, replacement, &mut changed))
+        }
+        Expression::VariableMapping(_, _) |
+        Expression::Array(_, _) if source_expression == target => {
+            return Some(replacement.clone());
+        }
+        _ => return None,
+    };
+
+    if changed { Some(substituted) } else { None }
+}
+
+// Untyped literals take the type of what they are combined with
+fn with_type(expression: Expression, ty: Types) -> Expression {
+    match expression {
+        Expression::Float(bits, Types::Unknown) if ast::is_float(ty) => {
+            ast::float(ast::float_value(bits, Types::F64), ty)
+        }
+        Expression::Float(_, Types::Unknown) if ty != Types::Unknown => {
+            error!("`{}` is a floating-point literal, but is used as `{:?}`", expression, ty)
+        }
+        Expression::BitVector(value, Types::Unknown) if ast::is_float(ty) => {
+            ast::float(value as f64, ty)
+        }
+        Expression::BitVector(value, Types::Unknown) if ty != Types::Unknown &&
+                                                         ty != Types::Bool => {
+            // Negative literals for unsigned values are explained where
+            // they are compared
+            if !ast::fits(value, ty) && (value >= 0 || ast::is_signed(ty)) {
+                error!("`{}` is out of range for `{:?}`", value, ty);
+            }
+
+            Expression::BitVector(value, ty)
+        }
+        _ => expression,
+    }
+}
+
+fn type_of(expression: &Expression) -> Types {
+    match *expression {
+        Expression::BitVector(_, Types::Unknown) |
+        Expression::Float(_, Types::Unknown) => Types::Unknown,
+        _ => ast::determine_evaluation_type(expression),
+    }
+}
+
+fn is_integer(ty: Types) -> bool {
+    match ty {
+        Types::Bool | Types::Void | Types::Unknown | Types::F32 | Types::F64 => false,
+        _ => true,
+    }
+}
+
+// Whichever reading of the bits mixing signed and unsigned operands chose
+// would surprise someone, so specifications ask for `int` or `uint` instead
+fn check_signedness(left: &Expression, op: BinaryOperator, right: &Expression) {
+    let (l_type, r_type) = (type_of(left), type_of(right));
+
+    for &(literal, other, ty) in &[(left, right, r_type), (right, left, l_type)] {
+        if let Expression::BitVector(value, Types::Unknown) = *literal {
+            if value < 0 && is_integer(ty) && !ast::same_signedness(ty, Types::I64) {
+                error!("`{}` is negative, but `{}` is unsigned (`{:?}`) and would read it as \
+                        a large positive value; write `int({})` to compare it as a signed \
+                        number",
+                       literal,
+                       other,
+                       ty,
+                       other);
+            }
+        }
+    }
+
+    // Shift amounts may be of any integer type, as in Rust
+    if op == BinaryOperator::BitwiseLeftShift || op == BinaryOperator::BitwiseRightShift {
+        return;
+    }
+
+    if is_integer(l_type) && is_integer(r_type) && !ast::same_signedness(l_type, r_type) {
+        error!("`{}` is `{:?}` but `{}` is `{:?}`: signed and unsigned values are not mixed \
+                implicitly; convert both with `int(..)`, or both with `uint(..)`",
+               left,
+               l_type,
+               right,
+               r_type);
+    }
+}
+
+// The length of an array argument or result of type `[T; N]` in `len(a)`,
+// which is part of its type as it is for the bounds checks of the body
+fn fixed_length(name: &str, args: &[Expression], data: &MirData) -> Option<usize> {
+    match args.first() {
+        Some(&Expression::Array(ref array, _)) if name == "len" && args.len() == 1 => {
+            let array = Expression::VariableMapping(array.clone(), Types::Unknown);
+            option::specified_type(&array, data).and_then(array_length)
         }
-        Expression::VariableMapping(_, _) if source_expression == target => replacement.clone(),
-        _ => source_expression.clone(),
+        _ => None,
     }
 }
 
+// Every term made keeps the place in the clause of the term it was made of
 fn walk_and_replace(expression: Expression, data: &MirData) -> Expression {
+    if !spans::active() {
+        return replace_terms(expression, data);
+    }
+
+    let replaced = replace_terms(expression.clone(), data);
+    spans::carry(&expression, &replaced);
+    replaced
+}
+
+fn replace_terms(expression: Expression, data: &MirData) -> Expression {
     match expression {
         Expression::VariableMapping(a, b) => {
             let aa = a.clone();
@@ -430,13 +3005,30 @@ fn walk_and_replace(expression: Expression, data: &MirData) -> Expression {
 
             if bb == Types::Unknown {
                 if aa == "ret" {
+                    if let Some(element) = ast::element_type(data.mir.return_ty) {
+                        return Expression::Array(aa, element);
+                    }
+
                     bb = ast::type_to_enum(data.mir.return_ty);
+                } else if aa.contains('.') {
+                    let ty = field_type(&aa, data)
+                        .unwrap_or_else(|| error!("Unknown field `{}`", aa));
+
+                    if let Some(element) = ast::element_type(ty) {
+                        return Expression::Array(aa, element);
+                    }
+
+                    bb = ast::type_to_enum(ty);
                 } else {
                     for arg in data.mir.args_iter() {
                         let arg2 = &data.mir.local_decls[arg];
                         let a2 = arg2.name.unwrap().as_str();
 
                         if a == String::from_utf8_lossy(a2.as_bytes()) {
+                            if let Some(element) = ast::element_type(arg2.ty) {
+                                return Expression::Array(aa, element);
+                            }
+
                             bb = ast::type_to_enum(arg2.ty);
                             break;
                         }
@@ -447,18 +3039,132 @@ fn walk_and_replace(expression: Expression, data: &MirData) -> Expression {
             Expression::VariableMapping(aa, bb)
         }
         Expression::BinaryExpression(a, b, c) => {
-            let aa = Box::new(walk_and_replace(*a.clone(), data));
-            let ca = Box::new(walk_and_replace(*c.clone(), data));
-            Expression::BinaryExpression(aa, b, ca)
+            if let Some(expression) = option::equality(&a, b, &c, data) {
+                return expression;
+            }
+
+            let aa = walk_and_replace((*a).clone(), data);
+            let ca = walk_and_replace((*c).clone(), data);
+
+            if let Some(expression) = builtins::compare_slices(&aa, b, &ca) {
+                return expression;
+            }
+
+            check_signedness(&aa, b, &ca);
+
+            let (l_type, r_type) = (type_of(&aa), type_of(&ca));
+            Expression::BinaryExpression(Rc::new(with_type(aa, r_type)),
+                                         b,
+                                         Rc::new(with_type(ca, l_type)))
+        }
+        Expression::Select(array, index) => {
+            let array = walk_and_replace((*array).clone(), data);
+            let index = with_type(walk_and_replace((*index).clone(), data), Types::Usize);
+            let select = Expression::Select(Rc::new(array), Rc::new(index));
+            arrays::flattened(&select, data).unwrap_or(select)
+        }
+        Expression::Store(array, index, value) => {
+            let array = walk_and_replace((*array).clone(), data);
+            let index = with_type(walk_and_replace((*index).clone(), data), Types::Usize);
+            let value = with_type(walk_and_replace((*value).clone(), data), type_of(&array));
+            Expression::Store(Rc::new(array), Rc::new(index), Rc::new(value))
+        }
+        Expression::Forall(var, ty, body) => {
+            // Bound variables are renamed so the weakest precondition never
+            // substitutes them
+            let bound = format!("{}$bound", var);
+            let body = theory::bind(&body, &var, &Expression::VariableMapping(bound.clone(), ty));
+            Expression::Forall(bound, ty, Rc::new(walk_and_replace(body, data)))
         }
         Expression::UnaryExpression(a, b) => {
-            let ba = Box::new(walk_and_replace(*b.clone(), data));
+            let ba = Rc::new(walk_and_replace((*b).clone(), data));
             Expression::UnaryExpression(a, ba)
         }
+        // The variant of `x is Variant` is no variable to resolve
+        Expression::FunctionCall(ref name, ref args, _) if name == "is" && args.len() == 2 => {
+            enums::test(&args[0], &args[1], data)
+        }
+        Expression::FunctionCall(name, args, _) => {
+            let args = args.into_iter().map(|arg| walk_and_replace(arg, data)).collect::<Vec<_>>();
+
+            if let Some(expression) = option::expand(&name, &args, data) {
+                return expression;
+            }
+
+            if let Some(expression) = result::expand(&name, &args, data) {
+                return expression;
+            }
+
+            if let Some(length) = fixed_length(&name, &args, data) {
+                return Expression::BitVector(length as i64, Types::Usize);
+            }
+
+            if let Some(expression) = builtins::expand(&name, &args) {
+                return expression;
+            }
+
+            let function = theory::find(&data.theories, &name)
+                .unwrap_or_else(|| error!("Unknown function `{}`", name));
+
+            if function.parameters.len() != args.len() {
+                error!("`{}` expects {} arguments, found {}",
+                       name,
+                       function.parameters.len(),
+                       args.len());
+            }
+
+            let args = args.into_iter()
+                .zip(function.parameters.iter())
+                .map(|(arg, &(_, ty))| with_type(arg, ty))
+                .collect::<Vec<_>>();
+
+            for (&(ref param, ty), arg) in function.parameters.iter().zip(args.iter()) {
+                if ast::determine_evaluation_type(arg) != ty {
+                    error!("Argument `{}` of `{}` must be of type `{:?}`", param, name, ty);
+                }
+            }
+
+            match function.definition {
+                Some(ref definition) => {
+                    let body = walk_and_replace(parse_condition(definition.clone()), data);
+                    theory::instantiate(&body, function, &args)
+                }
+                None => Expression::FunctionCall(name.clone(), args, function.result),
+            }
+        }
         _ => expression.clone(),
     }
 }
 
+// Integer constants, of operands and of the discriminants of variants
+fn gen_integral(const_int: &ConstInt) -> Expression {
+    match *const_int {
+        ConstInt::I8(i) => Expression::BitVector(i as i64, Types::I8),
+        ConstInt::I16(i) => Expression::BitVector(i as i64, Types::I16),
+        ConstInt::I32(i) => Expression::BitVector(i as i64, Types::I32),
+        ConstInt::I64(i) => Expression::BitVector(i as i64, Types::I64),
+        ConstInt::U8(i) => Expression::BitVector(i as i64, Types::U8),
+        ConstInt::U16(i) => Expression::BitVector(i as i64, Types::U16),
+        ConstInt::U32(i) => Expression::BitVector(i as i64, Types::U32),
+        ConstInt::U64(i) => Expression::BitVector(i as i64, Types::U64),
+        // Literals keep 64 bits, extended to 128 when encoded
+        ConstInt::I128(i) if i == i as i64 as i128 => Expression::BitVector(i as i64, Types::I128),
+        ConstInt::U128(i) if i == i as u64 as u128 => Expression::BitVector(i as i64, Types::U128),
+        ConstInt::I128(_) | ConstInt::U128(_) => {
+            error!("The constant `{:?}` does not fit in 64 bits, which 128-bit constants are \
+                    limited to",
+                   const_int)
+        }
+        ConstInt::Usize(ConstUsize::Us16(i)) => Expression::BitVector(i as i64, Types::Usize),
+        ConstInt::Usize(ConstUsize::Us32(i)) => Expression::BitVector(i as i64, Types::Usize),
+        ConstInt::Usize(ConstUsize::Us64(i)) => Expression::BitVector(i as i64, Types::Usize),
+        ConstInt::Isize(ConstIsize::Is16(i)) => Expression::BitVector(i as i64, Types::Isize),
+        ConstInt::Isize(ConstIsize::Is32(i)) => Expression::BitVector(i as i64, Types::Isize),
+        ConstInt::Isize(ConstIsize::Is64(i)) => Expression::BitVector(i as i64, Types::Isize),
+        _ => unimplemented!(),
+    }
+}
+
 fn gen_expression(operand: &Operand, data: &MirData) -> Expression {
     match *operand {
         Operand::Consume(ref l) => gen_lvalue(l.clone(), data),
@@ -467,19 +3173,11 @@ fn gen_expression(operand: &Operand, data: &MirData) -> Expression {
                 Literal::Value { ref value } => {
                     match *value {
                         ConstVal::Bool(ref const_bool) => Expression::BooleanLiteral(*const_bool),
-                        ConstVal::Integral(ref const_int) => {
-                            match *const_int {
-                                ConstInt::I8(i) => Expression::BitVector(i as i64, Types::I8),
-                                ConstInt::I16(i) => Expression::BitVector(i as i64, Types::I16),
-                                ConstInt::I32(i) => Expression::BitVector(i as i64, Types::I32),
-                                ConstInt::I64(i) => Expression::BitVector(i as i64, Types::I64),
-                                ConstInt::U8(i) => Expression::BitVector(i as i64, Types::U8),
-                                ConstInt::U16(i) => Expression::BitVector(i as i64, Types::U16),
-                                ConstInt::U32(i) => Expression::BitVector(i as i64, Types::U32),
-                                ConstInt::U64(i) => Expression::BitVector(i as i64, Types::U64),
-                                _ => unimplemented!(),
-                            }
-                        }
+                        ConstVal::Integral(ref const_int) => gen_integral(const_int),
+                        // A `char` is its scalar value
+                        ConstVal::Char(c) => Expression::BitVector(c as i64, Types::U32),
+                        ConstVal::Float(ConstFloat::F32(f)) => ast::float(f as f64, Types::F32),
+                        ConstVal::Float(ConstFloat::F64(f)) => ast::float(f, Types::F64),
                         _ => unimplemented!(),
                     }
                 }
@@ -489,42 +3187,385 @@ fn gen_expression(operand: &Operand, data: &MirData) -> Expression {
     }
 }
 
+thread_local! {
+    // Parsed conditions by their text, since generated functions and shared
+    // fragments repeat the same strings
+    static PARSED: RefCell<HashMap<String, Expression>> = RefCell::new(HashMap::new());
+}
+
 fn parse_condition(condition: String) -> Expression {
-    condition_parser::parse_Condition(&*condition).unwrap_or_else(|e| {
-                                                                      error!("Error parsing \
-                                                                              condition `{}` with \
-                                                                              error `{:?}`",
-                                                                             condition,
-                                                                             e)
-                                                                  })
+    if let Some(expression) = PARSED.with(|parsed| parsed.borrow().get(&condition).cloned()) {
+        return expression;
+    }
+
+    let expression = condition_parser::parse_Condition(&*condition).unwrap_or_else(|e| {
+        error!("Error parsing condition `{}` with error `{:?}`", condition, e)
+    });
+
+    PARSED.with(|parsed| parsed.borrow_mut().insert(condition, expression.clone()));
+    expression
+}
+
+const CONDITION_KEYS: &'static [&'static str] = &["pre", "post", "post_err", "invariant",
+                                                  "variant"];
+const CONDITION_FLAGS: &'static [&'static str] = &["no_panic"];
+
+// A condition as written, with the span of the attribute or argument
+// giving it
+struct Clause {
+    text: String,
+    span: Span,
 }
 
-fn parse_attributes(attrs: &[Attribute]) -> (String, String) {
-    let mut pre_string = "".to_string();
-    let mut post_string = "".to_string();
+fn guarded(guard: &str, clause: Clause) -> Clause {
+    Clause {
+        text: format!("{} => ({})", guard, clause.text),
+        span: clause.span,
+    }
+}
+
+// Several clauses hold together; each is parenthesized, so a clause with
+// a quantifier or an implication keeps its scope
+fn conjunction(clauses: &[Clause]) -> String {
+    match clauses.len() {
+        0 => "".to_string(),
+        1 => clauses[0].text.clone(),
+        _ => {
+            clauses.iter()
+                .map(|clause| format!("({})", clause.text))
+                .collect::<Vec<_>>()
+                .join(" && ")
+        }
+    }
+}
+
+fn span_error(sess: &Session, span: Span, message: &str, note: &str) -> ! {
+    sess.struct_span_err(span, message).note(note).emit();
+    sess.abort_if_errors();
+    unreachable!()
+}
+
+fn parse_clause_syntax(clause: &Clause, sess: &Session) -> Expression {
+    if let Some(expression) = PARSED.with(|parsed| parsed.borrow().get(&clause.text).cloned()) {
+        return expression;
+    }
+
+    let expression = condition_parser::parse_Condition(&clause.text).unwrap_or_else(|e| {
+        span_error(sess,
+                   clause.span,
+                   &format!("cannot parse the condition `{}`", clause.text),
+                   &format!("{:?}", e))
+    });
+
+    PARSED.with(|parsed| parsed.borrow_mut().insert(clause.text.clone(), expression.clone()));
+    expression
+}
+
+// A condition checked on its own, as a pledge, flow output, history
+// invariant or harness assumption, resolved against the body and reported
+// at the attribute giving it when it does not type
+fn resolve_clause(clause: &Clause, data: &MirData, sess: &Session) -> Expression {
+    let expression = walk_and_replace(parse_clause_syntax(clause, sess), data);
+
+    if let Err(e) = ast::ty_check(&expression) {
+        span_error(sess,
+                   clause.span,
+                   &format!("the condition `{}` is ill-typed", clause.text),
+                   &e);
+    }
+
+    expression
+}
+
+// Reports a precondition or postcondition that does not parse or is not a
+// well-typed boolean before the clauses are combined, at the subterm at
+// fault when it can be placed and at the attribute giving it otherwise
+fn check_clause(clause: &Clause, data: &MirData, sess: &Session) {
+    spans::begin();
+    let syntax = parse_clause_syntax(clause, sess);
+    spans::parsed();
+    let expression = walk_and_replace(syntax, data);
+    let at = |range: Option<(usize, usize)>| {
+        range.map_or(clause.span,
+                     |range| spans::locate(clause.span, &clause.text, range, sess.codemap()))
+    };
+
+    if let Err(e) = ast::ty_check(&expression) {
+        span_error(sess,
+                   at(spans::culprit(&expression)),
+                   &format!("the condition `{}` is ill-typed", clause.text),
+                   &e);
+    }
+
+    if ast::determine_evaluation_type(&expression) != Types::Bool {
+        span_error(sess,
+                   at(spans::range(&expression)),
+                   &format!("the condition `{}` is not boolean", clause.text),
+                   &format!("it is of type {:?}", ast::determine_evaluation_type(&expression)));
+    }
+
+    spans::finish();
+}
+
+// The precondition and postcondition of `#[requires]` and `#[ensures]`, or
+// of the deprecated `#[condition(pre = "...", post = "...")]`, as the
+// conjunction of every clause given. Either one alone leaves the other
+// `true`; both are empty without a contract
+fn parse_attributes(attrs: &[Attribute], config: &Config, codemap: &CodeMap) -> (String, String) {
+    let (pre_clauses, post_clauses) = contract_clauses(attrs, config, codemap);
+    let mut pre_string = conjunction(&pre_clauses);
+    let mut post_string = conjunction(&post_clauses);
+
+    if pre_string == "" && post_string != "" {
+        pre_string = "true".to_string();
+    } else if post_string == "" && pre_string != "" {
+        post_string = "true".to_string();
+    }
+
+    (pre_string, post_string)
+}
+
+fn contract_clauses(attrs: &[Attribute],
+                    config: &Config,
+                    codemap: &CodeMap)
+                    -> (Vec<Clause>, Vec<Clause>) {
+    let mut pre_clauses = Vec::new();
+    let mut post_clauses = Vec::new();
+    let mut err_clauses = Vec::new();
 
     for attr in attrs {
-        if let Some(ref items) = attr.meta_item_list() {
-            for item in items.iter() {
-                if let NestedMetaItemKind::MetaItem(ref i_string) = item.node {
-                    if let MetaItemKind::NameValue(ref literal) = i_string.node {
-                        if let syntax::ast::LitKind::Str(ref attr_param_value, _) = literal.node {
-                            match i_string.name.to_string().as_ref() {
-                                "pre" => pre_string = attr_param_value.to_string(),
-                                "post" => post_string = attr_param_value.to_string(),
-                                _ => {
-                                    error!("I only accept `pre` and `post`. You gave me `{}`",
-                                           i_string.name)
-                                }
-                            }
-                        }
+        if config.recognizes(attr, "requires") {
+            pre_clauses.push(Clause {
+                                 text: string_value(attr, "requires"),
+                                 span: attr.span,
+                             });
+        } else if config.recognizes(attr, "ensures") {
+            post_clauses.push(Clause {
+                                  text: string_value(attr, "ensures"),
+                                  span: attr.span,
+                              });
+        } else if config.recognizes(attr, "ensures_err") {
+            err_clauses.push(Clause {
+                                 text: string_value(attr, "ensures_err"),
+                                 span: attr.span,
+                             });
+        } else if config.recognizes(attr, "condition") {
+            let arguments = config::argument_list(attr, CONDITION_KEYS, CONDITION_FLAGS, codemap);
+
+            for (key, argument) in arguments {
+                let clause = Clause {
+                    text: argument.value,
+                    span: argument.span,
+                };
+
+                match &*key {
+                    "pre" => pre_clauses.push(clause),
+                    "post" => post_clauses.push(clause),
+                    "post_err" => err_clauses.push(clause),
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    // With a postcondition for errors, the others only cover `Ok` returns
+    if !err_clauses.is_empty() {
+        post_clauses = post_clauses.into_iter()
+            .map(|clause| guarded("ret.is_ok()", clause))
+            .chain(err_clauses.into_iter().map(|clause| guarded("ret.is_err()", clause)))
+            .collect();
+    }
+
+    (pre_clauses, post_clauses)
+}
+
+fn string_value(attr: &Attribute, name: &str) -> String {
+    attr.value_str()
+        .unwrap_or_else(|| error!("Expected `#[{} = \"condition\"]`", name))
+        .to_string()
+}
+
+// `#[invariant = "..."]` or `#[variant = "..."]`, or that key of a
+// `#[condition]`
+fn parse_clause(attrs: &[Attribute],
+                key: &str,
+                config: &Config,
+                codemap: &CodeMap)
+                -> Option<Clause> {
+    attrs.iter()
+        .filter_map(|attr| if config.recognizes(attr, key) {
+                        Some(Clause {
+                                 text: string_value(attr, key),
+                                 span: attr.span,
+                             })
+                    } else if config.recognizes(attr, "condition") {
+                        config::argument_list(attr, CONDITION_KEYS, CONDITION_FLAGS, codemap)
+                            .into_iter()
+                            .filter(|&(ref name, _)| name == key)
+                            .map(|(_, argument)| {
+                                     Clause {
+                                         text: argument.value,
+                                         span: argument.span,
+                                     }
+                                 })
+                            .last()
+                    } else {
+                        None
+                    })
+        .last()
+}
+
+// `#[no_panic]`, `#[condition(no_panic)]` or the `no_panic` option
+fn no_panic(attrs: &[Attribute], config: &Config, codemap: &CodeMap) -> bool {
+    config.no_panic ||
+    attrs.iter().any(|attr| {
+        config.recognizes(attr, "no_panic") ||
+        (config.recognizes(attr, "condition") &&
+         config::argument_list(attr, CONDITION_KEYS, CONDITION_FLAGS, codemap)
+             .iter()
+             .any(|&(ref name, _)| name == "no_panic"))
+    })
+}
+
+fn parse_contract_stable(attrs: &[Attribute],
+                         config: &Config,
+                         codemap: &CodeMap)
+                         -> Option<String> {
+    attrs.iter()
+        .filter(|attr| config.recognizes(attr, "contract_stable"))
+        .map(|attr| {
+            match config::arguments(attr, &["since"], &[], codemap).remove("since") {
+                Some(since) => since.value,
+                None => error!("Expected `#[contract_stable(since = \"version\")]`"),
+            }
+        })
+        .last()
+}
+
+// `#[harness]` or `#[harness(assume = "condition")]`, giving the assumption
+// on the symbolic inputs, empty when there is none
+fn parse_harness(attrs: &[Attribute], config: &Config, codemap: &CodeMap) -> Option<Clause> {
+    attrs.iter()
+        .filter(|attr| config.recognizes(attr, "harness"))
+        .map(|attr| {
+            let assume = if attr.is_word() {
+                None
+            } else {
+                config::arguments(attr, &["assume"], &[], codemap).remove("assume")
+            };
+
+            match assume {
+                Some(assume) => {
+                    Clause {
+                        text: assume.value,
+                        span: assume.span,
+                    }
+                }
+                None => {
+                    Clause {
+                        text: String::new(),
+                        span: attr.span,
                     }
                 }
             }
+        })
+        .last()
+}
+
+// Invariants relating the state of a struct before and after each method
+// taking it by `&mut self`
+fn history_invariants(tcx: TyCtxt, mir: &Mir, config: &Config) -> Vec<Clause> {
+    let receiver = match mir.args_iter().next() {
+        Some(arg) => &mir.local_decls[arg],
+        None => return Vec::new(),
+    };
+
+    if receiver.name.map_or(true, |name| &*name.as_str() != "self") {
+        return Vec::new();
+    }
+
+    match receiver.ty.sty {
+        TypeVariants::TyRef(_, ref referenced) if referenced.mutbl == hir::MutMutable => {
+            match referenced.ty.sty {
+                TypeVariants::TyAdt(adt, _) => {
+                    tcx.get_attrs(adt.did)
+                        .iter()
+                        .filter(|attr| config.recognizes(attr, "history_invariant"))
+                        .map(|attr| {
+                                 Clause {
+                                     text: string_value(attr, "history_invariant"),
+                                     span: attr.span,
+                                 }
+                             })
+                        .collect()
+                }
+                _ => Vec::new(),
+            }
         }
+        _ => Vec::new(),
     }
+}
 
-    (pre_string, post_string)
+fn is_public(tcx: TyCtxt, node_id: NodeId) -> bool {
+    match tcx.hir.get(node_id) {
+        hir_map::NodeItem(item) => item.vis == hir::Public,
+        hir_map::NodeImplItem(item) => item.vis == hir::Public,
+        // Trait methods are as visible as the trait itself
+        hir_map::NodeTraitItem(_) => true,
+        _ => false,
+    }
+}
+
+fn parse_constant_time(attrs: &[Attribute],
+                       config: &Config,
+                       codemap: &CodeMap)
+                       -> Option<Vec<String>> {
+    attrs.iter()
+        .filter(|attr| config.recognizes(attr, "constant_time"))
+        .map(|attr| {
+            match config::arguments(attr, &["secret"], &[], codemap).remove("secret") {
+                Some(secret) => {
+                    secret.value.split(',').map(|name| name.trim().to_string()).collect()
+                }
+                None => Vec::new(),
+            }
+        })
+        .last()
+}
+
+fn parse_flows(attrs: &[Attribute],
+               config: &Config,
+               codemap: &CodeMap)
+               -> Vec<self_composition::FlowContract> {
+    let mut flows = Vec::new();
+
+    for attr in attrs.iter().filter(|attr| config.recognizes(attr, "flows")) {
+        let arguments = config::arguments(attr, &["from", "to"], &["forbidden"], codemap);
+        let names = |key: &str| {
+            arguments.get(key).map_or(Vec::new(), |argument| {
+                argument.value.split(',').map(|name| name.trim().to_string()).collect()
+            })
+        };
+
+        let flow = self_composition::FlowContract {
+            from: names("from"),
+            to: names("to"),
+            span: arguments.get("to").map_or(attr.span, |argument| argument.span),
+            forbidden: arguments.contains_key("forbidden"),
+        };
+
+        if !flow.forbidden {
+            error!("Only `forbidden` flows can be verified");
+        }
+
+        if flow.from.is_empty() || flow.to.is_empty() {
+            error!("A flow contract needs both `from` and `to`");
+        }
+
+        flows.push(flow);
+    }
+
+    flows
 }
 
 pub trait Pred2SMT {
@@ -541,30 +3582,73 @@ impl Pred2SMT for SMTLib2<QF_AUFBV> {
     fn expr2smtlib(&mut self, vc: &Expression) -> Self::Idx {
         match *vc {
             Expression::BinaryExpression(ref left, ref op, ref right) => {
+                if let Some(lowered) = ast::boolean_ordering(left, *op, right) {
+                    return self.expr2smtlib(&lowered);
+                }
+
                 let l = self.expr2smtlib(left.as_ref());
                 let r = self.expr2smtlib(right.as_ref());
+                // Rust picks the signed or unsigned operation by the type of
+                // the operands, and bitwise operators act on bits unless
+                // they combine booleans
+                let operand = ast::determine_evaluation_type(left);
+                let signed = ast::is_signed(operand);
+                let boolean = operand == Types::Bool;
 
                 match *op {
                     BinaryOperator::Addition => self.assert(bitvec::OpCodes::BvAdd, &[l, r]),
                     BinaryOperator::Subtraction => self.assert(bitvec::OpCodes::BvSub, &[l, r]),
                     BinaryOperator::Multiplication => self.assert(bitvec::OpCodes::BvMul, &[l, r]),
-                    BinaryOperator::Division => self.assert(bitvec::OpCodes::BvSDiv, &[l, r]),
-                    BinaryOperator::Modulo => self.assert(bitvec::OpCodes::BvSMod, &[l, r]),
-                    BinaryOperator::BitwiseOr => self.assert(core::OpCodes::Or, &[l, r]),
-                    BinaryOperator::BitwiseAnd => self.assert(core::OpCodes::And, &[l, r]),
-                    BinaryOperator::BitwiseXor => self.assert(core::OpCodes::Xor, &[l, r]),
+                    BinaryOperator::Division if signed => {
+                        self.assert(bitvec::OpCodes::BvSDiv, &[l, r])
+                    }
+                    BinaryOperator::Division => self.assert(bitvec::OpCodes::BvUDiv, &[l, r]),
+                    // `%` is the remainder, taking the sign of the dividend
+                    BinaryOperator::Modulo if signed => {
+                        self.assert(bitvec::OpCodes::BvSRem, &[l, r])
+                    }
+                    BinaryOperator::Modulo => self.assert(bitvec::OpCodes::BvURem, &[l, r]),
+                    BinaryOperator::BitwiseOr if boolean => {
+                        self.assert(core::OpCodes::Or, &[l, r])
+                    }
+                    BinaryOperator::BitwiseOr => self.assert(bitvec::OpCodes::BvOr, &[l, r]),
+                    BinaryOperator::BitwiseAnd if boolean => {
+                        self.assert(core::OpCodes::And, &[l, r])
+                    }
+                    BinaryOperator::BitwiseAnd => self.assert(bitvec::OpCodes::BvAnd, &[l, r]),
+                    BinaryOperator::BitwiseXor if boolean => {
+                        self.assert(core::OpCodes::Xor, &[l, r])
+                    }
+                    BinaryOperator::BitwiseXor => self.assert(bitvec::OpCodes::BvXor, &[l, r]),
                     BinaryOperator::BitwiseLeftShift => {
                         self.assert(bitvec::OpCodes::BvShl, &[l, r])
                     }
-                    BinaryOperator::BitwiseRightShift => {
+                    BinaryOperator::BitwiseRightShift if signed => {
                         self.assert(bitvec::OpCodes::BvAShr, &[l, r])
                     }
-                    BinaryOperator::LessThan => self.assert(bitvec::OpCodes::BvSLt, &[l, r]),
-                    BinaryOperator::LessThanOrEqual => self.assert(bitvec::OpCodes::BvSLe, &[l, r]),
-                    BinaryOperator::GreaterThan => self.assert(bitvec::OpCodes::BvSGt, &[l, r]),
-                    BinaryOperator::GreaterThanOrEqual => {
+                    BinaryOperator::BitwiseRightShift => {
+                        self.assert(bitvec::OpCodes::BvLShr, &[l, r])
+                    }
+                    BinaryOperator::LessThan if signed => {
+                        self.assert(bitvec::OpCodes::BvSLt, &[l, r])
+                    }
+                    BinaryOperator::LessThan => self.assert(bitvec::OpCodes::BvULt, &[l, r]),
+                    BinaryOperator::LessThanOrEqual if signed => {
+                        self.assert(bitvec::OpCodes::BvSLe, &[l, r])
+                    }
+                    BinaryOperator::LessThanOrEqual => {
+                        self.assert(bitvec::OpCodes::BvULe, &[l, r])
+                    }
+                    BinaryOperator::GreaterThan if signed => {
+                        self.assert(bitvec::OpCodes::BvSGt, &[l, r])
+                    }
+                    BinaryOperator::GreaterThan => self.assert(bitvec::OpCodes::BvUGt, &[l, r]),
+                    BinaryOperator::GreaterThanOrEqual if signed => {
                         self.assert(bitvec::OpCodes::BvSGe, &[l, r])
                     }
+                    BinaryOperator::GreaterThanOrEqual => {
+                        self.assert(bitvec::OpCodes::BvUGe, &[l, r])
+                    }
                     BinaryOperator::Equal |
                     BinaryOperator::BiImplication => self.assert(core::OpCodes::Cmp, &[l, r]),
                     BinaryOperator::NotEqual => {
@@ -583,7 +3667,26 @@ impl Pred2SMT for SMTLib2<QF_AUFBV> {
                 let n = self.expr2smtlib(e.as_ref());
                 match *op {
                     UnaryOperator::Negation => self.assert(bitvec::OpCodes::BvNeg, &[n]),
-                    UnaryOperator::Not => self.assert(core::OpCodes::Not, &[n]),
+                    UnaryOperator::Not if ast::determine_evaluation_type(e) == Types::Bool => {
+                        self.assert(core::OpCodes::Not, &[n])
+                    }
+                    UnaryOperator::Not => self.assert(bitvec::OpCodes::BvNot, &[n]),
+                    UnaryOperator::Convert(ty) => {
+                        let source = ast::determine_evaluation_type(e);
+                        let (from, to) = (bitvector_size(source), bitvector_size(ty));
+                        let by = to.saturating_sub(from) as u64;
+
+                        // Narrowing keeps the low bits, as `as` does
+                        if to < from {
+                            self.assert(bitvec::OpCodes::Extract(to as u64 - 1, 0), &[n])
+                        } else if by == 0 {
+                            n
+                        } else if ast::same_signedness(source, Types::I64) {
+                            self.assert(bitvec::OpCodes::SignExtend(by), &[n])
+                        } else {
+                            self.assert(bitvec::OpCodes::ZeroExtend(by), &[n])
+                        }
+                    }
                 }
             }
             Expression::VariableMapping(ref v, ref ty) => {
@@ -591,33 +3694,97 @@ impl Pred2SMT for SMTLib2<QF_AUFBV> {
                 //    return self.get_by_name(&v);
                 //}
 
+                self.new_var(Some(&v), sort(*ty))
+            }
+            Expression::Array(ref v, ref ty) => {
+                let index: QF_AUFBV_Sorts = sort(Types::Usize).into();
+                let element: QF_AUFBV_Sorts = sort(*ty).into();
                 self.new_var(Some(&v),
-                             match *ty {
-                                 Types::Bool => bitvec::Sorts::Bool,
-                                 Types::Void | Types::Unknown => unimplemented!(),
-                                 _ => bitvec::Sorts::BitVector(bitvector_size(*ty)),
-                             })
+                             array_ex::Sorts::Array(Rc::new(index), Rc::new(element)))
+            }
+            Expression::Length(ref array) => {
+                self.new_var(Some(&format!("{}$len", ast::array_name(array))),
+                             sort(Types::Usize))
+            }
+            Expression::Select(ref array, ref index) => {
+                let a = self.expr2smtlib(array);
+                let i = self.expr2smtlib(index);
+                self.assert(array_ex::OpCodes::Select, &[a, i])
+            }
+            Expression::Store(ref array, ref index, ref value) => {
+                let a = self.expr2smtlib(array);
+                let i = self.expr2smtlib(index);
+                let v = self.expr2smtlib(value);
+                self.assert(array_ex::OpCodes::Store, &[a, i, v])
             }
             Expression::BooleanLiteral(ref b) => self.new_const(core::OpCodes::Const(*b)),
+            // 128-bit literals are kept in 64 bits, extended by their signedness
+            Expression::BitVector(ref value, ref ty) if bitvector_size(*ty) == 128 => {
+                let low = bv_const!(self, *value as u64, 64);
+
+                if ast::is_signed(*ty) {
+                    self.assert(bitvec::OpCodes::SignExtend(64), &[low])
+                } else {
+                    self.assert(bitvec::OpCodes::ZeroExtend(64), &[low])
+                }
+            }
             Expression::BitVector(ref value, ref size) => {
                 bv_const!(self, *value as u64, bitvector_size(*size))
             }
+            // Applications and quantifiers are eliminated before encoding, and
+            // conditions on floats are solved as SMT-LIB text
+            Expression::FunctionCall(..) |
+            Expression::Forall(..) |
+            Expression::Float(..) => unreachable!(),
         }
     }
 }
 
+fn sort(ty: Types) -> bitvec::Sorts {
+    match ty {
+        Types::Bool => bitvec::Sorts::Bool,
+        Types::Void | Types::Unknown => unimplemented!(),
+        _ => bitvec::Sorts::BitVector(bitvector_size(ty)),
+    }
+}
+
+fn milliseconds(duration: Duration) -> u64 {
+    duration.as_secs() * 1000 + duration.subsec_nanos() as u64 / 1000000
+}
+
 fn bitvector_size(ty: Types) -> usize {
     match ty {
         Types::I8 | Types::U8 => 8,
         Types::I16 | Types::U16 => 16,
         Types::I32 | Types::U32 => 32,
-        Types::I64 | Types::U64 => 64,
+        Types::I64 | Types::U64 | Types::Isize | Types::Usize => 64,
+        Types::I128 | Types::U128 => 128,
         _ => unreachable!(),
     }
 }
 
+// What a tool building on Stanley adds to it
+#[derive(Default)]
+pub struct Extensions {
+    pub generators: Vec<Box<ObligationGenerator>>,
+    pub theories: Vec<Theory>,
+}
+
 #[plugin_registrar]
 pub fn plugin_registrar(reg: &mut Registry) {
-    reg.register_attribute("condition".to_string(), AttributeType::Whitelisted);
-    reg.register_mir_pass(Box::new(StanleyMir {}));
+    register(reg, Extensions::default());
+}
+
+// Entry point for tools building on Stanley: their own plugin registrar calls
+// this instead of loading Stanley separately, adding their extensions
+pub fn register(reg: &mut Registry, extensions: Extensions) {
+    let config = Config::from_args(reg.args());
+
+    for name in ATTRIBUTES {
+        for spelling in config.spellings(name) {
+            reg.register_attribute(spelling, AttributeType::Whitelisted);
+        }
+    }
+
+    reg.register_mir_pass(Box::new(StanleyMir::new(config, extensions)));
 }
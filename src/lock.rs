@@ -0,0 +1,257 @@
+use {MirData, field_path, parse_condition, referenced, substitute_variable_with_expression,
+     walk_and_replace};
+use ast::{self, BinaryOperator, Expression};
+use rustc::hir::def_id::DefId;
+use rustc::mir::*;
+use rustc::ty::{Ty, TyCtxt, TypeVariants};
+use self_composition;
+use theory;
+use std::rc::Rc;
+
+// Inside a lock invariant, the data the mutex guards
+const GUARDED: &'static str = "data";
+
+pub enum Call {
+    // `Mutex::lock`, which waits for the lock and returns a guard
+    Lock,
+    // Unwrapping or dereferencing a guard, which passes on access to the data
+    Guard,
+}
+
+fn is_sync(tcx: TyCtxt, ty: Ty, name: &str) -> bool {
+    match ty.sty {
+        TypeVariants::TyAdt(adt, _) => tcx.item_path_str(adt.did) == format!("std::sync::{}", name),
+        _ => false,
+    }
+}
+
+pub fn is_guard(tcx: TyCtxt, ty: Ty) -> bool {
+    is_sync(tcx, ty, "MutexGuard")
+}
+
+// A guard itself, a borrow of one, or the `LockResult` holding one
+fn carries_guard(tcx: TyCtxt, ty: Ty) -> bool {
+    match referenced(ty).sty {
+        TypeVariants::TyAdt(_, substs) if !is_guard(tcx, referenced(ty)) => {
+            substs.types().next().map_or(false, |ty| is_guard(tcx, ty))
+        }
+        _ => is_guard(tcx, referenced(ty)),
+    }
+}
+
+pub fn call_kind(data: &MirData, def_id: DefId, args: &[Operand]) -> Option<Call> {
+    let receiver = match args.first() {
+        Some(receiver) => receiver.ty(data.mir, data.tcx),
+        None => return None,
+    };
+
+    match &*data.tcx.item_name(def_id).as_str() {
+        "lock" if is_sync(data.tcx, referenced(receiver), "Mutex") => Some(Call::Lock),
+        "unwrap" | "expect" | "deref" | "deref_mut" if carries_guard(data.tcx, receiver) => {
+            Some(Call::Guard)
+        }
+        _ => None,
+    }
+}
+
+fn assignment<'a, 'tcx>(local: Local, data: &MirData<'a, 'tcx>) -> Option<Rvalue<'tcx>> {
+    for block in &data.block_data {
+        for stmt in &block.statements {
+            if let StatementKind::Assign(Lvalue::Local(assigned), ref rvalue) = stmt.kind {
+                if assigned == local {
+                    return Some(rvalue.clone());
+                }
+            }
+        }
+    }
+
+    None
+}
+
+// The local an operand was moved, copied or borrowed from
+pub fn source<'a, 'tcx>(operand: &Operand<'tcx>, data: &MirData<'a, 'tcx>) -> Option<Local> {
+    let local = match *operand {
+        Operand::Consume(Lvalue::Local(local)) => local,
+        _ => return None,
+    };
+
+    match assignment(local, data) {
+        Some(Rvalue::Use(ref operand)) => source(operand, data),
+        Some(Rvalue::Ref(_, _, Lvalue::Local(borrowed))) => Some(borrowed),
+        _ => Some(local),
+    }
+}
+
+pub fn borrowed<'a, 'tcx>(operand: &Operand<'tcx>, data: &MirData<'a, 'tcx>) -> Option<Lvalue<'tcx>> {
+    match *operand {
+        Operand::Consume(Lvalue::Local(local)) => {
+            match assignment(local, data) {
+                Some(Rvalue::Ref(_, _, lvalue)) => Some(lvalue),
+                Some(Rvalue::Use(ref operand)) => borrowed(operand, data),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+fn invariant<'a, 'tcx>(mutex: &Lvalue<'tcx>,
+                       guarded: &Expression,
+                       data: &MirData<'a, 'tcx>)
+                       -> Option<Expression> {
+    let (base, field) = match *mutex {
+        Lvalue::Projection(ref pro) => {
+            match pro.elem {
+                ProjectionElem::Field(field, _) => (&pro.base, field),
+                _ => return None,
+            }
+        }
+        _ => return None,
+    };
+    let field_id = match referenced(base.ty(data.mir, data.tcx).to_ty(data.tcx)).sty {
+        TypeVariants::TyAdt(adt, _) if adt.is_struct() => {
+            adt.struct_variant().fields[field.index()].did
+        }
+        _ => return None,
+    };
+
+    data.tcx
+        .get_attrs(field_id)
+        .iter()
+        .find(|attr| data.config.recognizes(attr, "lock_invariant"))
+        .map(|attr| {
+            let condition = attr.value_str()
+                .unwrap_or_else(|| error!("Expected `#[lock_invariant = \"condition\"]`"))
+                .to_string();
+            let expression = theory::bind(&parse_condition(condition), GUARDED, guarded);
+            let expression = walk_and_replace(expression, data);
+            ast::ty_check(&expression).unwrap_or_else(|e| error!("{}", e));
+            expression
+        })
+}
+
+// The data a lock guard, or a borrow taken through one, gives access to,
+// together with the invariant of its mutex
+pub fn guarded(local: Local, data: &MirData) -> Option<(Expression, Option<Expression>)> {
+    for block in &data.block_data {
+        if let TerminatorKind::Call { ref func, ref args, ref destination, .. } =
+            block.terminator().kind {
+            match *destination {
+                Some((Lvalue::Local(dest), _)) if dest == local => {}
+                _ => continue,
+            }
+
+            let def_id = match ::function_def_id(func) {
+                Some(def_id) => def_id,
+                None => return None,
+            };
+
+            return match call_kind(data, def_id, args) {
+                Some(Call::Lock) => {
+                    let mutex = match borrowed(&args[0], data) {
+                        Some(mutex) => mutex,
+                        None => return None,
+                    };
+                    let (path, ty) = match field_path(&mutex, data) {
+                        Some(field) => field,
+                        None => return None,
+                    };
+                    let element = match ty.sty {
+                        TypeVariants::TyAdt(_, substs) => substs.type_at(0),
+                        _ => return None,
+                    };
+                    let var = Expression::VariableMapping(format!("{}.{}", path, GUARDED),
+                                                          ast::type_to_enum(element));
+                    let invariant = invariant(&mutex, &var, data);
+
+                    Some((var, invariant))
+                }
+                Some(Call::Guard) => source(&args[0], data).and_then(|guard| guarded(guard, data)),
+                None => None,
+            };
+        }
+    }
+
+    None
+}
+
+// Whether the function releases a lock, and so has invariants to restore
+pub fn releases(tcx: TyCtxt, mir: &Mir) -> bool {
+    mir.basic_blocks().iter().any(|block| {
+        match block.terminator().kind {
+            TerminatorKind::Drop { location: Lvalue::Local(local), .. } => {
+                is_guard(tcx, mir.local_decls[local].ty)
+            }
+            _ => false,
+        }
+    })
+}
+
+// Other threads may change the data while the lock is free, but only to
+// values satisfying the invariant
+fn havoc(wp: &Expression,
+         var: &Expression,
+         invariant: &Option<Expression>,
+         suffix: &str,
+         data: &MirData)
+         -> Expression {
+    let var = data.composition.rename(var);
+    let fresh = self_composition::rename_with(&var, suffix);
+    let wp = substitute_variable_with_expression(wp, &var, &fresh);
+
+    match *invariant {
+        Some(ref invariant) => {
+            let assumed = substitute_variable_with_expression(&data.composition.rename(invariant),
+                                                              &var,
+                                                              &fresh);
+            Expression::BinaryExpression(Rc::new(assumed),
+                                         BinaryOperator::Implication,
+                                         Rc::new(wp))
+        }
+        None => wp,
+    }
+}
+
+// Acquiring the lock: the guarded data is whatever other threads left,
+// which satisfies the invariant
+pub fn acquire(wp: &Expression,
+               guard: &Lvalue,
+               index: usize,
+               depth: usize,
+               data: &MirData)
+               -> Expression {
+    match *guard {
+        Lvalue::Local(local) => {
+            match guarded(local, data) {
+                Some((var, invariant)) => {
+                    havoc(wp, &var, &invariant, &format!("$acquired{}_{}", index, depth), data)
+                }
+                None => wp.clone(),
+            }
+        }
+        _ => wp.clone(),
+    }
+}
+
+// Releasing the lock, by dropping its guard, must restore the invariant
+pub fn release(wp: &Expression,
+               guard: Local,
+               index: usize,
+               depth: usize,
+               data: &MirData)
+               -> Expression {
+    match guarded(guard, data) {
+        Some((var, Some(invariant))) => {
+            let restored = data.composition.rename(&invariant);
+            let wp = havoc(wp,
+                           &var,
+                           &Some(invariant),
+                           &format!("$released{}_{}", index, depth),
+                           data);
+
+            Expression::BinaryExpression(Rc::new(restored), BinaryOperator::And, Rc::new(wp))
+        }
+        Some((var, None)) => havoc(wp, &var, &None, &format!("$released{}_{}", index, depth), data),
+        None => wp.clone(),
+    }
+}
@@ -0,0 +1,86 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs::File;
+use std::io::Write;
+
+// A public function whose contract was proved, and what the proof assumed
+pub struct Entry {
+    pub name: String,
+    pub pre: String,
+    pub post: String,
+    pub assumptions: Vec<String>,
+}
+
+// FNV-1a, so a hash is the same on every toolchain
+pub fn hash<I: Iterator<Item = u8>>(bytes: I) -> String {
+    let hash = bytes.fold(0xcbf29ce484222325u64,
+                          |h, b| (h ^ b as u64).wrapping_mul(0x100000001b3));
+
+    format!("{:016x}", hash)
+}
+
+fn contract_hash(pre: &str, post: &str) -> String {
+    hash(pre.bytes().chain(Some(0)).chain(post.bytes()))
+}
+
+pub fn quote(s: &str) -> String {
+    let mut quoted = String::from("\"");
+
+    for c in s.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\t' => quoted.push_str("\\t"),
+            c if (c as u32) < 0x20 => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+
+    quoted.push('"');
+    quoted
+}
+
+fn list<'a, I: Iterator<Item = &'a String>>(items: I) -> String {
+    items.map(|item| quote(item)).collect::<Vec<_>>().join(", ")
+}
+
+// The ledger lists everything the proofs of the run took for granted, with
+// the obligations relying on each, so a reviewer sees what a green run means
+pub fn save(path: &str,
+            entries: &[Entry],
+            ledger: &BTreeMap<String, BTreeSet<String>>,
+            toolchain: &str,
+            solver: &str) {
+    let mut file = File::create(path).unwrap_or_else(|e| error!("Cannot create `{}`: {}", path, e));
+
+    let functions = entries.iter()
+        .map(|entry| {
+            format!("    {{\"name\": {}, \"pre\": {}, \"post\": {}, \"contract\": {}, \
+                     \"assumptions\": [{}]}}",
+                    quote(&entry.name),
+                    quote(&entry.pre),
+                    quote(&entry.post),
+                    quote(&contract_hash(&entry.pre, &entry.post)),
+                    list(entry.assumptions.iter()))
+        })
+        .collect::<Vec<_>>()
+        .join(",\n");
+    let ledger = ledger.iter()
+        .map(|(assumption, labels)| {
+            format!("    {{\"assumption\": {}, \"obligations\": [{}]}}",
+                    quote(assumption),
+                    list(labels.iter()))
+        })
+        .collect::<Vec<_>>()
+        .join(",\n");
+
+    write!(file,
+           "{{\n  \"stanley\": {},\n  \"toolchain\": {},\n  \"solver\": {},\n  \
+            \"verified\": [\n{}\n  ],\n  \"ledger\": [\n{}\n  ]\n}}\n",
+           quote(env!("CARGO_PKG_VERSION")),
+           quote(toolchain),
+           quote(solver),
+           functions,
+           ledger)
+        .unwrap_or_else(|e| error!("Cannot write `{}`: {}", path, e));
+}
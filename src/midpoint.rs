@@ -0,0 +1,145 @@
+use ast::{self, BinaryOperator, Expression};
+use config::Solver;
+use rustproof_libsmt::backends::smtlib2::SMTRes;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+// Binary searches compute midpoints as `lo + (hi - lo) / 2` or, for the
+// floor average, `(a & b) + ((a ^ b) >> 1)`, and their proofs hinge on the
+// midpoint lying between the bounds, which the solver struggles to see
+// through the bit-level arithmetic of a large condition. Every midpoint
+// found gets that lemma, proved on its own first so that it is only ever
+// assumed where the encoding agrees
+
+thread_local! {
+    static PROVED: RefCell<HashMap<Expression, bool>> = RefCell::new(HashMap::new());
+}
+
+fn binary(left: Expression, op: BinaryOperator, right: Expression) -> Expression {
+    Expression::BinaryExpression(Rc::new(left), op, Rc::new(right))
+}
+
+fn is_literal(expression: &Expression, value: i64) -> bool {
+    match *expression {
+        Expression::BitVector(v, _) => v == value,
+        _ => false,
+    }
+}
+
+fn between(lo: &Expression, value: &Expression, hi: &Expression) -> Expression {
+    binary(binary(lo.clone(), BinaryOperator::LessThanOrEqual, value.clone()),
+           BinaryOperator::And,
+           binary(value.clone(), BinaryOperator::LessThanOrEqual, hi.clone()))
+}
+
+// `lo + (hi - lo) / 2`, also halved by a shift, as `(lo, hi)`
+fn offset_midpoint(expression: &Expression) -> Option<(&Expression, &Expression)> {
+    if let Expression::BinaryExpression(ref lo, BinaryOperator::Addition, ref half) = *expression {
+        if let Expression::BinaryExpression(ref difference, op, ref by) = **half {
+            let halved = match op {
+                BinaryOperator::Division => is_literal(by, 2),
+                BinaryOperator::BitwiseRightShift => is_literal(by, 1),
+                _ => false,
+            };
+
+            if let Expression::BinaryExpression(ref hi, BinaryOperator::Subtraction, ref base) =
+                **difference {
+                if halved && base == lo {
+                    return Some((lo, hi));
+                }
+            }
+        }
+    }
+
+    None
+}
+
+// `(a & b) + ((a ^ b) >> 1)`, in either operand order, as `(a, b)`
+fn floor_average(expression: &Expression) -> Option<(&Expression, &Expression)> {
+    if let Expression::BinaryExpression(ref both, BinaryOperator::Addition, ref half) =
+        *expression {
+        if let (&Expression::BinaryExpression(ref a, BinaryOperator::BitwiseAnd, ref b),
+                &Expression::BinaryExpression(ref either, BinaryOperator::BitwiseRightShift,
+                                              ref by)) = (&**both, &**half) {
+            if let Expression::BinaryExpression(ref x, BinaryOperator::BitwiseXor, ref y) =
+                **either {
+                if is_literal(by, 1) && ((x == a && y == b) || (x == b && y == a)) {
+                    return Some((a, b));
+                }
+            }
+        }
+    }
+
+    None
+}
+
+fn add(found: &mut Vec<Expression>, lemma: Expression) {
+    if !found.contains(&lemma) {
+        found.push(lemma);
+    }
+}
+
+fn candidates(expression: &Expression, found: &mut Vec<Expression>) {
+    // The difference wraps around for signed bounds far enough apart
+    if let Some((lo, hi)) = offset_midpoint(expression) {
+        let zero = Expression::BitVector(0, ast::determine_evaluation_type(hi));
+        let difference = binary(hi.clone(), BinaryOperator::Subtraction, lo.clone());
+        let apart = binary(binary(lo.clone(), BinaryOperator::LessThanOrEqual, hi.clone()),
+                           BinaryOperator::And,
+                           binary(zero, BinaryOperator::LessThanOrEqual, difference));
+        add(found,
+            binary(apart, BinaryOperator::Implication, between(lo, expression, hi)));
+    }
+
+    if let Some((a, b)) = floor_average(expression) {
+        let ordered = binary(binary(a.clone(), BinaryOperator::LessThanOrEqual, b.clone()),
+                             BinaryOperator::Implication,
+                             between(a, expression, b));
+        let reversed = binary(binary(b.clone(), BinaryOperator::LessThanOrEqual, a.clone()),
+                              BinaryOperator::Implication,
+                              between(b, expression, a));
+        add(found, binary(ordered, BinaryOperator::And, reversed));
+    }
+
+    match *expression {
+        Expression::BinaryExpression(ref left, _, ref right) => {
+            candidates(left, found);
+            candidates(right, found);
+        }
+        Expression::UnaryExpression(_, ref expr) |
+        Expression::Length(ref expr) => candidates(expr, found),
+        Expression::Select(ref array, ref index) => {
+            candidates(array, found);
+            candidates(index, found);
+        }
+        Expression::Store(ref array, ref index, ref value) => {
+            candidates(array, found);
+            candidates(index, found);
+            candidates(value, found);
+        }
+        _ => {}
+    }
+}
+
+fn proved(lemma: &Expression, solver: &Solver) -> bool {
+    if let Some(proved) = PROVED.with(|proved| proved.borrow().get(lemma).cloned()) {
+        return proved;
+    }
+
+    let proved = match ::solve_prepared(&ast::simplify_expression(lemma), solver) {
+        SMTRes::Unsat(..) => true,
+        _ => false,
+    };
+
+    PROVED.with(|cache| cache.borrow_mut().insert(lemma.clone(), proved));
+    proved
+}
+
+// The lemmas of the midpoints a prepared condition computes that hold
+pub fn lemmas(condition: &Expression, solver: &Solver) -> Vec<Expression> {
+    let mut found = Vec::new();
+    candidates(condition, &mut found);
+
+    found.into_iter().filter(|lemma| proved(lemma, solver)).collect()
+}
@@ -0,0 +1,82 @@
+use ast::{BinaryOperator, Expression, UnaryOperator};
+use std::rc::Rc;
+
+fn conjuncts(expression: &Expression) -> Vec<Expression> {
+    match *expression {
+        Expression::BinaryExpression(ref left, BinaryOperator::And, ref right) => {
+            let mut result = conjuncts(left);
+            result.extend(conjuncts(right));
+            result
+        }
+        _ => vec![expression.clone()],
+    }
+}
+
+fn conjunction(expressions: Vec<Expression>) -> Expression {
+    let mut iter = expressions.into_iter();
+    let first = iter.next().unwrap_or(Expression::BooleanLiteral(true));
+
+    iter.fold(first, |acc, e| {
+        Expression::BinaryExpression(Rc::new(acc), BinaryOperator::And, Rc::new(e))
+    })
+}
+
+fn count_literals(expression: &Expression) -> usize {
+    match *expression {
+        Expression::BinaryExpression(ref left, _, ref right) => {
+            count_literals(left) + count_literals(right)
+        }
+        Expression::UnaryExpression(_, ref expr) => count_literals(expr),
+        Expression::BitVector(..) => 1,
+        _ => 0,
+    }
+}
+
+fn offset_literal(expression: &Expression,
+                  target: usize,
+                  delta: i64,
+                  seen: &mut usize)
+                  -> Expression {
+    match *expression {
+        Expression::BinaryExpression(ref left, ref op, ref right) => {
+            let new_left = offset_literal(left, target, delta, seen);
+            let new_right = offset_literal(right, target, delta, seen);
+            Expression::BinaryExpression(Rc::new(new_left), *op, Rc::new(new_right))
+        }
+        Expression::UnaryExpression(ref op, ref expr) => {
+            Expression::UnaryExpression(op.clone(),
+                                        Rc::new(offset_literal(expr, target, delta, seen)))
+        }
+        Expression::BitVector(value, ty) => {
+            *seen += 1;
+
+            if *seen - 1 == target {
+                Expression::BitVector(value + delta, ty)
+            } else {
+                expression.clone()
+            }
+        }
+        _ => expression.clone(),
+    }
+}
+
+// Every mutant is a postcondition that a strong specification should no
+// longer satisfy: one conjunct negated, or one constant moved by one
+pub fn mutants(post: &Expression) -> Vec<Expression> {
+    let mut result = Vec::new();
+    let parts = conjuncts(post);
+
+    for i in 0..parts.len() {
+        let mut mutated = parts.clone();
+        mutated[i] = Expression::UnaryExpression(UnaryOperator::Not, Rc::new(parts[i].clone()));
+        result.push(conjunction(mutated));
+    }
+
+    for i in 0..count_literals(post) {
+        for delta in &[1, -1] {
+            result.push(offset_literal(post, i, *delta, &mut 0));
+        }
+    }
+
+    result
+}
@@ -0,0 +1,448 @@
+use {MirData, assign, gen_expression, gen_lvalue};
+use ast::{self, BinaryOperator, Expression, Types, UnaryOperator};
+use float;
+use option;
+use rustc::hir::def_id::DefId;
+use rustc::mir::*;
+use self_composition::{self, Composition};
+use std::rc::Rc;
+
+// The numeric methods of the standard library everyday code calls are
+// modelled by what they return rather than inlined: `checked_div` is
+// `None` exactly when dividing would panic or overflow, `abs` of the
+// minimum panics and `clamp` of an empty range panics, like failed
+// assertions, while `pow` wraps like the other arithmetic. `ilog2`,
+// `isqrt` and `next_power_of_two` are defined by axioms, which also give
+// their meaning in specifications. `is_nan`, `is_infinite` and
+// `is_finite` classify floats
+
+pub enum Method {
+    CheckedDiv,
+    Min,
+    Max,
+    Clamp,
+    Abs,
+    Pow,
+    Defined(&'static str),
+    Classify(&'static str),
+}
+
+// Names the result of a call, which is only known through its definition
+const VALUE_SUFFIX: &'static str = "$value";
+// The exponent of `pow` is a `u32`
+const EXPONENT_BITS: u32 = 32;
+const DEFINED: [&'static str; 3] = ["ilog2", "isqrt", "next_power_of_two"];
+
+fn binary(left: Expression, op: BinaryOperator, right: Expression) -> Expression {
+    Expression::BinaryExpression(Rc::new(left), op, Rc::new(right))
+}
+
+fn not(expression: Expression) -> Expression {
+    Expression::UnaryExpression(UnaryOperator::Not, Rc::new(expression))
+}
+
+fn equal(left: Expression, right: Expression) -> Expression {
+    binary(left, BinaryOperator::Equal, right)
+}
+
+// `then` when `condition` holds and `otherwise` when it does not
+pub fn cases(condition: Expression, then: Expression, otherwise: Expression) -> Expression {
+    binary(binary(condition.clone(), BinaryOperator::Implication, then),
+           BinaryOperator::And,
+           binary(not(condition), BinaryOperator::Implication, otherwise))
+}
+
+fn is_integer(ty: Types) -> bool {
+    match ty {
+        Types::Bool | Types::Void | Types::Unknown | Types::F32 | Types::F64 => false,
+        _ => true,
+    }
+}
+
+fn minimum(ty: Types) -> Expression {
+    ast::type_constant(ty, "MIN")
+}
+
+// Literals only keep 64 bits, so larger powers are shifted into place
+fn power_of_two(k: usize, ty: Types) -> Expression {
+    if k < 64 {
+        Expression::BitVector((1u64 << k) as i64, ty)
+    } else {
+        binary(Expression::BitVector(1, ty),
+               BinaryOperator::BitwiseLeftShift,
+               Expression::BitVector(k as i64, ty))
+    }
+}
+
+// The largest value whose square the type can hold
+fn largest_root(ty: Types) -> Expression {
+    let root = match (::bitvector_size(ty), ast::is_signed(ty)) {
+        (8, true) => 11,
+        (16, true) => 181,
+        (32, true) => 46340,
+        (64, true) => 3037000499,
+        // Beyond `i64::MAX`, so extended from an unsigned literal
+        (128, true) => {
+            return Expression::UnaryExpression(UnaryOperator::Convert(ty),
+                                               Rc::new(Expression::BitVector(-5402926248376769404,
+                                                                             Types::U64)))
+        }
+        (128, false) => -1,
+        (bits, false) => ((1u64 << (bits / 2)) - 1) as i64,
+        _ => unreachable!(),
+    };
+
+    Expression::BitVector(root, ty)
+}
+
+fn result_type(function: &str, ty: Types) -> Types {
+    if function == "ilog2" { Types::U32 } else { ty }
+}
+
+// What `x` must be for `function` not to panic
+fn domain(function: &str, x: &Expression, ty: Types) -> Expression {
+    let bits = ::bitvector_size(ty);
+
+    match function {
+        "ilog2" => binary(x.clone(), BinaryOperator::GreaterThan, Expression::BitVector(0, ty)),
+        "isqrt" => {
+            binary(x.clone(),
+                   BinaryOperator::GreaterThanOrEqual,
+                   Expression::BitVector(0, ty))
+        }
+        _ => {
+            binary(x.clone(),
+                   BinaryOperator::LessThanOrEqual,
+                   power_of_two(bits - 1, ty))
+        }
+    }
+}
+
+// The value of `function` at `x` inside its domain, as a condition on
+// `result`; the cases are spelled out for every bit, as shifting by the
+// result would mix types
+fn definition(function: &str, x: &Expression, result: &Expression, ty: Types) -> Expression {
+    let bits = ::bitvector_size(ty) as i64;
+    let mut definition = Expression::BooleanLiteral(true);
+
+    match function {
+        "ilog2" => {
+            for k in 0..bits {
+                let top = equal(binary(x.clone(),
+                                       BinaryOperator::BitwiseRightShift,
+                                       Expression::BitVector(k, ty)),
+                                Expression::BitVector(1, ty));
+                definition = binary(definition,
+                                    BinaryOperator::And,
+                                    binary(top,
+                                           BinaryOperator::Implication,
+                                           equal(result.clone(),
+                                                 Expression::BitVector(k, Types::U32))));
+            }
+        }
+        "isqrt" => {
+            let root = largest_root(ty);
+            let square = |r: Expression| binary(r.clone(), BinaryOperator::Multiplication, r);
+            let next = binary(result.clone(),
+                              BinaryOperator::Addition,
+                              Expression::BitVector(1, ty));
+
+            definition = binary(binary(binary(Expression::BitVector(0, ty),
+                                              BinaryOperator::LessThanOrEqual,
+                                              result.clone()),
+                                       BinaryOperator::And,
+                                       binary(result.clone(),
+                                              BinaryOperator::LessThanOrEqual,
+                                              root.clone())),
+                                BinaryOperator::And,
+                                binary(binary(square(result.clone()),
+                                              BinaryOperator::LessThanOrEqual,
+                                              x.clone()),
+                                       BinaryOperator::And,
+                                       binary(equal(result.clone(), root),
+                                              BinaryOperator::Or,
+                                              binary(x.clone(),
+                                                     BinaryOperator::LessThan,
+                                                     square(next)))));
+        }
+        _ => {
+            for k in 0..bits {
+                let power = power_of_two(k as usize, ty);
+                let mut fits = binary(x.clone(), BinaryOperator::LessThanOrEqual, power.clone());
+
+                if k > 0 {
+                    fits = binary(fits,
+                                  BinaryOperator::And,
+                                  binary(x.clone(),
+                                         BinaryOperator::GreaterThan,
+                                         power_of_two(k as usize - 1, ty)));
+                }
+
+                definition = binary(definition,
+                                    BinaryOperator::And,
+                                    binary(fits,
+                                           BinaryOperator::Implication,
+                                           equal(result.clone(), power)));
+            }
+        }
+    }
+
+    definition
+}
+
+// `ilog2(x)`, `isqrt(x)` and `next_power_of_two(x)` in specifications,
+// with their argument resolved; `None` for other functions
+pub fn expand(name: &str, args: &[Expression]) -> Option<Expression> {
+    let function = match DEFINED.iter().find(|&&function| function == name) {
+        Some(function) => *function,
+        None => return None,
+    };
+
+    if args.len() != 1 {
+        error!("`{}` expects 1 arguments, found {}", name, args.len());
+    }
+
+    let ty = ast::determine_evaluation_type(&args[0]);
+
+    if !is_integer(ty) {
+        error!("`{}` expects an integer of known type, found `{}`", name, args[0]);
+    }
+
+    if function == "next_power_of_two" && ast::is_signed(ty) {
+        error!("`{}` expects an unsigned integer, found `{}`", name, args[0]);
+    }
+
+    Some(Expression::FunctionCall(function.to_string(), args.to_vec(), result_type(function, ty)))
+}
+
+// What is known about an application of one of the functions `expand`
+// introduces; `None` for other applications
+pub fn axiom(application: &Expression) -> Option<Expression> {
+    match *application {
+        Expression::FunctionCall(ref name, ref args, _) if DEFINED.contains(&&**name) => {
+            let ty = ast::determine_evaluation_type(&args[0]);
+
+            Some(binary(domain(name, &args[0], ty),
+                        BinaryOperator::Implication,
+                        definition(name, &args[0], application, ty)))
+        }
+        _ => None,
+    }
+}
+
+pub fn method(data: &MirData, def_id: DefId, args: &[Operand]) -> Option<Method> {
+    if def_id.is_local() {
+        return None;
+    }
+
+    let item_name = data.tcx.item_name(def_id).as_str();
+
+    match args.first() {
+        Some(receiver) if receiver.ty(data.mir, data.tcx).is_fp() && args.len() == 1 => {
+            return float::PREDICATES
+                .iter()
+                .find(|&&predicate| predicate == &*item_name)
+                .map(|predicate| Method::Classify(*predicate));
+        }
+        _ => {}
+    }
+
+    let ty = match args.first() {
+        Some(receiver) if receiver.ty(data.mir, data.tcx).is_integral() => {
+            ast::type_to_enum(receiver.ty(data.mir, data.tcx))
+        }
+        _ => return None,
+    };
+
+    match (&*item_name, args.len()) {
+        ("checked_div", 2) => Some(Method::CheckedDiv),
+        ("min", 2) => Some(Method::Min),
+        ("max", 2) => Some(Method::Max),
+        ("clamp", 3) => Some(Method::Clamp),
+        ("abs", 1) if ast::is_signed(ty) => Some(Method::Abs),
+        ("pow", 2) => Some(Method::Pow),
+        ("ilog2", 1) => Some(Method::Defined("ilog2")),
+        ("isqrt", 1) => Some(Method::Defined("isqrt")),
+        ("next_power_of_two", 1) if !ast::is_signed(ty) => {
+            Some(Method::Defined("next_power_of_two"))
+        }
+        _ => None,
+    }
+}
+
+// Conditions on fresh variables hold in every run they are renamed into
+pub fn assume(definition: Expression, wp: Expression, data: &MirData) -> Expression {
+    let definition = match data.composition {
+        Composition::Lockstep => {
+            binary(definition.clone(),
+                   BinaryOperator::And,
+                   self_composition::rename(&definition))
+        }
+        composition => composition.rename(&definition),
+    };
+
+    binary(definition, BinaryOperator::Implication, wp)
+}
+
+// A panic fails a single run, so what avoids it must hold whenever the
+// precondition does
+fn require(check: Expression, wp: Expression, data: &MirData) -> Expression {
+    if data.composition.is_relational() || data.probe.is_some() {
+        wp
+    } else {
+        binary(data.composition.rename(&check), BinaryOperator::And, wp)
+    }
+}
+
+// `base` to the power `exponent` by squaring: the squares and the factors
+// the bits of the exponent select are fresh variables, as writing them out
+// would double the term with every square
+fn power(base: &Expression,
+         exponent: &Expression,
+         result: &Expression,
+         ty: Types)
+         -> Expression {
+    let one = Expression::BitVector(1, ty);
+    let named = |kind: &str, bit: u32| {
+        self_composition::rename_with(result, &format!("${}{}", kind, bit))
+    };
+
+    // A literal exponent selects its factors itself
+    let (bits, known) = match *exponent {
+        Expression::BitVector(value, _) => {
+            let value = value as u32;
+            (EXPONENT_BITS - value.leading_zeros(), Some(value))
+        }
+        _ => (EXPONENT_BITS, None),
+    };
+
+    let mut definition = Expression::BooleanLiteral(true);
+    let mut square = base.clone();
+    let mut product = one.clone();
+
+    for bit in 0..bits {
+        if bit > 0 {
+            let previous = square;
+            square = named("square", bit);
+            definition = binary(definition,
+                                BinaryOperator::And,
+                                equal(square.clone(),
+                                      binary(previous.clone(),
+                                             BinaryOperator::Multiplication,
+                                             previous)));
+        }
+
+        let factor = match known {
+            Some(value) if value & (1 << bit) == 0 => continue,
+            Some(_) => square.clone(),
+            None => {
+                let factor = named("factor", bit);
+                let selected = binary(binary(binary(exponent.clone(),
+                                                    BinaryOperator::BitwiseRightShift,
+                                                    Expression::BitVector(bit as i64,
+                                                                          Types::U32)),
+                                             BinaryOperator::BitwiseAnd,
+                                             Expression::BitVector(1, Types::U32)),
+                                      BinaryOperator::Equal,
+                                      Expression::BitVector(1, Types::U32));
+                definition = binary(definition,
+                                    BinaryOperator::And,
+                                    cases(selected,
+                                          equal(factor.clone(), square.clone()),
+                                          equal(factor.clone(), one.clone())));
+                factor
+            }
+        };
+
+        product = if product == one {
+            factor
+        } else {
+            binary(product, BinaryOperator::Multiplication, factor)
+        };
+    }
+
+    binary(definition, BinaryOperator::And, equal(result.clone(), product))
+}
+
+pub fn call(wp: &Expression,
+            method: Method,
+            args: &[Operand],
+            destination: Lvalue,
+            index: usize,
+            depth: usize,
+            data: &MirData)
+            -> Expression {
+    let ty = ast::type_to_enum(args[0].ty(data.mir, data.tcx));
+    let args = args.iter().map(|arg| gen_expression(arg, data)).collect::<Vec<_>>();
+    let dest = gen_lvalue(destination, data);
+    let suffix = format!("{}{}_{}", VALUE_SUFFIX, index, depth);
+    let result = self_composition::rename_with(&dest, &suffix);
+    let a = args[0].clone();
+
+    match method {
+        Method::CheckedDiv => {
+            let b = args[1].clone();
+            let mut defined = not(equal(b.clone(), Expression::BitVector(0, ty)));
+
+            if ast::is_signed(ty) {
+                let overflows = binary(equal(a.clone(), minimum(ty)),
+                                       BinaryOperator::And,
+                                       equal(b.clone(), Expression::BitVector(-1, ty)));
+                defined = binary(defined, BinaryOperator::And, not(overflows));
+            }
+
+            let wp = assign(wp, &dest, &binary(a, BinaryOperator::Division, b), data);
+
+            assign(&wp, &option::tag(&dest), &defined, data)
+        }
+        Method::Min | Method::Max => {
+            let b = args[1].clone();
+            let (first, second) = (equal(result.clone(), a.clone()),
+                                   equal(result.clone(), b.clone()));
+            let ordered = binary(a, BinaryOperator::LessThanOrEqual, b);
+            let definition = match method {
+                Method::Min => cases(ordered, first, second),
+                _ => cases(ordered, second, first),
+            };
+
+            assume(definition, assign(wp, &dest, &result, data), data)
+        }
+        Method::Clamp => {
+            let (lo, hi) = (args[1].clone(), args[2].clone());
+            let definition = cases(binary(a.clone(), BinaryOperator::LessThan, lo.clone()),
+                                   equal(result.clone(), lo.clone()),
+                                   cases(binary(hi.clone(), BinaryOperator::LessThan, a.clone()),
+                                         equal(result.clone(), hi.clone()),
+                                         equal(result.clone(), a)));
+            let wp = assume(definition, assign(wp, &dest, &result, data), data);
+
+            require(binary(lo, BinaryOperator::LessThanOrEqual, hi), wp, data)
+        }
+        Method::Abs => {
+            let negative = binary(a.clone(),
+                                  BinaryOperator::LessThan,
+                                  Expression::BitVector(0, ty));
+            let definition = cases(negative,
+                                   equal(result.clone(),
+                                         Expression::UnaryExpression(UnaryOperator::Negation,
+                                                                     Rc::new(a.clone()))),
+                                   equal(result.clone(), a.clone()));
+            let wp = assume(definition, assign(wp, &dest, &result, data), data);
+
+            require(not(equal(a, minimum(ty))), wp, data)
+        }
+        Method::Pow => {
+            let definition = power(&a, &args[1], &result, ty);
+
+            assume(definition, assign(wp, &dest, &result, data), data)
+        }
+        Method::Defined(function) => {
+            let wp = assume(definition(function, &a, &result, ty),
+                            assign(wp, &dest, &result, data),
+                            data);
+
+            require(domain(function, &a, ty), wp, data)
+        }
+        Method::Classify(predicate) => assign(wp, &dest, &float::predicate(predicate, &a), data),
+    }
+}
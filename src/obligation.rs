@@ -0,0 +1,12 @@
+use MirData;
+use ast::Expression;
+
+// Downstream tools implement this to add their own obligations to every
+// verified function. Each obligation is a formula that must be valid; it is
+// solved, cached and reported like Stanley's own checks.
+pub trait ObligationGenerator {
+    fn name(&self) -> &str;
+
+    // `pre` is the function's precondition, or `true` when it has none
+    fn obligations(&self, data: &MirData, pre: &Expression) -> Vec<(String, Expression)>;
+}
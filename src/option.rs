@@ -0,0 +1,223 @@
+use {MirData, assign, field_type, gen_expression, gen_lvalue, walk_and_replace, with_type};
+use ast::{self, BinaryOperator, Expression, Types, UnaryOperator};
+use rustc::hir::def_id::DefId;
+use rustc::mir::*;
+use rustc::ty::Ty;
+use std::rc::Rc;
+
+// An `Option` is modelled by its payload, named after the option itself,
+// and a boolean tag `name$some` telling whether it is `Some`; the payload
+// of `None` is unconstrained
+
+pub enum Method {
+    Unwrap,
+    IsSome,
+    IsNone,
+}
+
+enum Constructor<'e> {
+    None,
+    Some(&'e Expression),
+}
+
+fn binary(left: Expression, op: BinaryOperator, right: Expression) -> Expression {
+    Expression::BinaryExpression(Rc::new(left), op, Rc::new(right))
+}
+
+fn not(expression: Expression) -> Expression {
+    Expression::UnaryExpression(UnaryOperator::Not, Rc::new(expression))
+}
+
+pub fn tag(payload: &Expression) -> Expression {
+    match *payload {
+        Expression::VariableMapping(ref name, _) => {
+            Expression::VariableMapping(format!("{}$some", name), Types::Bool)
+        }
+        _ => error!("Only options held in variables and fields are supported, found `{}`", payload),
+    }
+}
+
+pub fn holds_option(lvalue: &Lvalue, data: &MirData) -> bool {
+    ast::is_option(lvalue.ty(data.mir, data.tcx).to_ty(data.tcx))
+}
+
+// The type of a variable of a specification, of an argument, a field path
+// or `ret`
+pub fn specified_type<'a, 'tcx>(expression: &Expression,
+                                data: &MirData<'a, 'tcx>)
+                                -> Option<Ty<'tcx>> {
+    let name = match *expression {
+        Expression::VariableMapping(ref name, _) => name,
+        _ => return None,
+    };
+
+    if name == "ret" {
+        Some(data.mir.return_ty)
+    } else if name.contains('.') {
+        field_type(name, data)
+    } else {
+        data.mir
+            .args_iter()
+            .map(|arg| &data.mir.local_decls[arg])
+            .find(|decl| decl.name.map_or(false, |n| &*n.as_str() == name))
+            .map(|decl| decl.ty)
+    }
+}
+
+// Whether a variable of a specification holds an option
+fn is_specified_option(expression: &Expression, data: &MirData) -> bool {
+    specified_type(expression, data).map_or(false, ast::is_option)
+}
+
+fn constructor(expression: &Expression) -> Option<Constructor> {
+    match *expression {
+        Expression::VariableMapping(ref name, _) if name == "None" => Some(Constructor::None),
+        Expression::FunctionCall(ref name, ref args, _) if name == "Some" && args.len() == 1 => {
+            Some(Constructor::Some(&args[0]))
+        }
+        _ => None,
+    }
+}
+
+fn matches(option: &Expression, constructor: Constructor, data: &MirData) -> Expression {
+    if !is_specified_option(option, data) {
+        error!("`{}` is compared against `Some` or `None`, but is not an option", option);
+    }
+
+    let option = walk_and_replace(option.clone(), data);
+
+    match constructor {
+        Constructor::None => not(tag(&option)),
+        Constructor::Some(payload) => {
+            let payload = with_type(walk_and_replace(payload.clone(), data),
+                                    ast::determine_evaluation_type(&option));
+            binary(tag(&option),
+                   BinaryOperator::And,
+                   binary(option, BinaryOperator::Equal, payload))
+        }
+    }
+}
+
+// `o == Some(x)`, `o == None` and comparisons of two options in
+// specifications, before their operands are resolved; `None` for other
+// comparisons
+pub fn equality(left: &Expression,
+                op: BinaryOperator,
+                right: &Expression,
+                data: &MirData)
+                -> Option<Expression> {
+    if op != BinaryOperator::Equal && op != BinaryOperator::NotEqual {
+        return None;
+    }
+
+    let equal = match (constructor(left), constructor(right)) {
+        (Some(_), Some(_)) => error!("Compare an option against `Some` or `None`, not two of them"),
+        (Some(constructor), None) => matches(right, constructor, data),
+        (None, Some(constructor)) => matches(left, constructor, data),
+        (None, None) => {
+            if !is_specified_option(left, data) || !is_specified_option(right, data) {
+                return None;
+            }
+
+            let left = walk_and_replace(left.clone(), data);
+            let right = walk_and_replace(right.clone(), data);
+
+            // The same tag, and the same payload when both are `Some`
+            binary(binary(tag(&left), BinaryOperator::Equal, tag(&right)),
+                   BinaryOperator::And,
+                   binary(tag(&left),
+                          BinaryOperator::Implication,
+                          binary(left, BinaryOperator::Equal, right)))
+        }
+    };
+
+    Some(if op == BinaryOperator::NotEqual { not(equal) } else { equal })
+}
+
+// `is_some`, `is_none` and `unwrap` on options in specifications, with
+// their arguments resolved
+pub fn expand(name: &str, args: &[Expression], data: &MirData) -> Option<Expression> {
+    if args.len() != 1 || !is_specified_option(&args[0], data) {
+        return None;
+    }
+
+    match name {
+        "is_some" => Some(tag(&args[0])),
+        "is_none" => Some(not(tag(&args[0]))),
+        "unwrap" => Some(args[0].clone()),
+        _ => None,
+    }
+}
+
+pub fn method(data: &MirData, def_id: DefId, args: &[Operand]) -> Option<Method> {
+    match args.first() {
+        Some(receiver) if ast::is_option(receiver.ty(data.mir, data.tcx)) => {}
+        _ => return None,
+    }
+
+    match &*data.tcx.item_name(def_id).as_str() {
+        "unwrap" | "expect" => Some(Method::Unwrap),
+        "is_some" => Some(Method::IsSome),
+        "is_none" => Some(Method::IsNone),
+        _ => None,
+    }
+}
+
+// Unwrapping `None` panics, so like an assertion, a single run must show
+// the option is `Some` whenever the precondition holds
+pub fn call(wp: &Expression,
+            method: Method,
+            args: &[Operand],
+            destination: Lvalue,
+            data: &MirData)
+            -> Expression {
+    let receiver = gen_expression(&args[0], data);
+    let result = match method {
+        Method::Unwrap => receiver.clone(),
+        Method::IsSome => tag(&receiver),
+        Method::IsNone => not(tag(&receiver)),
+    };
+    let wp = assign(wp, &gen_lvalue(destination, data), &result, data);
+
+    match method {
+        Method::Unwrap if !data.composition.is_relational() && data.probe.is_none() => {
+            binary(data.composition.rename(&tag(&receiver)), BinaryOperator::And, wp)
+        }
+        _ => wp,
+    }
+}
+
+// Applied after the statement's own weakest precondition, which covers the
+// payload: constructing an option sets its tag, and copying, moving or
+// borrowing a whole option carries the tag along
+pub fn statement(wp: Expression, stmt: &Statement, data: &MirData) -> Expression {
+    match stmt.kind {
+        StatementKind::Assign(ref lvalue, ref rvalue) => {
+            if !holds_option(lvalue, data) {
+                return wp;
+            }
+
+            let source = match *rvalue {
+                Rvalue::Aggregate(AggregateKind::Adt(_, variant, _, _), _) => {
+                    Expression::BooleanLiteral(variant == 1)
+                }
+                Rvalue::Use(Operand::Consume(ref source)) |
+                Rvalue::Ref(_, _, ref source) => tag(&gen_lvalue(source.clone(), data)),
+                _ => return wp,
+            };
+
+            assign(&wp, &tag(&gen_lvalue(lvalue.clone(), data)), &source, data)
+        }
+        StatementKind::SetDiscriminant { ref lvalue, variant_index } => {
+            if !holds_option(lvalue, data) {
+                return wp;
+            }
+
+            assign(&wp,
+                   &tag(&gen_lvalue(lvalue.clone(), data)),
+                   &Expression::BooleanLiteral(variant_index == 1),
+                   data)
+        }
+        _ => wp,
+    }
+}
@@ -0,0 +1,152 @@
+use {MirData, assign, gen_expression, gen_lvalue, referenced};
+use ast::{self, BinaryOperator, Expression, UnaryOperator};
+use enums;
+use float;
+use numeric::{assume, cases};
+use option;
+use rustc::hir::def_id::DefId;
+use rustc::mir::*;
+use rustc::ty::{AdtDef, Ty, TypeVariants};
+use self_composition;
+use std::rc::Rc;
+
+// `Ordering` is an enum like the others, modelled by its discriminant.
+// `cmp` on integers, chars and booleans sets the discriminant of its result
+// to that of `Less`, `Equal` or `Greater` as its arguments compare, and
+// `partial_cmp` does the same inside a `Some`, which for floats is `None`
+// when either argument is NaN. `then` and `reverse` chain and flip
+// orderings. A `match` on the result switches on the discriminant like any
+// other, so comparator-based code is verified through the comparisons
+
+pub enum Method {
+    Cmp,
+    PartialCmp,
+    Then,
+    Reverse,
+}
+
+// The variants of `Ordering` in order
+const LESS: usize = 0;
+const EQUAL: usize = 1;
+const GREATER: usize = 2;
+
+// Names the result of a call, which is only known through its definition
+const VALUE_SUFFIX: &'static str = "$ordering";
+
+// `Ordering`, recognized by its variants like `Option`
+fn is_ordering_adt(adt: &AdtDef) -> bool {
+    adt.is_enum() && adt.variants.len() == 3 && &*adt.variants[LESS].name.as_str() == "Less" &&
+    &*adt.variants[EQUAL].name.as_str() == "Equal" &&
+    &*adt.variants[GREATER].name.as_str() == "Greater"
+}
+
+// The `Ordering` of a result, also inside an `Option`
+fn ordering_adt(ty: Ty) -> Option<&AdtDef> {
+    match referenced(ty).sty {
+        TypeVariants::TyAdt(adt, _) if is_ordering_adt(adt) => Some(adt),
+        TypeVariants::TyAdt(adt, substs) if ast::is_option_adt(adt) => {
+            ordering_adt(substs.type_at(0))
+        }
+        _ => None,
+    }
+}
+
+fn is_primitive(ty: Ty, partial: bool) -> bool {
+    match referenced(ty).sty {
+        TypeVariants::TyBool |
+        TypeVariants::TyChar |
+        TypeVariants::TyInt(_) |
+        TypeVariants::TyUint(_) => true,
+        TypeVariants::TyFloat(_) => partial,
+        _ => false,
+    }
+}
+
+pub fn method(data: &MirData, def_id: DefId, args: &[Operand]) -> Option<Method> {
+    if def_id.is_local() || args.is_empty() {
+        return None;
+    }
+
+    let item_name = data.tcx.item_name(def_id).as_str();
+    let receiver = args[0].ty(data.mir, data.tcx);
+    let on_ordering = ordering_adt(receiver).is_some() && !ast::is_option(receiver);
+
+    match (&*item_name, args.len()) {
+        ("cmp", 2) if is_primitive(receiver, false) => Some(Method::Cmp),
+        ("partial_cmp", 2) if is_primitive(receiver, true) => Some(Method::PartialCmp),
+        ("then", 2) if on_ordering => Some(Method::Then),
+        ("reverse", 1) if on_ordering => Some(Method::Reverse),
+        _ => None,
+    }
+}
+
+fn binary(left: Expression, op: BinaryOperator, right: Expression) -> Expression {
+    Expression::BinaryExpression(Rc::new(left), op, Rc::new(right))
+}
+
+fn equal(left: Expression, right: Expression) -> Expression {
+    binary(left, BinaryOperator::Equal, right)
+}
+
+pub fn call<'a, 'tcx>(wp: &Expression,
+                      method: Method,
+                      args: &[Operand<'tcx>],
+                      destination: Lvalue<'tcx>,
+                      index: usize,
+                      depth: usize,
+                      data: &MirData<'a, 'tcx>)
+                      -> Expression {
+    let adt = ordering_adt(destination.ty(data.mir, data.tcx).to_ty(data.tcx))
+        .unwrap_or_else(|| error!("`{:?}` does not hold an `Ordering`", destination));
+    let dest = gen_lvalue(destination, data);
+    let order = enums::discriminant(&dest, adt);
+    let suffix = format!("{}{}_{}", VALUE_SUFFIX, index, depth);
+    let result = self_composition::rename_with(&order, &suffix);
+    let variant = |variant| equal(result.clone(), enums::discriminant_value(adt, variant));
+    let args = args.iter().map(|arg| gen_expression(arg, data)).collect::<Vec<_>>();
+
+    let definition = match method {
+        Method::Cmp | Method::PartialCmp => {
+            let (a, b) = (args[0].clone(), args[1].clone());
+
+            cases(binary(a.clone(), BinaryOperator::LessThan, b.clone()),
+                  variant(LESS),
+                  cases(equal(a, b), variant(EQUAL), variant(GREATER)))
+        }
+        // The first ordering unless it is `Equal`
+        Method::Then => {
+            let first = enums::discriminant(&args[0], adt);
+            let second = enums::discriminant(&args[1], adt);
+
+            cases(equal(first.clone(), enums::discriminant_value(adt, EQUAL)),
+                  equal(result.clone(), second),
+                  equal(result.clone(), first))
+        }
+        Method::Reverse => {
+            let order = enums::discriminant(&args[0], adt);
+            let is = |variant| equal(order.clone(), enums::discriminant_value(adt, variant));
+
+            cases(is(LESS),
+                  variant(GREATER),
+                  cases(is(GREATER), variant(LESS), variant(EQUAL)))
+        }
+    };
+    let wp = assume(definition, assign(wp, &order, &result, data), data);
+
+    match method {
+        Method::PartialCmp => {
+            let nan = |x: &Expression| float::predicate("is_nan", x);
+            let defined = if ast::is_float(ast::determine_evaluation_type(&args[0])) {
+                Expression::UnaryExpression(UnaryOperator::Not,
+                                            Rc::new(binary(nan(&args[0]),
+                                                           BinaryOperator::Or,
+                                                           nan(&args[1]))))
+            } else {
+                Expression::BooleanLiteral(true)
+            };
+
+            assign(&wp, &option::tag(&dest), &defined, data)
+        }
+        _ => wp,
+    }
+}
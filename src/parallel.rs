@@ -0,0 +1,273 @@
+use ast::{BinaryOperator, Expression};
+use config::Solver;
+use regex::Regex;
+use rustproof_libsmt::backends::smtlib2::SMTProc;
+use smtlib;
+use std::cmp;
+use std::collections::VecDeque;
+use std::io::Write;
+use std::process::{Child, Command, Stdio};
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+
+// The independent obligations of a condition are solved by separate solver
+// processes. Conditions are not `Send`, so each obligation is handed to its
+// thread as SMT-LIB text. Every thread takes the next open obligation as it
+// finishes one, so a slow obligation does not hold back the others
+
+pub enum Outcome {
+    Valid,
+    // The assignments falsifying the condition, as names and hexadecimal values
+    Invalid(Vec<(String, String)>),
+    Error(String),
+}
+
+fn split(condition: &Expression,
+         premises: &mut Vec<Rc<Expression>>,
+         found: &mut Vec<Expression>) {
+    match *condition {
+        Expression::BinaryExpression(ref left, BinaryOperator::And, ref right) => {
+            split(left, premises, found);
+            split(right, premises, found);
+        }
+        Expression::BinaryExpression(ref left, BinaryOperator::Implication, ref right) => {
+            premises.push(left.clone());
+            split(right, premises, found);
+            premises.pop();
+        }
+        _ => {
+            found.push(premises.iter().rev().fold(condition.clone(), |wp, premise| {
+                Expression::BinaryExpression(premise.clone(),
+                                             BinaryOperator::Implication,
+                                             Rc::new(wp))
+            }))
+        }
+    }
+}
+
+// The conjuncts of a condition, each under the premises it was reached
+// through, so `p => (a && b)` is valid exactly when `p => a` and `p => b` are
+pub fn obligations(condition: &Expression) -> Vec<Expression> {
+    let mut found = Vec::new();
+    split(condition, &mut Vec::new(), &mut found);
+    found
+}
+
+// How z3 is run on a script read from its standard input
+const FLAGS: &'static [&'static str] = &["-in", "-smt2"];
+
+// The arguments the solver is run with, its time limit included
+pub fn arguments(solver: &Solver) -> Vec<String> {
+    let mut arguments = FLAGS.iter().map(|flag| flag.to_string()).collect::<Vec<_>>();
+
+    if let Some(timeout) = solver.timeout {
+        arguments.push(format!("-T:{}", timeout));
+    }
+
+    arguments
+}
+
+fn spawn(solver: &Solver) -> Result<Child, String> {
+    Command::new(&solver.binary)
+        .args(&arguments(solver))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("Cannot run {}: {}", solver.binary, e))
+}
+
+pub fn run(script: &[u8], solver: &Solver) -> Result<String, String> {
+    let mut child = spawn(solver)?;
+
+    child.stdin
+        .take()
+        .unwrap()
+        .write_all(script)
+        .map_err(|e| format!("Cannot write to {}: {}", solver.binary, e))?;
+
+    let output = child.wait_with_output()
+        .map_err(|e| format!("{} failed: {}", solver.binary, e))?;
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+// The solver as the SMT-LIB interface talks to it, one command at a time
+pub struct Process {
+    solver: Solver,
+    child: Option<Child>,
+}
+
+impl Process {
+    pub fn new(solver: &Solver) -> Process {
+        Process {
+            solver: solver.clone(),
+            child: None,
+        }
+    }
+}
+
+impl SMTProc for Process {
+    fn init(&mut self) {
+        self.child = Some(spawn(&self.solver).unwrap_or_else(|e| error!("{}", e)));
+    }
+
+    fn pipe<'a>(&'a mut self) -> &'a mut Child {
+        if self.child.is_none() {
+            self.init();
+        }
+
+        self.child.as_mut().unwrap()
+    }
+}
+
+// The bits of a `#b` or `#x` literal, most significant first
+fn literal_bits(literal: &str) -> Option<String> {
+    if literal.starts_with("#b") {
+        Some(literal[2..].to_string())
+    } else if literal.starts_with("#x") {
+        literal[2..]
+            .chars()
+            .map(|digit| digit.to_digit(16).map(|digit| format!("{:04b}", digit)))
+            .collect()
+    } else {
+        None
+    }
+}
+
+// The bits of a float of `exponent` and `significand` bits, from an
+// `(fp sign exponent fraction)` triple or one of the named constants
+fn float_bits(value: &str, exponent: usize, significand: usize) -> Option<String> {
+    let ones = |n: usize| "1".repeat(n);
+    let zeros = |n: usize| "0".repeat(n);
+    let mut words = value.trim_matches(|c| c == '(' || c == ')').split_whitespace();
+
+    match (words.next(), words.next()) {
+        (Some("fp"), Some(sign)) => {
+            let parts = Some(sign).into_iter().chain(words).map(literal_bits);
+            parts.collect::<Option<Vec<_>>>().map(|parts| parts.concat())
+        }
+        (Some("_"), Some("+zero")) => Some(zeros(exponent + significand)),
+        (Some("_"), Some("-zero")) => Some(format!("1{}", zeros(exponent + significand - 1))),
+        (Some("_"), Some(infinity)) if infinity == "+oo" || infinity == "-oo" => {
+            let sign = if infinity == "-oo" { "1" } else { "0" };
+            Some(format!("{}{}{}", sign, ones(exponent), zeros(significand - 1)))
+        }
+        (Some("_"), Some("NaN")) => {
+            Some(format!("0{}1{}", ones(exponent), zeros(significand - 2)))
+        }
+        _ => None,
+    }
+}
+
+fn hexadecimal(bits: &str) -> String {
+    let padded = format!("{}{}", "0".repeat((4 - bits.len() % 4) % 4), bits);
+
+    padded.as_bytes()
+        .chunks(4)
+        .map(|digit| {
+                 format!("{:x}", digit.iter().fold(0, |n, &bit| n * 2 + (bit - b'0') as u32))
+             })
+        .collect()
+}
+
+// The variables of a model and the hexadecimal digits of their values, for
+// bitvectors, booleans (`1` for `true`) and floats (their IEEE bits); the
+// values of other sorts are left out
+pub fn model(text: &str) -> Vec<(String, String)> {
+    let re = Regex::new(concat!(r"\(define-fun \|?([^|\s()]+)\|? \(\) ",
+                                r"(Bool|\(_ BitVec \d+\)|\(_ FloatingPoint (\d+) (\d+)\))\s+",
+                                r"(\([^()]*\)|[^\s()]+)\)"))
+        .unwrap();
+
+    re.captures_iter(text)
+        .filter_map(|cap| {
+            let value = &cap[5];
+            let bits = match (&cap[2], cap.get(3), cap.get(4)) {
+                ("Bool", _, _) if value == "true" => Some("1".to_string()),
+                ("Bool", _, _) if value == "false" => Some("0".to_string()),
+                ("Bool", _, _) => None,
+                (_, Some(exponent), Some(significand)) => {
+                    match (exponent.as_str().parse(), significand.as_str().parse()) {
+                        (Ok(exponent), Ok(significand)) if significand > 1 => {
+                            float_bits(value, exponent, significand)
+                        }
+                        _ => None,
+                    }
+                }
+                _ => literal_bits(value),
+            };
+
+            bits.map(|bits| (cap[1].to_string(), hexadecimal(&bits)))
+        })
+        .collect()
+}
+
+// Solves `obligations` on `jobs` threads, stopping the remaining ones once
+// any is falsified; the first falsified obligation gives the model
+pub fn solve(label: &str, obligations: &[Expression], solver: &Solver, jobs: usize) -> Outcome {
+    let mut scripts = VecDeque::new();
+
+    for (i, obligation) in obligations.iter().enumerate() {
+        let mut script = Vec::new();
+        let part = format!("{} ({} of {})", label, i + 1, obligations.len());
+        smtlib::write(&mut script, &part, obligation)
+            .and_then(|_| writeln!(script, "(get-model)"))
+            .unwrap_or_else(|e| error!("Cannot encode `{}`: {}", label, e));
+        scripts.push_back((i, script));
+    }
+
+    let queue = Arc::new(Mutex::new(scripts));
+    let results = Arc::new(Mutex::new(vec![None; obligations.len()]));
+    let falsified = Arc::new(AtomicBool::new(false));
+
+    let workers = (0..cmp::min(jobs, obligations.len()))
+        .map(|_| {
+            let queue = queue.clone();
+            let results = results.clone();
+            let falsified = falsified.clone();
+            let solver = solver.clone();
+
+            thread::spawn(move || loop {
+                if falsified.load(Ordering::SeqCst) {
+                    return;
+                }
+
+                let (i, script) = match queue.lock().unwrap().pop_front() {
+                    Some(next) => next,
+                    None => return,
+                };
+                let result = run(&script, &solver);
+
+                if let Ok(ref output) = result {
+                    if output.starts_with("sat") {
+                        falsified.store(true, Ordering::SeqCst);
+                    }
+                }
+
+                results.lock().unwrap()[i] = Some(result);
+            })
+        })
+        .collect::<Vec<_>>();
+
+    for worker in workers {
+        worker.join().unwrap_or_else(|_| error!("A solver thread of `{}` panicked", label));
+    }
+
+    let results = results.lock().unwrap();
+
+    for result in results.iter() {
+        match *result {
+            Some(Ok(ref output)) if output.starts_with("sat") => {
+                return Outcome::Invalid(model(output))
+            }
+            Some(Ok(ref output)) if output.starts_with("unsat") => {}
+            Some(Ok(ref output)) => return Outcome::Error(output.clone()),
+            Some(Err(ref error)) => return Outcome::Error(error.clone()),
+            // Stopped after another obligation was falsified
+            None => {}
+        }
+    }
+
+    Outcome::Valid
+}
@@ -0,0 +1,83 @@
+use ast::Expression;
+use theory;
+use std::rc::Rc;
+
+// Marks the values a pledge reads once the returned borrow has expired
+pub const FINAL_SUFFIX: &'static str = "$final";
+
+// The variable a returned borrow points into, and its value once the
+// borrowed place holds `prophecy`
+fn updated_root(place: &Expression, prophecy: &Expression) -> (String, Expression) {
+    match *place {
+        Expression::VariableMapping(ref name, _) => (name.clone(), prophecy.clone()),
+        Expression::Select(ref array, ref index) => {
+            match **array {
+                Expression::Array(ref name, _) => {
+                    (name.clone(),
+                     Expression::Store(array.clone(), index.clone(), Rc::new(prophecy.clone())))
+                }
+                _ => error!("Pledges need the returned borrow to point into an argument"),
+            }
+        }
+        _ => error!("Pledges need the returned borrow to point into an argument"),
+    }
+}
+
+// A pledge holds for the final value of the returned borrow, `prophecy`,
+// whatever the caller writes through it: the borrowed place ends up
+// holding that value and everything else keeps its value at return
+pub fn obligation(pledge: &Expression, place: &Expression, prophecy: &Expression) -> Expression {
+    let (root, updated) = updated_root(place, prophecy);
+    let resolved = resolve_final(pledge, &root, &updated, prophecy);
+
+    // Without `final`, the borrow is read when it is returned
+    theory::bind(&resolved, "ret", place)
+}
+
+fn resolve_final(expression: &Expression,
+                 root: &str,
+                 updated: &Expression,
+                 prophecy: &Expression)
+                 -> Expression {
+    let resolve = |e: &Expression| resolve_final(e, root, updated, prophecy);
+
+    match *expression {
+        Expression::BinaryExpression(ref left, op, ref right) => {
+            Expression::BinaryExpression(Rc::new(resolve(left)), op, Rc::new(resolve(right)))
+        }
+        Expression::UnaryExpression(ref op, ref expr) => {
+            Expression::UnaryExpression(op.clone(), Rc::new(resolve(expr)))
+        }
+        Expression::VariableMapping(ref name, ty) |
+        Expression::Array(ref name, ty) if name.contains(FINAL_SUFFIX) => {
+            let name = name.replace(FINAL_SUFFIX, "");
+
+            if name == "ret" {
+                prophecy.clone()
+            } else if name == root {
+                updated.clone()
+            } else {
+                match *expression {
+                    Expression::Array(..) => Expression::Array(name, ty),
+                    _ => Expression::VariableMapping(name, ty),
+                }
+            }
+        }
+        Expression::FunctionCall(ref name, ref args, ty) => {
+            Expression::FunctionCall(name.clone(), args.iter().map(resolve).collect(), ty)
+        }
+        Expression::Length(ref array) => Expression::Length(Rc::new(resolve(array))),
+        Expression::Select(ref array, ref index) => {
+            Expression::Select(Rc::new(resolve(array)), Rc::new(resolve(index)))
+        }
+        Expression::Store(ref array, ref index, ref value) => {
+            Expression::Store(Rc::new(resolve(array)),
+                              Rc::new(resolve(index)),
+                              Rc::new(resolve(value)))
+        }
+        Expression::Forall(ref var, ty, ref body) => {
+            Expression::Forall(var.clone(), ty, Rc::new(resolve(body)))
+        }
+        _ => expression.clone(),
+    }
+}
@@ -0,0 +1,73 @@
+use ast::Expression;
+use config::Config;
+use parallel;
+use smtlib;
+use std::cell::RefCell;
+use std::fs::{self, File};
+use std::io::{Result, Write};
+use std::path::Path;
+
+// Every proved obligation is archived with what it takes to replay it
+// independently: the exact script, the solver and the flags it was checked
+// with, and the proof object the solver gives for it. The proof comes from
+// replaying the archived script, so it also confirms the script on its own
+// is unsat
+
+thread_local! {
+    static PRODUCER: RefCell<Option<String>> = RefCell::new(None);
+}
+
+fn producer(config: &Config) -> String {
+    PRODUCER.with(|producer| {
+        let mut producer = producer.borrow_mut();
+
+        if producer.is_none() {
+            *producer = Some(config.producer());
+        }
+
+        producer.clone().unwrap()
+    })
+}
+
+fn create(path: &Path, contents: &[u8]) {
+    File::create(path)
+        .and_then(|mut file| file.write_all(contents))
+        .unwrap_or_else(|e| error!("Cannot write `{}`: {}", path.display(), e));
+}
+
+fn script(label: &str, condition: &Expression, config: &Config) -> Result<Vec<u8>> {
+    let mut script = Vec::new();
+    writeln!(script, "; proved by {}", producer(config))?;
+    writeln!(script,
+             "; replay with `{} {}`",
+             config.solver.binary,
+             parallel::arguments(&config.solver).join(" "))?;
+    writeln!(script, "(set-option :produce-proofs true)")?;
+    smtlib::write(&mut script, label, condition)?;
+    writeln!(script, "(get-proof)")?;
+
+    Ok(script)
+}
+
+// Writes `directory/<label>.smt2` and, when the replay is unsat, the proof
+// to `directory/<label>.proof`
+pub fn archive(directory: &str, label: &str, condition: &Expression, config: &Config) {
+    let script = script(label, condition, config)
+        .unwrap_or_else(|e| error!("Cannot encode `{}`: {}", label, e));
+
+    fs::create_dir_all(directory)
+        .unwrap_or_else(|e| error!("Cannot create `{}`: {}", directory, e));
+    let base = Path::new(directory).join(smtlib::file_name(label));
+    create(&base.with_extension("smt2"), &script);
+
+    match parallel::run(&script, &config.solver) {
+        Ok(ref output) if output.starts_with("unsat") => {
+            create(&base.with_extension("proof"), output["unsat".len()..].trim().as_bytes())
+        }
+        Ok(output) => {
+            println!("   warning: replaying the archived script gave `{}`, no proof written",
+                     output.lines().next().unwrap_or(""))
+        }
+        Err(error) => println!("   warning: cannot replay the archived script: {}", error),
+    }
+}
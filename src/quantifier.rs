@@ -0,0 +1,181 @@
+use ast::{self, BinaryOperator, Expression, UnaryOperator};
+use theory;
+use std::rc::Rc;
+
+// The solver only decides quantifier-free formulas. A quantified goal is
+// proved for a fresh witness, which is exact; a quantified assumption is
+// instantiated at the indices the formula reads from or writes to the
+// arrays its body indexes by the bound variable, which is sound but can miss
+// proofs that need other instances. `exists` is the negation of a `forall`,
+// so an existential goal is proved when one of those indices witnesses it
+pub fn eliminate(verification_condition: &Expression) -> Expression {
+    let mut fresh = 0;
+    let skolemized = skolemize(verification_condition, true, &mut fresh);
+
+    let mut indices = Vec::new();
+    reads(&skolemized, &mut Vec::new(), None, &mut indices);
+
+    instantiate(&skolemized, &indices)
+}
+
+fn contains_forall(expression: &Expression) -> bool {
+    match *expression {
+        Expression::BinaryExpression(ref left, _, ref right) => {
+            contains_forall(left) || contains_forall(right)
+        }
+        Expression::UnaryExpression(_, ref expr) => contains_forall(expr),
+        Expression::Forall(..) => true,
+        _ => false,
+    }
+}
+
+fn skolemize(expression: &Expression, positive: bool, fresh: &mut usize) -> Expression {
+    match *expression {
+        Expression::BinaryExpression(ref left, op @ BinaryOperator::And, ref right) |
+        Expression::BinaryExpression(ref left, op @ BinaryOperator::Or, ref right) => {
+            let left = skolemize(left, positive, fresh);
+            Expression::BinaryExpression(Rc::new(left),
+                                         op,
+                                         Rc::new(skolemize(right, positive, fresh)))
+        }
+        Expression::BinaryExpression(ref left, BinaryOperator::Implication, ref right) => {
+            let left = skolemize(left, !positive, fresh);
+            Expression::BinaryExpression(Rc::new(left),
+                                         BinaryOperator::Implication,
+                                         Rc::new(skolemize(right, positive, fresh)))
+        }
+        Expression::BinaryExpression(ref left, _, ref right) => {
+            if contains_forall(left) || contains_forall(right) {
+                error!("Quantifiers may only appear under `&&`, `||`, `!` and `=>`");
+            }
+
+            expression.clone()
+        }
+        Expression::UnaryExpression(UnaryOperator::Not, ref expr) => {
+            Expression::UnaryExpression(UnaryOperator::Not,
+                                        Rc::new(skolemize(expr, !positive, fresh)))
+        }
+        Expression::Forall(ref var, ty, ref body) if positive => {
+            *fresh += 1;
+            let witness = Expression::VariableMapping(format!("{}$sk{}", var, fresh), ty);
+            skolemize(&theory::bind(body, var, &witness), positive, fresh)
+        }
+        Expression::Forall(ref var, ty, ref body) => {
+            Expression::Forall(var.clone(), ty, Rc::new(skolemize(body, positive, fresh)))
+        }
+        _ => expression.clone(),
+    }
+}
+
+fn mentions(expression: &Expression, names: &[String]) -> bool {
+    match *expression {
+        Expression::BinaryExpression(ref left, _, ref right) => {
+            mentions(left, names) || mentions(right, names)
+        }
+        Expression::UnaryExpression(_, ref expr) |
+        Expression::Length(ref expr) |
+        Expression::Forall(_, _, ref expr) => mentions(expr, names),
+        Expression::VariableMapping(ref name, _) => names.contains(name),
+        Expression::FunctionCall(_, ref args, _) => args.iter().any(|arg| mentions(arg, names)),
+        Expression::Select(ref array, ref index) => {
+            mentions(array, names) || mentions(index, names)
+        }
+        Expression::Store(ref array, ref index, ref value) => {
+            mentions(array, names) || mentions(index, names) || mentions(value, names)
+        }
+        _ => false,
+    }
+}
+
+// Collects the reads and writes of arrays at indices free of bound variables
+// or, when `index_of` is given, the reads at indices mentioning that variable
+fn reads(expression: &Expression,
+         bound: &mut Vec<String>,
+         index_of: Option<&str>,
+         found: &mut Vec<(String, Expression)>) {
+    match *expression {
+        Expression::BinaryExpression(ref left, _, ref right) => {
+            reads(left, bound, index_of, found);
+            reads(right, bound, index_of, found);
+        }
+        Expression::UnaryExpression(_, ref expr) |
+        Expression::Length(ref expr) => reads(expr, bound, index_of, found),
+        Expression::FunctionCall(_, ref args, _) => {
+            for arg in args {
+                reads(arg, bound, index_of, found);
+            }
+        }
+        Expression::Forall(ref var, _, ref body) => {
+            bound.push(var.clone());
+            reads(body, bound, index_of, found);
+            bound.pop();
+        }
+        Expression::Select(ref array, ref index) => {
+            reads(array, bound, index_of, found);
+            reads(index, bound, index_of, found);
+
+            let read = match index_of {
+                Some(var) => mentions(index, &[var.to_string()]),
+                None => !mentions(index, bound),
+            };
+
+            if read {
+                let read = (ast::array_name(array).to_string(), (**index).clone());
+
+                if !found.contains(&read) {
+                    found.push(read);
+                }
+            }
+        }
+        Expression::Store(ref array, ref index, ref value) => {
+            reads(array, bound, index_of, found);
+            reads(index, bound, index_of, found);
+            reads(value, bound, index_of, found);
+
+            if index_of.is_none() && !mentions(index, bound) {
+                let write = (ast::array_name(array).to_string(), (**index).clone());
+
+                if !found.contains(&write) {
+                    found.push(write);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn instantiate(expression: &Expression, indices: &[(String, Expression)]) -> Expression {
+    match *expression {
+        Expression::BinaryExpression(ref left, op, ref right) => {
+            Expression::BinaryExpression(Rc::new(instantiate(left, indices)),
+                                         op,
+                                         Rc::new(instantiate(right, indices)))
+        }
+        Expression::UnaryExpression(ref op, ref expr) => {
+            Expression::UnaryExpression(op.clone(), Rc::new(instantiate(expr, indices)))
+        }
+        Expression::Forall(ref var, ty, ref body) => {
+            let mut triggers = Vec::new();
+            reads(body, &mut Vec::new(), Some(var), &mut triggers);
+
+            let mut instances = Vec::new();
+
+            for &(ref array, ref index) in indices {
+                if triggers.iter().any(|&(ref read, _)| read == array) &&
+                   ast::determine_evaluation_type(index) == ty &&
+                   !instances.contains(index) {
+                    instances.push(index.clone());
+                }
+            }
+
+            instances.into_iter()
+                .map(|index| instantiate(&theory::bind(body, var, &index), indices))
+                .fold(Expression::BooleanLiteral(true), |acc, instance| {
+                    Expression::BinaryExpression(Rc::new(acc),
+                                                 BinaryOperator::And,
+                                                 Rc::new(instance))
+                })
+        }
+        _ => expression.clone(),
+    }
+}
@@ -0,0 +1,132 @@
+use std::collections::BTreeMap;
+use summary::{self, Status, Summary};
+
+// The end of a run summarizes its obligations as a tree of the modules and
+// functions they belong to, counting those proved (✓), refuted or failed
+// (✗) and not attempted (?) under each, followed by the slowest ones
+
+const MARKS: [&'static str; 3] = ["✓", "✗", "?"];
+const SLOWEST: usize = 3;
+
+#[derive(Default)]
+struct Node {
+    children: BTreeMap<String, Node>,
+    counts: [usize; 3],
+}
+
+fn column(status: Status) -> usize {
+    match status {
+        Status::Valid => 0,
+        Status::Invalid | Status::Error => 1,
+        Status::Skipped => 2,
+    }
+}
+
+fn mark(status: Status) -> &'static str {
+    MARKS[column(status)]
+}
+
+// The module path of the function a label is about, split at the `::`s
+// outside generic arguments
+fn path(label: &str) -> Vec<String> {
+    let name = summary::function(label);
+    let mut segments = Vec::new();
+    let mut segment = String::new();
+    let mut depth = 0;
+    let mut chars = name.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '<' => depth += 1,
+            '>' => depth -= 1,
+            ':' if depth == 0 && chars.peek() == Some(&':') => {
+                chars.next();
+                segments.push(segment);
+                segment = String::new();
+                continue;
+            }
+            _ => {}
+        }
+
+        segment.push(c);
+    }
+
+    segments.push(segment);
+    segments
+}
+
+fn insert(node: &mut Node, path: &[String], status: Status) {
+    node.counts[column(status)] += 1;
+
+    if let Some((first, rest)) = path.split_first() {
+        insert(node.children.entry(first.clone()).or_insert_with(Node::default),
+               rest,
+               status);
+    }
+}
+
+fn counts(counts: &[usize; 3]) -> String {
+    MARKS.iter()
+        .zip(counts.iter())
+        .filter(|&(_, &count)| count > 0)
+        .map(|(mark, count)| format!("{} {}", mark, count))
+        .collect::<Vec<_>>()
+        .join("  ")
+}
+
+fn lines(node: &Node, prefix: &str, found: &mut Vec<(String, String)>) {
+    let last = node.children.len();
+
+    for (index, (name, child)) in node.children.iter().enumerate() {
+        let (branch, indent) = if index + 1 == last {
+            ("└── ", "    ")
+        } else {
+            ("├── ", "│   ")
+        };
+
+        found.push((format!("{}{}{}", prefix, branch, name), counts(&child.counts)));
+        lines(child, &format!("{}{}", prefix, indent), found);
+    }
+}
+
+pub fn print(summaries: &[Summary], elapsed: u64) {
+    let mut root = Node::default();
+
+    for summary in summaries {
+        insert(&mut root, &path(&summary.label), summary.status);
+    }
+
+    let mut found = Vec::new();
+    lines(&root, "", &mut found);
+    let width = found.iter().map(|&(ref name, _)| name.chars().count()).max().unwrap_or(0);
+
+    if !summaries.is_empty() {
+        println!("");
+        println!("Summary of {} obligations:", summaries.len());
+    }
+
+    for (name, counts) in found {
+        let padding = width - name.chars().count();
+        println!("   {}{}  {}", name, " ".repeat(padding), counts);
+    }
+
+    let mut slowest = summaries.iter().filter(|summary| summary.cost > 0).collect::<Vec<_>>();
+    slowest.sort_by(|a, b| b.cost.cmp(&a.cost));
+
+    if !slowest.is_empty() {
+        println!("Slowest obligations:");
+
+        for summary in slowest.iter().take(SLOWEST) {
+            println!("   {:>8} ms  {} {}", summary.cost, mark(summary.status), summary.label);
+        }
+    }
+
+    let result = if root.counts[1] == 0 { "ok" } else { "FAILED" };
+    println!("verification result: {}. {} proved; {} failed; {} skipped; finished in {}.{:03}s",
+             result,
+             root.counts[0],
+             root.counts[1],
+             root.counts[2],
+             elapsed / 1000,
+             elapsed % 1000);
+}
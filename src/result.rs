@@ -0,0 +1,270 @@
+use {MirData, assign, function_def_id, gen_expression, gen_lvalue};
+use ast::{self, BinaryOperator, Expression, Types, UnaryOperator};
+use option;
+use rustc::hir::def_id::DefId;
+use rustc::mir::*;
+use rustc::ty::{Ty, TypeVariants};
+use self_composition;
+use std::rc::Rc;
+
+// A `Result` is modelled like an option: by its `Ok` payload, named after
+// the result itself, a boolean tag `name$ok` telling whether it is `Ok`,
+// and the `Err` payload `name$err`. The `?` operator matches on
+// `Try::into_result` of its operand and returns `Try::from_error` of the
+// converted error, so an error is an ordinary path to the return, where
+// the postcondition is checked
+
+pub enum Method {
+    IntoResult,
+    FromError,
+    From,
+    Unwrap,
+    UnwrapErr,
+    IsOk,
+    IsErr,
+}
+
+// Names the error of `?` converted to a different type, about which
+// nothing is known
+const CONVERTED_SUFFIX: &'static str = "$from";
+
+fn binary(left: Expression, op: BinaryOperator, right: Expression) -> Expression {
+    Expression::BinaryExpression(Rc::new(left), op, Rc::new(right))
+}
+
+fn not(expression: Expression) -> Expression {
+    Expression::UnaryExpression(UnaryOperator::Not, Rc::new(expression))
+}
+
+fn name(result: &Expression) -> &str {
+    match *result {
+        Expression::VariableMapping(ref name, _) => name,
+        _ => error!("Only results held in variables and fields are supported, found `{}`", result),
+    }
+}
+
+pub fn tag(result: &Expression) -> Expression {
+    Expression::VariableMapping(format!("{}$ok", name(result)), Types::Bool)
+}
+
+pub fn error(result: &Expression, ty: Types) -> Expression {
+    Expression::VariableMapping(format!("{}$err", name(result)), ty)
+}
+
+// The type of the `Err` payload of a result, also behind references
+pub fn error_type(ty: Ty) -> Types {
+    match ty.sty {
+        TypeVariants::TyRef(_, ref referenced) => error_type(referenced.ty),
+        TypeVariants::TyAdt(_, substs) => ast::type_to_enum(substs.type_at(1)),
+        _ => Types::Unknown,
+    }
+}
+
+fn lvalue_type<'a, 'tcx>(lvalue: &Lvalue<'tcx>, data: &MirData<'a, 'tcx>) -> Ty<'tcx> {
+    lvalue.ty(data.mir, data.tcx).to_ty(data.tcx)
+}
+
+pub fn holds_result(lvalue: &Lvalue, data: &MirData) -> bool {
+    ast::is_result(lvalue_type(lvalue, data))
+}
+
+// The tag and the `Err` payload of a result held in `lvalue`
+fn parts(lvalue: &Lvalue, data: &MirData) -> (Expression, Expression) {
+    let result = gen_lvalue(lvalue.clone(), data);
+    (tag(&result), error(&result, error_type(lvalue_type(lvalue, data))))
+}
+
+// `is_ok`, `is_err`, `unwrap` and `unwrap_err` on results in
+// specifications, with their arguments resolved
+pub fn expand(name: &str, args: &[Expression], data: &MirData) -> Option<Expression> {
+    let ty = match args.first().and_then(|arg| option::specified_type(arg, data)) {
+        Some(ty) if args.len() == 1 && ast::is_result(ty) => ty,
+        _ => return None,
+    };
+
+    match name {
+        "is_ok" => Some(tag(&args[0])),
+        "is_err" => Some(not(tag(&args[0]))),
+        "unwrap" => Some(args[0].clone()),
+        "unwrap_err" => Some(error(&args[0], error_type(ty))),
+        _ => None,
+    }
+}
+
+fn trait_path(data: &MirData, def_id: DefId) -> Option<String> {
+    data.tcx.trait_of_item(def_id).map(|trait_id| data.tcx.item_path_str(trait_id))
+}
+
+fn is_from_error(data: &MirData, def_id: DefId) -> bool {
+    match trait_path(data, def_id).as_ref().map(|path| &**path) {
+        Some("std::ops::Try") | Some("core::ops::Try") => {
+            &*data.tcx.item_name(def_id).as_str() == "from_error"
+        }
+        _ => false,
+    }
+}
+
+// Whether a `From::from` call is the conversion of the error of `?`, whose
+// result is passed straight to `Try::from_error`. Other conversions, such
+// as widening an integer, are left to the call's own contract
+fn converts_error(data: &MirData, destination: &Lvalue, target: BasicBlock) -> bool {
+    match data.mir[target].terminator().kind {
+        TerminatorKind::Call { ref func, ref args, .. } => {
+            function_def_id(func).map_or(false, |def_id| is_from_error(data, def_id)) &&
+            args.first().map_or(false, |arg| match *arg {
+                Operand::Consume(ref lvalue) => lvalue == destination,
+                Operand::Constant(_) => false,
+            })
+        }
+        _ => false,
+    }
+}
+
+pub fn method(data: &MirData,
+              def_id: DefId,
+              args: &[Operand],
+              destination: &Lvalue,
+              target: BasicBlock)
+              -> Option<Method> {
+    let item_name = data.tcx.item_name(def_id).as_str();
+
+    if is_from_error(data, def_id) {
+        return Some(Method::FromError);
+    }
+
+    match (trait_path(data, def_id).as_ref().map(|path| &**path), &*item_name) {
+        (Some("std::ops::Try"), "into_result") |
+        (Some("core::ops::Try"), "into_result") => return Some(Method::IntoResult),
+        (Some("std::convert::From"), "from") |
+        (Some("core::convert::From"), "from") if converts_error(data, destination, target) => {
+            return Some(Method::From)
+        }
+        _ => {}
+    }
+
+    match args.first() {
+        Some(receiver) if ast::is_result(receiver.ty(data.mir, data.tcx)) => {}
+        _ => return None,
+    }
+
+    match &*item_name {
+        "unwrap" | "expect" => Some(Method::Unwrap),
+        "unwrap_err" | "expect_err" => Some(Method::UnwrapErr),
+        "is_ok" => Some(Method::IsOk),
+        "is_err" => Some(Method::IsErr),
+        _ => None,
+    }
+}
+
+// Copies the whole of a result, tag and `Err` payload included
+fn copy(wp: &Expression, destination: &Lvalue, source: &Lvalue, data: &MirData) -> Expression {
+    let (dest_tag, dest_error) = parts(destination, data);
+    let (source_tag, source_error) = parts(source, data);
+    let wp = assign(wp, &dest_tag, &source_tag, data);
+    assign(&wp, &dest_error, &source_error, data)
+}
+
+// Unwrapping the wrong variant panics, so like an assertion, a single run
+// must show the result is of the expected one whenever the precondition
+// holds
+pub fn call(wp: &Expression,
+            method: Method,
+            args: &[Operand],
+            destination: Lvalue,
+            index: usize,
+            depth: usize,
+            data: &MirData)
+            -> Expression {
+    let dest = gen_lvalue(destination.clone(), data);
+    let argument = gen_expression(&args[0], data);
+
+    let wp = match method {
+        Method::IntoResult => {
+            let wp = assign(wp, &dest, &argument, data);
+
+            match args[0] {
+                Operand::Consume(ref source) => copy(&wp, &destination, source, data),
+                Operand::Constant(_) => {
+                    error!("Only results held in variables are supported by `?`")
+                }
+            }
+        }
+        Method::FromError => {
+            let (dest_tag, dest_error) = parts(&destination, data);
+            let wp = assign(wp, &dest_tag, &Expression::BooleanLiteral(false), data);
+            assign(&wp, &dest_error, &argument, data)
+        }
+        // Converting a value to its own type is the identity, as for the
+        // error of `?` when the function returns the same error type
+        Method::From if args[0].ty(data.mir, data.tcx) == lvalue_type(&destination, data) => {
+            assign(wp, &dest, &argument, data)
+        }
+        Method::From => {
+            let converted = self_composition::rename_with(&dest,
+                                                          &format!("{}{}_{}",
+                                                                   CONVERTED_SUFFIX,
+                                                                   index,
+                                                                   depth));
+            assign(wp, &dest, &converted, data)
+        }
+        Method::Unwrap => assign(wp, &dest, &argument, data),
+        Method::UnwrapErr => {
+            let ty = error_type(args[0].ty(data.mir, data.tcx));
+            assign(wp, &dest, &error(&argument, ty), data)
+        }
+        Method::IsOk => assign(wp, &dest, &tag(&argument), data),
+        Method::IsErr => assign(wp, &dest, &not(tag(&argument)), data),
+    };
+
+    let check = match method {
+        Method::Unwrap => tag(&argument),
+        Method::UnwrapErr => not(tag(&argument)),
+        _ => return wp,
+    };
+
+    if data.composition.is_relational() || data.probe.is_some() {
+        wp
+    } else {
+        binary(data.composition.rename(&check), BinaryOperator::And, wp)
+    }
+}
+
+// Applied after the statement's own weakest precondition, which covers the
+// `Ok` payload: constructing a result sets its tag, and its `Err` payload
+// for `Err`, and copying, moving or borrowing a whole result carries both
+// along
+pub fn statement(wp: Expression, stmt: &Statement, data: &MirData) -> Expression {
+    match stmt.kind {
+        StatementKind::Assign(ref lvalue, ref rvalue) => {
+            if !holds_result(lvalue, data) {
+                return wp;
+            }
+
+            let (dest_tag, dest_error) = parts(lvalue, data);
+
+            match *rvalue {
+                Rvalue::Aggregate(AggregateKind::Adt(_, 0, _, _), _) => {
+                    assign(&wp, &dest_tag, &Expression::BooleanLiteral(true), data)
+                }
+                Rvalue::Aggregate(AggregateKind::Adt(..), ref operands) => {
+                    let wp = assign(&wp, &dest_tag, &Expression::BooleanLiteral(false), data);
+                    assign(&wp, &dest_error, &gen_expression(&operands[0], data), data)
+                }
+                Rvalue::Use(Operand::Consume(ref source)) |
+                Rvalue::Ref(_, _, ref source) => copy(&wp, lvalue, source, data),
+                _ => wp,
+            }
+        }
+        StatementKind::SetDiscriminant { ref lvalue, variant_index } => {
+            if !holds_result(lvalue, data) {
+                return wp;
+            }
+
+            assign(&wp,
+                   &parts(lvalue, data).0,
+                   &Expression::BooleanLiteral(variant_index == 0),
+                   data)
+        }
+        _ => wp,
+    }
+}
@@ -0,0 +1,207 @@
+use {MirData, span_error};
+use ast::{self, BinaryOperator, Expression, Types};
+use option;
+use rustc::ty::Ty;
+use std::rc::Rc;
+use structs;
+use syntax::codemap::Span;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Composition {
+    // A plain, single execution
+    Single,
+    // The first of two sequentially composed executions
+    FirstRun,
+    // The renamed second execution, generated before the first
+    SecondRun,
+    // Both executions at once, required to take the same branches
+    Lockstep,
+    // The body of a callee inlined at the given nesting level
+    Inlined(usize),
+}
+
+impl Composition {
+    pub fn is_relational(&self) -> bool {
+        match *self {
+            Composition::FirstRun | Composition::SecondRun | Composition::Lockstep => true,
+            Composition::Single | Composition::Inlined(_) => false,
+        }
+    }
+
+    // Renames an expression built from the MIR into the namespace of the
+    // execution being generated
+    pub fn rename(&self, expression: &Expression) -> Expression {
+        match *self {
+            Composition::SecondRun => rename(expression),
+            Composition::Inlined(level) => rename_with(expression, &format!("$i{}", level)),
+            _ => expression.clone(),
+        }
+    }
+}
+
+pub struct FlowContract {
+    pub from: Vec<String>,
+    pub to: Vec<String>,
+    // Where the outputs are given, to report them at
+    pub span: Span,
+    pub forbidden: bool,
+}
+
+// Variables belonging to the second copy of a self-composed function are
+// the originals with this suffix appended; `$` cannot appear in a Rust
+// identifier, so the two runs never collide.
+pub const SECOND_RUN_SUFFIX: &'static str = "$2";
+
+pub fn rename(expression: &Expression) -> Expression {
+    rename_with(expression, SECOND_RUN_SUFFIX)
+}
+
+pub fn rename_with(expression: &Expression, suffix: &str) -> Expression {
+    match *expression {
+        Expression::BinaryExpression(ref left, ref op, ref right) => {
+            Expression::BinaryExpression(Rc::new(rename_with(left, suffix)),
+                                         *op,
+                                         Rc::new(rename_with(right, suffix)))
+        }
+        Expression::UnaryExpression(ref op, ref expr) => {
+            Expression::UnaryExpression(op.clone(), Rc::new(rename_with(expr, suffix)))
+        }
+        Expression::VariableMapping(ref name, ref ty) => {
+            Expression::VariableMapping(name.clone() + suffix, *ty)
+        }
+        Expression::FunctionCall(ref name, ref args, ty) => {
+            Expression::FunctionCall(name.clone(),
+                                     args.iter().map(|arg| rename_with(arg, suffix)).collect(),
+                                     ty)
+        }
+        Expression::Array(ref name, ref ty) => Expression::Array(name.clone() + suffix, *ty),
+        Expression::Length(ref array) => Expression::Length(Rc::new(rename_with(array, suffix))),
+        Expression::Select(ref array, ref index) => {
+            Expression::Select(Rc::new(rename_with(array, suffix)),
+                               Rc::new(rename_with(index, suffix)))
+        }
+        Expression::Store(ref array, ref index, ref value) => {
+            Expression::Store(Rc::new(rename_with(array, suffix)),
+                              Rc::new(rename_with(index, suffix)),
+                              Rc::new(rename_with(value, suffix)))
+        }
+        Expression::Forall(ref var, ty, ref body) => {
+            Expression::Forall(var.clone() + suffix, ty, Rc::new(rename_with(body, suffix)))
+        }
+        _ => expression.clone(),
+    }
+}
+
+pub fn agreement(expression: &Expression) -> Expression {
+    Expression::BinaryExpression(Rc::new(expression.clone()),
+                                 BinaryOperator::Equal,
+                                 Rc::new(rename(expression)))
+}
+
+// The variables holding the value of the argument `name`, which agree
+// between the runs exactly when the argument does: the fields of a struct or
+// tuple, the elements and length of an array or slice, and the payload and
+// tag of an option
+fn parts(name: &str, ty: Ty, data: &MirData) -> Vec<Expression> {
+    let parts = if structs::is_struct(ty) || structs::is_tuple(ty) {
+        structs::fields(data.tcx, name, ty)
+    } else if let Some(element) = ast::element_type(ty) {
+        let array = Expression::Array(name.to_string(), element);
+        vec![Expression::Length(Rc::new(array.clone())), array]
+    } else {
+        let value = Expression::VariableMapping(name.to_string(), ast::type_to_enum(ty));
+
+        if ast::is_option(ty) { vec![option::tag(&value), value] } else { vec![value] }
+    };
+
+    // Results are only modelled by parts of their variants
+    let unknown = ast::is_result(ty) ||
+                  parts.iter().any(|part| match *part {
+                                       Expression::VariableMapping(_, Types::Unknown) |
+                                       Expression::Array(_, Types::Unknown) => true,
+                                       _ => false,
+                                   });
+
+    if unknown {
+        span_error(data.tcx.sess,
+                   data.mir.span,
+                   &format!("cannot compare the argument `{}` of type `{}` between two runs",
+                            name,
+                            ty),
+                   "only integers, booleans, floats, options of them, and arrays, slices, \
+                    structs and tuples of those are supported in relational properties")
+    }
+
+    parts
+}
+
+pub fn public_arguments(data: &MirData, secrets: &[String]) -> Vec<Expression> {
+    let mut public = Vec::new();
+    let mut found = Vec::new();
+
+    for arg in data.mir.args_iter() {
+        let decl = &data.mir.local_decls[arg];
+        let name = decl.name.unwrap().as_str().to_string();
+
+        if secrets.contains(&name) {
+            found.push(name);
+        } else {
+            public.extend(parts(&name, decl.ty, data));
+        }
+    }
+
+    for secret in secrets {
+        if !found.contains(secret) {
+            error!("Secret `{}` is not an argument of this function", secret);
+        }
+    }
+
+    public
+}
+
+pub fn argument_pairs(first: &MirData, second: &MirData) -> Vec<Expression> {
+    let first_args = first.mir.args_iter().collect::<Vec<_>>();
+    let second_args = second.mir.args_iter().collect::<Vec<_>>();
+
+    if first_args.len() != second_args.len() {
+        error!("Functions take a different number of arguments: {} and {}",
+               first_args.len(),
+               second_args.len());
+    }
+
+    let mut pairs = Vec::new();
+
+    for (a, b) in first_args.iter().zip(second_args.iter()) {
+        let a_decl = &first.mir.local_decls[*a];
+        let b_decl = &second.mir.local_decls[*b];
+
+        if a_decl.ty != b_decl.ty {
+            error!("Argument types do not match: `{}` and `{}`", a_decl.ty, b_decl.ty);
+        }
+
+        let a_parts = parts(&a_decl.name.unwrap().as_str(), a_decl.ty, first);
+        let b_parts = parts(&b_decl.name.unwrap().as_str(), b_decl.ty, second);
+
+        for (a_part, b_part) in a_parts.into_iter().zip(b_parts) {
+            pairs.push(Expression::BinaryExpression(Rc::new(a_part),
+                                                    BinaryOperator::Equal,
+                                                    Rc::new(rename(&b_part))));
+        }
+    }
+
+    pairs
+}
+
+pub fn product_precondition(pre: &Expression, public: &[Expression]) -> Expression {
+    let mut condition = Expression::BinaryExpression(Rc::new(pre.clone()),
+                                                     BinaryOperator::And,
+                                                     Rc::new(rename(pre)));
+
+    for input in public {
+        condition = Expression::BinaryExpression(Rc::new(condition),
+                                                 BinaryOperator::And,
+                                                 Rc::new(agreement(input)));
+    }
+
+    condition
+}
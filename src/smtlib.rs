@@ -0,0 +1,305 @@
+use ast::{self, BinaryOperator, Expression, Types, UnaryOperator};
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::{BufWriter, Result, Write};
+use std::path::Path;
+
+// The exponent and significand widths of a floating-point type
+fn float_format(ty: Types) -> (usize, usize) {
+    match ty {
+        Types::F32 => (8, 24),
+        _ => (11, 53),
+    }
+}
+
+fn sort(ty: Types) -> String {
+    match ty {
+        Types::Bool => "Bool".to_string(),
+        Types::F32 | Types::F64 => {
+            let (exponent, significand) = float_format(ty);
+            format!("(_ FloatingPoint {} {})", exponent, significand)
+        }
+        _ => format!("(_ BitVec {})", ::bitvector_size(ty)),
+    }
+}
+
+fn array_sort(element: Types) -> String {
+    format!("(Array {} {})", sort(Types::Usize), sort(element))
+}
+
+fn declarations(expression: &Expression, found: &mut BTreeMap<String, String>) {
+    match *expression {
+        Expression::BinaryExpression(ref left, _, ref right) => {
+            declarations(left, found);
+            declarations(right, found);
+        }
+        Expression::UnaryExpression(_, ref expr) |
+        Expression::Forall(_, _, ref expr) => declarations(expr, found),
+        Expression::VariableMapping(ref name, ty) => {
+            found.insert(name.clone(), sort(ty));
+        }
+        Expression::Array(ref name, element) => {
+            found.insert(name.clone(), array_sort(element));
+        }
+        Expression::Length(ref array) => {
+            found.insert(format!("{}$len", ast::array_name(array)), sort(Types::Usize));
+        }
+        Expression::Select(ref array, ref index) => {
+            declarations(array, found);
+            declarations(index, found);
+        }
+        Expression::Store(ref array, ref index, ref value) => {
+            declarations(array, found);
+            declarations(index, found);
+            declarations(value, found);
+        }
+        Expression::FunctionCall(_, ref args, _) => {
+            for arg in args {
+                declarations(arg, found);
+            }
+        }
+        Expression::BitVector(..) |
+        Expression::Float(..) |
+        Expression::BooleanLiteral(_) => {}
+    }
+}
+
+// The operator for operands of type `operand`: Rust picks the signed or
+// unsigned operation by it, and bitwise operators act on bits unless they
+// combine booleans
+fn operator(op: BinaryOperator, operand: Types) -> &'static str {
+    let signed = ast::is_signed(operand);
+    let boolean = operand == Types::Bool;
+
+    match op {
+        BinaryOperator::Addition => "bvadd",
+        BinaryOperator::Subtraction => "bvsub",
+        BinaryOperator::Multiplication => "bvmul",
+        BinaryOperator::Division if signed => "bvsdiv",
+        BinaryOperator::Division => "bvudiv",
+        BinaryOperator::Modulo if signed => "bvsrem",
+        BinaryOperator::Modulo => "bvurem",
+        BinaryOperator::BitwiseOr if boolean => "or",
+        BinaryOperator::BitwiseOr => "bvor",
+        BinaryOperator::BitwiseAnd if boolean => "and",
+        BinaryOperator::BitwiseAnd => "bvand",
+        BinaryOperator::BitwiseXor if boolean => "xor",
+        BinaryOperator::BitwiseXor => "bvxor",
+        BinaryOperator::BitwiseLeftShift => "bvshl",
+        BinaryOperator::BitwiseRightShift if signed => "bvashr",
+        BinaryOperator::BitwiseRightShift => "bvlshr",
+        BinaryOperator::LessThan if signed => "bvslt",
+        BinaryOperator::LessThan => "bvult",
+        BinaryOperator::LessThanOrEqual if signed => "bvsle",
+        BinaryOperator::LessThanOrEqual => "bvule",
+        BinaryOperator::GreaterThan if signed => "bvsgt",
+        BinaryOperator::GreaterThan => "bvugt",
+        BinaryOperator::GreaterThanOrEqual if signed => "bvsge",
+        BinaryOperator::GreaterThanOrEqual => "bvuge",
+        BinaryOperator::Equal | BinaryOperator::BiImplication => "=",
+        BinaryOperator::NotEqual => "distinct",
+        BinaryOperator::And => "and",
+        BinaryOperator::Or => "or",
+        BinaryOperator::Xor => "xor",
+        BinaryOperator::Implication => "=>",
+    }
+}
+
+fn application<W: Write>(out: &mut W, function: &str, args: &[&Expression]) -> Result<()> {
+    write!(out, "({}", function)?;
+
+    for arg in args {
+        write!(out, " ")?;
+        term(out, arg)?;
+    }
+
+    write!(out, ")")
+}
+
+// Rust's `%` truncates the quotient like C's `fmod`, while `fp.rem` rounds
+// it to the nearest integer; the two differ by the divisor exactly when
+// their signs do, and adding it back is exact
+fn float_remainder<W: Write>(out: &mut W, left: &Expression, right: &Expression) -> Result<()> {
+    write!(out, "(let (($remainder ")?;
+    application(out, "fp.rem", &[left, right])?;
+    write!(out, ")) (ite (and (not (fp.isZero $remainder)) (distinct (fp.isNegative \
+                 $remainder) ")?;
+    application(out, "fp.isNegative", &[left])?;
+    write!(out, ")) (fp.add RNE $remainder (ite ")?;
+    application(out, "fp.isNegative", &[left])?;
+    write!(out, " (fp.neg ")?;
+    application(out, "fp.abs", &[right])?;
+    write!(out, ") ")?;
+    application(out, "fp.abs", &[right])?;
+    write!(out, ")) $remainder))")
+}
+
+// Arithmetic rounds to nearest, ties to even, as in Rust, and comparisons
+// are those of IEEE 754, under which `NaN` equals nothing
+fn float_operation<W: Write>(out: &mut W,
+                             op: BinaryOperator,
+                             left: &Expression,
+                             right: &Expression)
+                             -> Result<()> {
+    let function = match op {
+        BinaryOperator::Addition => "fp.add RNE",
+        BinaryOperator::Subtraction => "fp.sub RNE",
+        BinaryOperator::Multiplication => "fp.mul RNE",
+        BinaryOperator::Division => "fp.div RNE",
+        BinaryOperator::Modulo => return float_remainder(out, left, right),
+        BinaryOperator::LessThan => "fp.lt",
+        BinaryOperator::LessThanOrEqual => "fp.leq",
+        BinaryOperator::GreaterThan => "fp.gt",
+        BinaryOperator::GreaterThanOrEqual => "fp.geq",
+        BinaryOperator::Equal => "fp.eq",
+        BinaryOperator::NotEqual => {
+            write!(out, "(not ")?;
+            application(out, "fp.eq", &[left, right])?;
+            return write!(out, ")");
+        }
+        // Bitwise operators on floats are rejected by the type check
+        _ => unreachable!(),
+    };
+
+    application(out, function, &[left, right])
+}
+
+// Writes the term directly to `out`, so no text is built for the whole
+// condition at once
+fn term<W: Write>(out: &mut W, expression: &Expression) -> Result<()> {
+    match *expression {
+        Expression::BinaryExpression(ref left, op, ref right) => {
+            if let Some(lowered) = ast::boolean_ordering(left, op, right) {
+                return term(out, &lowered);
+            }
+
+            if ast::is_float(ast::determine_evaluation_type(left)) {
+                return float_operation(out, op, left, right);
+            }
+
+            write!(out, "({} ", operator(op, ast::determine_evaluation_type(left)))?;
+            term(out, left)?;
+            write!(out, " ")?;
+            term(out, right)?;
+            write!(out, ")")
+        }
+        Expression::UnaryExpression(ref op, ref expr) => {
+            match *op {
+                UnaryOperator::Negation if ast::is_float(ast::determine_evaluation_type(expr)) => {
+                    write!(out, "(fp.neg ")?
+                }
+                UnaryOperator::Negation => write!(out, "(bvneg ")?,
+                UnaryOperator::Not if ast::determine_evaluation_type(expr) == Types::Bool => {
+                    write!(out, "(not ")?
+                }
+                UnaryOperator::Not => write!(out, "(bvnot ")?,
+                UnaryOperator::Convert(ty) => {
+                    let source = ast::determine_evaluation_type(expr);
+                    let (from, to) = (::bitvector_size(source), ::bitvector_size(ty));
+
+                    // Narrowing keeps the low bits, as `as` does
+                    if to < from {
+                        write!(out, "((_ extract {} 0) ", to - 1)?
+                    } else {
+                        let extend = if ast::same_signedness(source, Types::I64) {
+                            "sign_extend"
+                        } else {
+                            "zero_extend"
+                        };
+                        write!(out, "((_ {} {}) ", extend, to - from)?
+                    }
+                }
+            }
+
+            term(out, expr)?;
+            write!(out, ")")
+        }
+        Expression::VariableMapping(ref name, _) |
+        Expression::Array(ref name, _) => write!(out, "|{}|", name),
+        Expression::Length(ref array) => write!(out, "|{}$len|", ast::array_name(array)),
+        Expression::Select(ref array, ref index) => {
+            write!(out, "(select ")?;
+            term(out, array)?;
+            write!(out, " ")?;
+            term(out, index)?;
+            write!(out, ")")
+        }
+        Expression::Store(ref array, ref index, ref value) => {
+            write!(out, "(store ")?;
+            term(out, array)?;
+            write!(out, " ")?;
+            term(out, index)?;
+            write!(out, " ")?;
+            term(out, value)?;
+            write!(out, ")")
+        }
+        Expression::BooleanLiteral(value) => write!(out, "{}", value),
+        // 128-bit literals are kept in 64 bits, extended by their signedness
+        Expression::BitVector(value, ty) if ::bitvector_size(ty) == 128 && ast::is_signed(ty) => {
+            write!(out, "((_ sign_extend 64) (_ bv{} 64))", value as u64)
+        }
+        Expression::BitVector(value, ty) => {
+            let size = ::bitvector_size(ty);
+            let mask = if size >= 64 { !0 } else { (1u64 << size) - 1 };
+            write!(out, "(_ bv{} {})", value as u64 & mask, size)
+        }
+        // Reinterpreting the bits of the literal keeps `NaN` and `-0.0`
+        Expression::Float(bits, ty) => {
+            let (exponent, significand) = float_format(ty);
+            write!(out,
+                   "((_ to_fp {} {}) (_ bv{} {}))",
+                   exponent,
+                   significand,
+                   bits,
+                   exponent + significand)
+        }
+        // Applications and quantifiers are eliminated before encoding
+        Expression::FunctionCall(..) |
+        Expression::Forall(..) => unreachable!(),
+    }
+}
+
+// A complete QF_AUFBV script checking the validity of `condition`, which is
+// valid when the script is unsat; conditions on floats add the theory of
+// floating point, in QF_ABVFP
+pub fn write<W: Write>(out: &mut W, label: &str, condition: &Expression) -> Result<()> {
+    let logic = if ast::any_type(condition, &ast::is_float) {
+        "QF_ABVFP"
+    } else {
+        "QF_AUFBV"
+    };
+
+    writeln!(out, "; {}", label)?;
+    writeln!(out, "(set-logic {})", logic)?;
+
+    let mut found = BTreeMap::new();
+    declarations(condition, &mut found);
+
+    for (name, sort) in found {
+        writeln!(out, "(declare-fun |{}| () {})", name, sort)?;
+    }
+
+    write!(out, "(assert (not ")?;
+    term(out, condition)?;
+    writeln!(out, "))")?;
+    writeln!(out, "(check-sat)")
+}
+
+// A label as a file name, without the separators of paths
+pub fn file_name(label: &str) -> String {
+    label.chars().map(|c| if c.is_alphanumeric() { c } else { '_' }).collect()
+}
+
+pub fn export(directory: &str, label: &str, condition: &Expression) {
+    let path = Path::new(directory).join(format!("{}.smt2", file_name(label)));
+
+    fs::create_dir_all(directory)
+        .unwrap_or_else(|e| error!("Cannot create `{}`: {}", directory, e));
+    let file = File::create(&path)
+        .unwrap_or_else(|e| error!("Cannot create `{}`: {}", path.display(), e));
+    let mut out = BufWriter::new(file);
+
+    write(&mut out, label, condition)
+        .and_then(|_| out.flush())
+        .unwrap_or_else(|e| error!("Cannot write `{}`: {}", path.display(), e));
+}
@@ -0,0 +1,134 @@
+use ast::{self, Expression};
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use syntax::codemap::{BytePos, CodeMap, Span};
+
+// While a clause is checked, every term parsed from it keeps the byte range
+// it was written at in the literal, and the terms `walk_and_replace` makes
+// of them keep the range of the term they came from. An ill-typed clause is
+// then reported at the subterm that is ill-typed instead of the whole
+// attribute. Terms are told apart by value, so a term written twice in a
+// clause is placed at its first occurrence
+
+thread_local! {
+    static RECORDING: Cell<bool> = Cell::new(false);
+    static RANGES: RefCell<HashMap<Expression, (usize, usize)>> = RefCell::new(HashMap::new());
+}
+
+pub fn begin() {
+    RANGES.with(|ranges| ranges.borrow_mut().clear());
+    RECORDING.with(|recording| recording.set(true));
+}
+
+// The clause is parsed; conditions parsed while it is replaced, such as
+// the definitions of theory functions, are not part of it
+pub fn parsed() {
+    RECORDING.with(|recording| recording.set(false));
+}
+
+pub fn finish() {
+    parsed();
+    RANGES.with(|ranges| ranges.borrow_mut().clear());
+}
+
+fn recording() -> bool {
+    RECORDING.with(|recording| recording.get())
+}
+
+pub fn range(expression: &Expression) -> Option<(usize, usize)> {
+    RANGES.with(|ranges| ranges.borrow().get(expression).cloned())
+}
+
+// Inner terms are parsed first, so a parenthesized term keeps the range
+// without its parentheses
+fn insert(range: (usize, usize), expression: &Expression) {
+    RANGES.with(|ranges| {
+                    ranges.borrow_mut().entry(expression.clone()).or_insert(range);
+                });
+}
+
+pub fn start(expression: &Expression) -> Option<usize> {
+    range(expression).map(|(lo, _)| lo)
+}
+
+pub fn end(expression: &Expression) -> Option<usize> {
+    range(expression).map(|(_, hi)| hi)
+}
+
+// Called by the parser with the offsets of the term, which a term built of
+// others takes from them
+pub fn record(lo: Option<usize>, hi: Option<usize>, expression: Expression) -> Expression {
+    if let (Some(lo), Some(hi), true) = (lo, hi, recording()) {
+        insert((lo, hi), &expression);
+    }
+
+    expression
+}
+
+// Whether terms are being placed, which is only while a clause is checked
+pub fn active() -> bool {
+    RANGES.with(|ranges| !ranges.borrow().is_empty())
+}
+
+// `to` was made of `from`, and is placed where it was written
+pub fn carry(from: &Expression, to: &Expression) {
+    if let Some(range) = range(from) {
+        insert(range, to);
+    }
+}
+
+fn children(expression: &Expression) -> Vec<&Expression> {
+    match *expression {
+        Expression::BinaryExpression(ref left, _, ref right) |
+        Expression::Select(ref left, ref right) => vec![&**left, &**right],
+        Expression::UnaryExpression(_, ref expr) |
+        Expression::Length(ref expr) |
+        Expression::Forall(_, _, ref expr) => vec![&**expr],
+        Expression::Store(ref array, ref index, ref value) => vec![&**array, &**index, &**value],
+        Expression::FunctionCall(_, ref args, _) => args.iter().collect(),
+        _ => Vec::new(),
+    }
+}
+
+// The smallest ill-typed term of an ill-typed condition that was written
+// somewhere in the clause
+pub fn culprit(expression: &Expression) -> Option<(usize, usize)> {
+    let inner = children(expression)
+        .into_iter()
+        .filter(|child| ast::ty_check(child).is_err())
+        .filter_map(culprit)
+        .next();
+
+    inner.or_else(|| range(expression))
+}
+
+// The span of `range` of `text`, the condition of the attribute or argument
+// at `span`. Conditions with escapes, and ranges outside of what was
+// written, such as the guard of an `ensures_err` clause, are placed at the
+// whole attribute
+pub fn locate(span: Span, text: &str, range: (usize, usize), codemap: &CodeMap) -> Span {
+    let snippet = match codemap.span_to_snippet(span) {
+        Ok(snippet) => snippet,
+        Err(_) => return span,
+    };
+    let (first, last) = match (snippet.find('"'), snippet.rfind('"')) {
+        (Some(first), Some(last)) if first < last => (first, last),
+        _ => return span,
+    };
+    let literal = &snippet[first + 1..last];
+    let start = match text.find(literal) {
+        Some(start) if !literal.contains('\\') => start,
+        _ => return span,
+    };
+    let (lo, hi) = range;
+
+    if lo < start || hi > start + literal.len() {
+        return span;
+    }
+
+    Span {
+        lo: BytePos(span.lo.0 + (first + 1 + lo - start) as u32),
+        hi: BytePos(span.lo.0 + (first + 1 + hi - start) as u32),
+        ..span
+    }
+}
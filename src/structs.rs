@@ -0,0 +1,177 @@
+use {MirData, assign, field_path, gen_expression, referenced};
+use ast::{self, Expression};
+use option;
+use rustc::mir::*;
+use rustc::ty::{Ty, TyCtxt, TypeVariants};
+
+// A struct has no value of its own: each of its fields is a variable named
+// by its path, as in `p.start.x`. Building, copying, moving or borrowing a
+// whole struct therefore assigns every field, and a call passing a struct to a
+// function with a contract renames the paths of the contract. Tuples are
+// modelled the same way, their fields named by position, as in `ret.0`
+
+// Deeper fields are not copied, which also stops at recursive types
+const MAX_DEPTH: usize = 4;
+
+pub fn is_struct(ty: Ty) -> bool {
+    match referenced(ty).sty {
+        TypeVariants::TyAdt(adt, _) if adt.is_struct() => true,
+        _ => false,
+    }
+}
+
+pub fn is_tuple(ty: Ty) -> bool {
+    match referenced(ty).sty {
+        TypeVariants::TyTuple(elements, _) => !elements.is_empty(),
+        _ => false,
+    }
+}
+
+// A variable holding a single value of type `ty`
+fn leaf(path: &str, ty: Ty) -> Expression {
+    match ast::element_type(ty) {
+        Some(element) => Expression::Array(path.to_string(), element),
+        None => Expression::VariableMapping(path.to_string(), ast::type_to_enum(ty)),
+    }
+}
+
+fn leaves<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
+                    path: &str,
+                    ty: Ty<'tcx>,
+                    depth: usize,
+                    found: &mut Vec<Expression>) {
+    let ty = referenced(ty);
+
+    match ty.sty {
+        TypeVariants::TyAdt(adt, substs) if adt.is_struct() => {
+            if depth == MAX_DEPTH {
+                return;
+            }
+
+            for field in &adt.struct_variant().fields {
+                leaves(tcx,
+                       &format!("{}.{}", path, field.name),
+                       field.ty(tcx, substs),
+                       depth + 1,
+                       found);
+            }
+        }
+        TypeVariants::TyTuple(elements, _) if !elements.is_empty() => {
+            if depth == MAX_DEPTH {
+                return;
+            }
+
+            for (index, &element) in elements.iter().enumerate() {
+                leaves(tcx, &format!("{}.{}", path, index), element, depth + 1, found);
+            }
+        }
+        _ => {
+            let leaf = leaf(path, ty);
+
+            if ast::is_option(ty) {
+                found.push(option::tag(&leaf));
+            }
+
+            found.push(leaf);
+        }
+    }
+}
+
+// The variables holding the fields of the struct or tuple at `path`, nested
+// ones included
+pub fn fields<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>, path: &str, ty: Ty<'tcx>) -> Vec<Expression> {
+    let mut found = Vec::new();
+
+    if is_struct(ty) || is_tuple(ty) {
+        leaves(tcx, path, ty, 0, &mut found);
+    }
+
+    found
+}
+
+// The same field of the struct at `path`, for a field of the struct at
+// `from`
+pub fn rebase(field: &Expression, from: &str, path: &str) -> Expression {
+    let rebased = |name: &str| format!("{}{}", path, &name[from.len()..]);
+
+    match *field {
+        Expression::VariableMapping(ref name, ty) => Expression::VariableMapping(rebased(name), ty),
+        Expression::Array(ref name, ty) => Expression::Array(rebased(name), ty),
+        _ => field.clone(),
+    }
+}
+
+// Assigns the fields at `dest` those at `source`
+fn copy(wp: Expression, dest: &str, ty: Ty, source: &Lvalue, data: &MirData) -> Expression {
+    let source = match field_path(source, data) {
+        Some((source, _)) => source,
+        None => return wp,
+    };
+
+    fields(data.tcx, dest, ty)
+        .iter()
+        .fold(wp, |wp, field| assign(&wp, field, &rebase(field, dest, &source), data))
+}
+
+// Applied after the statement's own weakest precondition
+pub fn statement(wp: Expression, stmt: &Statement, data: &MirData) -> Expression {
+    let (lvalue, rvalue) = match stmt.kind {
+        StatementKind::Assign(ref lvalue, ref rvalue) => (lvalue, rvalue),
+        _ => return wp,
+    };
+
+    let (dest, ty) = match field_path(lvalue, data) {
+        Some((dest, ty)) if is_struct(ty) || is_tuple(ty) => (dest, ty),
+        _ => return wp,
+    };
+
+    match *rvalue {
+        Rvalue::Use(Operand::Consume(ref source)) |
+        Rvalue::Ref(_, _, ref source) => copy(wp, &dest, ty, source, data),
+        // Each element of a tuple built in place, whole structs and tuples
+        // among them
+        Rvalue::Aggregate(AggregateKind::Tuple, ref operands) => {
+            let elements = match referenced(ty).sty {
+                TypeVariants::TyTuple(elements, _) => elements,
+                _ => return wp,
+            };
+            let mut wp = wp;
+
+            for (index, (operand, &element)) in operands.iter().zip(elements.iter()).enumerate() {
+                let path = format!("{}.{}", dest, index);
+
+                wp = match *operand {
+                    Operand::Consume(ref source) if is_struct(element) || is_tuple(element) => {
+                        copy(wp, &path, element, source, data)
+                    }
+                    _ => assign(&wp, &leaf(&path, element), &gen_expression(operand, data), data),
+                };
+            }
+
+            wp
+        }
+        // So is each field of a struct
+        Rvalue::Aggregate(AggregateKind::Adt(adt, _, substs, _), ref operands) => {
+            if !adt.is_struct() {
+                return wp;
+            }
+
+            let mut wp = wp;
+
+            for (operand, field) in operands.iter().zip(adt.struct_variant().fields.iter()) {
+                let path = format!("{}.{}", dest, field.name);
+                let field_ty = field.ty(data.tcx, substs);
+
+                wp = match *operand {
+                    Operand::Consume(ref source) if is_struct(field_ty) || is_tuple(field_ty) => {
+                        copy(wp, &path, field_ty, source, data)
+                    }
+                    _ => assign(&wp, &leaf(&path, field_ty), &gen_expression(operand, data), data),
+                };
+            }
+
+            wp
+        }
+        _ => wp,
+    }
+}
@@ -0,0 +1,248 @@
+use std::collections::HashMap;
+use std::fmt::{Debug, Error, Formatter};
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::Path;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Status {
+    Valid,
+    Invalid,
+    Error,
+    // Not attempted, as the time budget was spent
+    Skipped,
+}
+
+#[derive(Clone)]
+pub struct Summary {
+    pub label: String,
+    pub status: Status,
+    pub pre: String,
+    pub post: String,
+    // Milliseconds the solver took
+    pub cost: u64,
+}
+
+#[derive(Clone)]
+pub struct StableContract {
+    pub name: String,
+    pub since: String,
+    pub pre: String,
+    pub post: String,
+}
+
+impl Debug for Status {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        match *self {
+            Status::Valid => write!(fmt, "valid"),
+            Status::Invalid => write!(fmt, "invalid"),
+            Status::Error => write!(fmt, "error"),
+            Status::Skipped => write!(fmt, "skipped"),
+        }
+    }
+}
+
+// The function an obligation's label is about, before the kind of check
+pub fn function(label: &str) -> &str {
+    &label[..label.find(" (").unwrap_or(label.len())]
+}
+
+fn string_to_status(s: &str) -> Status {
+    match s {
+        "valid" => Status::Valid,
+        "invalid" => Status::Invalid,
+        "error" => Status::Error,
+        "skipped" => Status::Skipped,
+        _ => error!("Unknown status `{}` in summary file", s),
+    }
+}
+
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('\t', "\\t").replace('\n', "\\n")
+}
+
+fn unescape(s: &str) -> String {
+    let mut result = String::new();
+    let mut chars = s.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('t') => result.push('\t'),
+            Some('n') => result.push('\n'),
+            Some(other) => result.push(other),
+            None => result.push('\\'),
+        }
+    }
+
+    result
+}
+
+// Lines starting with `#` are comments
+fn read_records(path: &str, fields: &[usize]) -> Vec<Vec<String>> {
+    read_lines(path)
+        .into_iter()
+        .filter(|line| !line.starts_with('#'))
+        .map(|line| {
+            let record = line.split('\t').map(unescape).collect::<Vec<_>>();
+
+            if !fields.contains(&record.len()) {
+                error!("Malformed line in `{}`: `{}`", path, line);
+            }
+
+            record
+        })
+        .collect()
+}
+
+fn read_lines(path: &str) -> Vec<String> {
+    let file = File::open(path).unwrap_or_else(|e| error!("Cannot open `{}`: {}", path, e));
+
+    BufReader::new(file)
+        .lines()
+        .map(|line| line.unwrap_or_else(|e| error!("Cannot read `{}`: {}", path, e)))
+        .collect()
+}
+
+fn write_records(path: &str, header: Option<&str>, records: Vec<Vec<&str>>) {
+    let mut file = File::create(path).unwrap_or_else(|e| error!("Cannot create `{}`: {}", path, e));
+
+    if let Some(header) = header {
+        writeln!(file, "# {}", header).unwrap_or_else(|e| error!("Cannot write `{}`: {}", path, e));
+    }
+
+    for record in records {
+        writeln!(file,
+                 "{}",
+                 record.into_iter().map(escape).collect::<Vec<_>>().join("\t"))
+            .unwrap_or_else(|e| error!("Cannot write `{}`: {}", path, e));
+    }
+}
+
+// What produced a summary file, from its first line, e.g. `# producer`
+pub fn producer(path: &str) -> Option<String> {
+    read_lines(path)
+        .into_iter()
+        .next()
+        .and_then(|line| if line.starts_with("# ") {
+                      Some(line[2..].to_string())
+                  } else {
+                      None
+                  })
+}
+
+// One summary per line: label, status, pre, post and cost separated by
+// tabs; summaries written before costs were recorded have no cost
+pub fn load(path: &str) -> HashMap<String, Summary> {
+    read_records(path, &[4, 5])
+        .into_iter()
+        .map(|fields| {
+            (fields[0].clone(),
+             Summary {
+                 label: fields[0].clone(),
+                 status: string_to_status(&fields[1]),
+                 pre: fields[2].clone(),
+                 post: fields[3].clone(),
+                 cost: fields.get(4).map_or(0, |cost| {
+                     cost.parse()
+                         .unwrap_or_else(|_| error!("Malformed cost `{}` in `{}`", cost, path))
+                 }),
+             })
+        })
+        .collect()
+}
+
+pub fn save(path: &str, producer: &str, summaries: &[Summary]) {
+    let statuses = summaries.iter().map(|s| format!("{:?}", s.status)).collect::<Vec<_>>();
+    let costs = summaries.iter().map(|s| s.cost.to_string()).collect::<Vec<_>>();
+
+    write_records(path,
+                  Some(producer),
+                  summaries.iter()
+                      .zip(statuses.iter().zip(costs.iter()))
+                      .map(|(s, (status, cost))| {
+                          vec![&*s.label, &**status, &*s.pre, &*s.post, &**cost]
+                      })
+                      .collect());
+}
+
+// Every solved obligation is appended to the journal `path.partial` as
+// soon as it is solved, so an interrupted run leaves behind what it got
+// through; the journal is removed once the summaries are written
+pub fn journal(path: &str) -> String {
+    format!("{}.partial", path)
+}
+
+// One solved obligation per line: label, status, cost and a hash of the
+// obligation. A journal written by another producer is not resumed from
+pub fn load_journal(path: &str, producer: &str) -> HashMap<String, (Status, u64, String)> {
+    if !Path::new(path).exists() || self::producer(path).as_ref().map(|p| &**p) != Some(producer) {
+        return HashMap::new();
+    }
+
+    read_records(path, &[4])
+        .into_iter()
+        .map(|fields| {
+            let cost = fields[2].parse().unwrap_or_else(|_| {
+                error!("Malformed cost `{}` in `{}`", fields[2], path)
+            });
+
+            (fields[0].clone(), (string_to_status(&fields[1]), cost, fields[3].clone()))
+        })
+        .collect()
+}
+
+pub fn append_journal(path: &str,
+                      producer: &str,
+                      label: &str,
+                      status: Status,
+                      cost: u64,
+                      hash: &str) {
+    let mut file = OpenOptions::new()
+        .append(true)
+        .create(true)
+        .open(path)
+        .unwrap_or_else(|e| error!("Cannot open `{}`: {}", path, e));
+    let empty = file.metadata().map(|metadata| metadata.len() == 0).unwrap_or(false);
+
+    if empty {
+        writeln!(file, "# {}", producer)
+            .unwrap_or_else(|e| error!("Cannot write `{}`: {}", path, e));
+    }
+
+    let record = [label, &*format!("{:?}", status), &*cost.to_string(), hash];
+
+    writeln!(file,
+             "{}",
+             record.iter().map(|field| escape(field)).collect::<Vec<_>>().join("\t"))
+        .and_then(|_| file.flush())
+        .unwrap_or_else(|e| error!("Cannot write `{}`: {}", path, e));
+}
+
+// One stable contract per line: name, since, pre and post
+pub fn load_stable(path: &str) -> HashMap<String, StableContract> {
+    read_records(path, &[4])
+        .into_iter()
+        .map(|fields| {
+            (fields[0].clone(),
+             StableContract {
+                 name: fields[0].clone(),
+                 since: fields[1].clone(),
+                 pre: fields[2].clone(),
+                 post: fields[3].clone(),
+             })
+        })
+        .collect()
+}
+
+pub fn save_stable(path: &str, contracts: &[StableContract]) {
+    write_records(path,
+                  None,
+                  contracts.iter()
+                      .map(|c| vec![&*c.name, &*c.since, &*c.pre, &*c.post])
+                      .collect());
+}
@@ -0,0 +1,203 @@
+use ast::{BinaryOperator, Expression, Types};
+use condition_parser;
+use numeric;
+use std::rc::Rc;
+
+// A theory is a set of functions a crate can use in its specifications.
+// Functions with a definition are expanded where they are used; the others
+// are uninterpreted, constrained only by their axioms and by congruence.
+pub struct Theory {
+    pub name: String,
+    pub functions: Vec<Function>,
+}
+
+pub struct Function {
+    pub name: String,
+    pub parameters: Vec<(String, Types)>,
+    pub result: Types,
+    // A condition over the parameters giving the function's value
+    pub definition: Option<String>,
+    // Conditions over the parameters and `result` that hold at every use
+    pub axioms: Vec<String>,
+}
+
+pub fn find<'t>(theories: &'t [Theory], name: &str) -> Option<&'t Function> {
+    theories.iter().flat_map(|theory| theory.functions.iter()).find(|f| f.name == name)
+}
+
+// Replaces every variable called `name`, whatever its type, by `value`
+pub fn bind(expression: &Expression, name: &str, value: &Expression) -> Expression {
+    match *expression {
+        Expression::BinaryExpression(ref left, ref op, ref right) => {
+            Expression::BinaryExpression(Rc::new(bind(left, name, value)),
+                                         *op,
+                                         Rc::new(bind(right, name, value)))
+        }
+        Expression::UnaryExpression(ref op, ref expr) => {
+            Expression::UnaryExpression(op.clone(), Rc::new(bind(expr, name, value)))
+        }
+        Expression::FunctionCall(ref function, ref args, ty) => {
+            Expression::FunctionCall(function.clone(),
+                                     args.iter().map(|arg| bind(arg, name, value)).collect(),
+                                     ty)
+        }
+        Expression::Length(ref array) => Expression::Length(Rc::new(bind(array, name, value))),
+        Expression::Select(ref array, ref index) => {
+            Expression::Select(Rc::new(bind(array, name, value)),
+                               Rc::new(bind(index, name, value)))
+        }
+        Expression::Store(ref array, ref index, ref stored) => {
+            Expression::Store(Rc::new(bind(array, name, value)),
+                              Rc::new(bind(index, name, value)),
+                              Rc::new(bind(stored, name, value)))
+        }
+        Expression::Forall(ref var, _, _) if var == name => expression.clone(),
+        Expression::Forall(ref var, ty, ref body) => {
+            Expression::Forall(var.clone(), ty, Rc::new(bind(body, name, value)))
+        }
+        Expression::VariableMapping(ref var, _) if var == name => value.clone(),
+        _ => expression.clone(),
+    }
+}
+
+pub fn instantiate(body: &Expression, function: &Function, args: &[Expression]) -> Expression {
+    function.parameters
+        .iter()
+        .zip(args.iter())
+        .fold(body.clone(), |body, (&(ref param, _), arg)| bind(&body, param, arg))
+}
+
+fn applications(expression: &Expression, found: &mut Vec<Expression>) {
+    match *expression {
+        Expression::BinaryExpression(ref left, _, ref right) => {
+            applications(left, found);
+            applications(right, found);
+        }
+        Expression::UnaryExpression(_, ref expr) |
+        Expression::Length(ref expr) |
+        Expression::Forall(_, _, ref expr) => applications(expr, found),
+        Expression::Select(ref array, ref index) => {
+            applications(array, found);
+            applications(index, found);
+        }
+        Expression::Store(ref array, ref index, ref value) => {
+            applications(array, found);
+            applications(index, found);
+            applications(value, found);
+        }
+        Expression::FunctionCall(_, ref args, _) => {
+            for arg in args {
+                applications(arg, found);
+            }
+
+            if !found.contains(expression) {
+                found.push(expression.clone());
+            }
+        }
+        _ => {}
+    }
+}
+
+fn replace_applications(expression: &Expression, found: &[Expression]) -> Expression {
+    match *expression {
+        Expression::BinaryExpression(ref left, ref op, ref right) => {
+            Expression::BinaryExpression(Rc::new(replace_applications(left, found)),
+                                         *op,
+                                         Rc::new(replace_applications(right, found)))
+        }
+        Expression::UnaryExpression(ref op, ref expr) => {
+            Expression::UnaryExpression(op.clone(), Rc::new(replace_applications(expr, found)))
+        }
+        Expression::Length(ref array) => {
+            Expression::Length(Rc::new(replace_applications(array, found)))
+        }
+        Expression::Select(ref array, ref index) => {
+            Expression::Select(Rc::new(replace_applications(array, found)),
+                               Rc::new(replace_applications(index, found)))
+        }
+        Expression::Store(ref array, ref index, ref value) => {
+            Expression::Store(Rc::new(replace_applications(array, found)),
+                              Rc::new(replace_applications(index, found)),
+                              Rc::new(replace_applications(value, found)))
+        }
+        Expression::Forall(ref var, ty, ref body) => {
+            Expression::Forall(var.clone(), ty, Rc::new(replace_applications(body, found)))
+        }
+        Expression::FunctionCall(ref name, _, ty) => {
+            let index = found.iter().position(|e| e == expression).unwrap();
+            Expression::VariableMapping(format!("{}$app{}", name, index), ty)
+        }
+        _ => expression.clone(),
+    }
+}
+
+fn and(left: Expression, right: Expression) -> Expression {
+    Expression::BinaryExpression(Rc::new(left), BinaryOperator::And, Rc::new(right))
+}
+
+// Turns a verification condition using uninterpreted functions into one the
+// solver understands: each distinct application becomes a fresh variable,
+// assumed to satisfy the function's axioms and to agree with every other
+// application of the same function on equal arguments
+pub fn eliminate(verification_condition: &Expression, theories: &[Theory]) -> Expression {
+    let mut found = Vec::new();
+    applications(verification_condition, &mut found);
+
+    if found.is_empty() {
+        return verification_condition.clone();
+    }
+
+    let mut hypothesis = Expression::BooleanLiteral(true);
+
+    for application in &found {
+        if let Some(axiom) = numeric::axiom(application) {
+            hypothesis = and(hypothesis, axiom);
+        } else if let Expression::FunctionCall(ref name, ref args, _) = *application {
+            let function = find(theories, name).unwrap();
+
+            for axiom in &function.axioms {
+                let axiom = condition_parser::parse_Condition(axiom).unwrap_or_else(|e| {
+                    error!("Error parsing axiom `{}` of `{}` with error `{:?}`", axiom, name, e)
+                });
+                let axiom = bind(&instantiate(&axiom, function, args), "result", application);
+                hypothesis = and(hypothesis, axiom);
+            }
+        }
+    }
+
+    let mut constraints = Vec::new();
+    applications(&hypothesis, &mut found);
+
+    for (i, first) in found.iter().enumerate() {
+        for second in &found[i + 1..] {
+            if let (&Expression::FunctionCall(ref f, ref f_args, _),
+                    &Expression::FunctionCall(ref g, ref g_args, _)) = (first, second) {
+                if f != g {
+                    continue;
+                }
+
+                let same_args = f_args.iter()
+                    .zip(g_args.iter())
+                    .fold(Expression::BooleanLiteral(true), |acc, (a, b)| {
+                        and(acc,
+                            Expression::BinaryExpression(Rc::new(a.clone()),
+                                                         BinaryOperator::Equal,
+                                                         Rc::new(b.clone())))
+                    });
+
+                constraints.push(Expression::BinaryExpression(Rc::new(same_args),
+                                                              BinaryOperator::Implication,
+                                                              Rc::new(Expression::BinaryExpression(Rc::new(first.clone()),
+                                                                                                   BinaryOperator::Equal,
+                                                                                                   Rc::new(second.clone())))));
+            }
+        }
+    }
+
+    let hypothesis = constraints.into_iter().fold(hypothesis, and);
+    let closed = Expression::BinaryExpression(Rc::new(hypothesis),
+                                              BinaryOperator::Implication,
+                                              Rc::new(verification_condition.clone()));
+
+    replace_applications(&closed, &found)
+}
@@ -0,0 +1,138 @@
+use {MirData, gen_lvalue, parse_attributes, parse_condition, referenced,
+     substitute_variable_with_expression, walk_and_replace};
+use ast::{self, BinaryOperator, Expression};
+use lock;
+use rustc::hir::def_id::DefId;
+use rustc::mir::*;
+use rustc::ty::{Ty, TyCtxt, TypeVariants};
+use self_composition;
+use theory;
+use std::rc::Rc;
+
+enum Call {
+    Spawn,
+    Join,
+    // Unwrapping the result of a join, which is the spawned closure's value
+    Joined,
+}
+
+fn is_join_handle(tcx: TyCtxt, ty: Ty) -> bool {
+    match referenced(ty).sty {
+        TypeVariants::TyAdt(adt, _) => tcx.item_path_str(adt.did) == "std::thread::JoinHandle",
+        _ => false,
+    }
+}
+
+// The call whose result was moved or copied into `local`
+fn defining_call<'a, 'tcx>(local: Local,
+                           data: &MirData<'a, 'tcx>)
+                           -> Option<(DefId, Vec<Operand<'tcx>>)> {
+    for block in &data.block_data {
+        if let TerminatorKind::Call { ref func, ref args, ref destination, .. } =
+            block.terminator().kind {
+            match *destination {
+                Some((Lvalue::Local(dest), _)) if dest == local => {}
+                _ => continue,
+            }
+
+            return ::function_def_id(func).map(|def_id| (def_id, args.clone()));
+        }
+    }
+
+    None
+}
+
+fn call_kind(data: &MirData, def_id: DefId, args: &[Operand]) -> Option<Call> {
+    if data.tcx.item_path_str(def_id) == "std::thread::spawn" {
+        return Some(Call::Spawn);
+    }
+
+    let receiver = match args.first() {
+        Some(receiver) => receiver,
+        None => return None,
+    };
+
+    match &*data.tcx.item_name(def_id).as_str() {
+        "join" if is_join_handle(data.tcx, receiver.ty(data.mir, data.tcx)) => Some(Call::Join),
+        "unwrap" | "expect" => {
+            let joined = lock::source(receiver, data)
+                .and_then(|local| defining_call(local, data))
+                .map_or(false, |(def_id, args)| match call_kind(data, def_id, &args) {
+                    Some(Call::Join) => true,
+                    _ => false,
+                });
+
+            if joined { Some(Call::Joined) } else { None }
+        }
+        _ => None,
+    }
+}
+
+pub fn is_thread_call(data: &MirData, def_id: DefId, args: &[Operand]) -> bool {
+    call_kind(data, def_id, args).is_some()
+}
+
+// The postcondition of the closure a join handle was spawned with, over
+// `ret`; spawned closures take no arguments, so their precondition can
+// only be `true`
+fn spawned_post<'a, 'tcx>(handle: &Operand<'tcx>, data: &MirData<'a, 'tcx>) -> Option<String> {
+    let spawn_args = match lock::source(handle, data).and_then(|local| defining_call(local, data)) {
+        Some((def_id, args)) => {
+            match call_kind(data, def_id, &args) {
+                Some(Call::Spawn) => args,
+                _ => return None,
+            }
+        }
+        None => return None,
+    };
+
+    let closure_id = match spawn_args[0].ty(data.mir, data.tcx).sty {
+        TypeVariants::TyClosure(def_id, _) => def_id,
+        _ => return None,
+    };
+
+    let (pre, post) = parse_attributes(&data.tcx.get_attrs(closure_id),
+                                      &data.config,
+                                      data.tcx.sess.codemap());
+
+    if pre != "" && pre != "true" {
+        error!("Spawned closures take no arguments, so their precondition must be `true`");
+    }
+
+    if post == "" { None } else { Some(post) }
+}
+
+// A spawned thread only shares state with others through atomics and locks,
+// which are havocked at every access anyway, so spawning and joining leave
+// the rest of the state alone; the joined value is anything satisfying the
+// closure's postcondition
+pub fn call(wp: &Expression,
+            def_id: DefId,
+            args: &[Operand],
+            destination: Lvalue,
+            index: usize,
+            depth: usize,
+            data: &MirData)
+            -> Expression {
+    match call_kind(data, def_id, args) {
+        Some(Call::Joined) => {}
+        _ => return wp.clone(),
+    }
+
+    let dest = data.composition.rename(&gen_lvalue(destination, data));
+    let joined = self_composition::rename_with(&dest, &format!("$joined{}_{}", index, depth));
+    let wp = substitute_variable_with_expression(wp, &dest, &joined);
+
+    let handle = lock::source(&args[0], data)
+        .and_then(|local| defining_call(local, data))
+        .map(|(_, join_args)| join_args[0].clone());
+    let post = match handle.and_then(|handle| spawned_post(&handle, data)) {
+        Some(post) => post,
+        None => return wp,
+    };
+
+    let assumed = walk_and_replace(theory::bind(&parse_condition(post), "ret", &joined), data);
+    ast::ty_check(&assumed).unwrap_or_else(|e| error!("{}", e));
+
+    Expression::BinaryExpression(Rc::new(assumed), BinaryOperator::Implication, Rc::new(wp))
+}
@@ -0,0 +1,172 @@
+use {MirData, local_name, substitute_variable_with_expression};
+use ast::{BinaryOperator, Expression, Types};
+use rustc::hir::def_id::DefId;
+use rustc::mir::*;
+use self_composition;
+use std::rc::Rc;
+
+// Locals holding the result of `mem::uninitialized()` carry a ghost flag,
+// set once the whole local is written; reading one while it is unset is
+// undefined behaviour, and fails verification like a panic
+
+pub fn is_uninitialized_call(data: &MirData, def_id: DefId) -> bool {
+    match data.tcx.item_path_str(def_id).as_ref() {
+        "std::mem::uninitialized" | "core::mem::uninitialized" => true,
+        _ => false,
+    }
+}
+
+// A whole-local move of a tracked local, which carries its flag along
+fn moved(stmt: &Statement) -> Option<(Local, Local)> {
+    match stmt.kind {
+        StatementKind::Assign(Lvalue::Local(dest),
+                              Rvalue::Use(Operand::Consume(Lvalue::Local(source)))) => {
+            Some((dest, source))
+        }
+        _ => None,
+    }
+}
+
+fn tracked(data: &MirData) -> Vec<Local> {
+    let mut tracked = data.block_data
+        .iter()
+        .filter_map(|block| match block.terminator().kind {
+            TerminatorKind::Call { ref func, destination: Some((Lvalue::Local(local), _)), .. } => {
+                match ::function_def_id(func) {
+                    Some(def_id) if is_uninitialized_call(data, def_id) => Some(local),
+                    _ => None,
+                }
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    loop {
+        let moves = data.block_data
+            .iter()
+            .flat_map(|block| block.statements.iter().filter_map(moved))
+            .filter(|&(dest, source)| tracked.contains(&source) && !tracked.contains(&dest))
+            .map(|(dest, _)| dest)
+            .collect::<Vec<_>>();
+
+        if moves.is_empty() {
+            return tracked;
+        }
+
+        tracked.extend(moves);
+    }
+}
+
+fn flag(local: Local, data: &MirData) -> Expression {
+    data.composition.rename(&Expression::VariableMapping(format!("{}$init",
+                                                                 local_name(local, data)),
+                                                         Types::Bool))
+}
+
+// The local is anything at all, and not yet initialized
+pub fn uninitialized(wp: &Expression,
+                     destination: Lvalue,
+                     index: usize,
+                     depth: usize,
+                     data: &MirData)
+                     -> Expression {
+    let local = match destination {
+        Lvalue::Local(local) => local,
+        _ => error!("`mem::uninitialized()` is only supported when assigned to a local"),
+    };
+
+    let dest = data.composition.rename(&::gen_lvalue(destination, data));
+    let garbage = self_composition::rename_with(&dest, &format!("$uninit{}_{}", index, depth));
+    let wp = substitute_variable_with_expression(wp, &dest, &garbage);
+
+    substitute_variable_with_expression(&wp, &flag(local, data), &Expression::BooleanLiteral(false))
+}
+
+fn lvalue_reads(lvalue: &Lvalue, found: &mut Vec<Local>) {
+    match *lvalue {
+        Lvalue::Local(local) => found.push(local),
+        Lvalue::Projection(ref projection) => {
+            lvalue_reads(&projection.base, found);
+
+            if let ProjectionElem::Index(ref index) = projection.elem {
+                operand_reads(index, found);
+            }
+        }
+        Lvalue::Static(_) => {}
+    }
+}
+
+fn operand_reads(operand: &Operand, found: &mut Vec<Local>) {
+    if let Operand::Consume(ref lvalue) = *operand {
+        lvalue_reads(lvalue, found);
+    }
+}
+
+fn rvalue_reads(rvalue: &Rvalue, found: &mut Vec<Local>) {
+    match *rvalue {
+        Rvalue::Use(ref operand) |
+        Rvalue::Repeat(ref operand, _) |
+        Rvalue::Cast(_, ref operand, _) |
+        Rvalue::UnaryOp(_, ref operand) => operand_reads(operand, found),
+        Rvalue::BinaryOp(_, ref left, ref right) |
+        Rvalue::CheckedBinaryOp(_, ref left, ref right) => {
+            operand_reads(left, found);
+            operand_reads(right, found);
+        }
+        Rvalue::Aggregate(_, ref operands) => {
+            for operand in operands {
+                operand_reads(operand, found);
+            }
+        }
+        Rvalue::Len(ref lvalue) |
+        Rvalue::Discriminant(ref lvalue) => lvalue_reads(lvalue, found),
+        // Borrowing does not read, but the borrow may be read through later
+        Rvalue::Ref(..) |
+        Rvalue::Box(_) => {}
+    }
+}
+
+// Applied after the statement's own weakest precondition: a write of the
+// whole local initializes it, and every tracked local read must already be
+pub fn statement(wp: Expression, stmt: &Statement, data: &MirData) -> Expression {
+    let (lvalue, rvalue) = match stmt.kind {
+        StatementKind::Assign(ref lvalue, ref rvalue) => (lvalue, rvalue),
+        _ => return wp,
+    };
+
+    let tracked = tracked(data);
+    if tracked.is_empty() {
+        return wp;
+    }
+
+    if let Some((dest, source)) = moved(stmt) {
+        if tracked.contains(&source) {
+            return substitute_variable_with_expression(&wp, &flag(dest, data), &flag(source, data));
+        }
+    }
+
+    let mut wp = wp;
+
+    if let Lvalue::Local(local) = *lvalue {
+        if tracked.contains(&local) {
+            wp = substitute_variable_with_expression(&wp,
+                                                     &flag(local, data),
+                                                     &Expression::BooleanLiteral(true));
+        }
+    }
+
+    if data.composition.is_relational() {
+        return wp;
+    }
+
+    let mut reads = Vec::new();
+    rvalue_reads(rvalue, &mut reads);
+
+    for local in reads.into_iter().filter(|local| tracked.contains(local)) {
+        wp = Expression::BinaryExpression(Rc::new(flag(local, data)),
+                                          BinaryOperator::And,
+                                          Rc::new(wp));
+    }
+
+    wp
+}
@@ -0,0 +1,128 @@
+use {MirData, function_def_id, gen_expression, gen_lvalue, invariant,
+     substitute_variable_with_expression};
+use ast::{self, BinaryOperator, Expression, Types};
+use rustc::hir::def_id::DefId;
+use rustc::mir::*;
+use self_composition;
+use std::rc::Rc;
+
+// A variant proves termination. It is an integer that is non-negative and
+// strictly smaller every time the loop comes back to its header, measured
+// against its value at the header, and at every call of the function to
+// itself, measured against its value on entry. The termination check runs
+// with `true` as the postcondition, so it only fails on a path that does
+// not decrease the variant
+
+// Names the variables of the variant at the loop header, so that the
+// substitutions of the loop body leave them alone
+pub const HEAD_SUFFIX: &'static str = "$head";
+const RESULT_SUFFIX: &'static str = "$result";
+
+pub struct Variant {
+    pub function: DefId,
+    // The variant in terms of the variables at the loop and of the
+    // arguments, for those the function has
+    at_loop: Option<Expression>,
+    at_entry: Option<Expression>,
+}
+
+fn binary(left: Expression, op: BinaryOperator, right: Expression) -> Expression {
+    Expression::BinaryExpression(Rc::new(left), op, Rc::new(right))
+}
+
+fn check_integer(name: &str, variant: &Expression) {
+    match ast::determine_evaluation_type(variant) {
+        Types::Bool | Types::Void => {
+            error!("The variant `{}` of {} must be an integer", variant, name)
+        }
+        _ => {}
+    }
+}
+
+fn is_recursive(function: DefId, data: &MirData) -> bool {
+    data.mir.basic_blocks().iter().any(|block| match block.terminator().kind {
+        TerminatorKind::Call { ref func, .. } => function_def_id(func) == Some(function),
+        _ => false,
+    })
+}
+
+// That `variant` is non-negative at `before` and smaller at `after`
+fn decrease(after: Expression, before: Expression) -> Expression {
+    let zero = Expression::BitVector(0, ast::determine_evaluation_type(&before));
+
+    binary(binary(zero, BinaryOperator::LessThanOrEqual, before.clone()),
+           BinaryOperator::And,
+           binary(after, BinaryOperator::LessThan, before))
+}
+
+pub fn resolve(name: &str, condition: &str, function: DefId, data: &MirData) -> Variant {
+    let at_loop = if invariant::loops(data.mir) > 0 {
+        Some(invariant::resolve_locals(name, condition, data))
+    } else {
+        None
+    };
+    let at_entry = if is_recursive(function, data) {
+        Some(data.resolve_condition(condition))
+    } else {
+        None
+    };
+
+    if at_loop.is_none() && at_entry.is_none() {
+        error!("{} has a `variant` but neither loops nor calls itself", name);
+    }
+
+    for variant in at_loop.iter().chain(at_entry.iter()) {
+        check_integer(name, variant);
+    }
+
+    Variant {
+        function: function,
+        at_loop: at_loop,
+        at_entry: at_entry,
+    }
+}
+
+// The condition on coming back to the loop header: the invariant, and that
+// the variant decreased since the header was last left
+pub fn back_edge(invariant: &Expression, variant: &Variant) -> Expression {
+    match variant.at_loop {
+        Some(ref at_loop) => {
+            binary(invariant.clone(),
+                   BinaryOperator::And,
+                   decrease(at_loop.clone(),
+                            self_composition::rename_with(at_loop, HEAD_SUFFIX)))
+        }
+        None => invariant.clone(),
+    }
+}
+
+// A call of the function to itself must decrease the variant; what it
+// returns is left unconstrained
+pub fn recursive_call(wp: &Expression,
+                      variant: &Variant,
+                      args: &[Operand],
+                      destination: Lvalue,
+                      index: usize,
+                      depth: usize,
+                      data: &MirData)
+                      -> Expression {
+    let at_entry = variant.at_entry.as_ref().unwrap();
+    let mut at_call = at_entry.clone();
+
+    for (param, arg) in data.mir.args_iter().zip(args.iter()) {
+        let decl = &data.mir.local_decls[param];
+        let param_var = Expression::VariableMapping(decl.name.unwrap().as_str().to_string(),
+                                                    ast::type_to_enum(decl.ty));
+        at_call = substitute_variable_with_expression(&at_call,
+                                                      &param_var,
+                                                      &gen_expression(arg, data));
+    }
+
+    let dest = gen_lvalue(destination, data);
+    let suffix = format!("{}{}_{}", RESULT_SUFFIX, index, depth);
+    let result = self_composition::rename_with(&dest, &suffix);
+    let wp = substitute_variable_with_expression(wp, &dest, &result);
+    let entry = self_composition::rename_with(at_entry, ast::OLD_SUFFIX);
+
+    binary(decrease(at_call, entry), BinaryOperator::And, wp)
+}